@@ -18,7 +18,7 @@ pub fn PortalDemo() -> Element {
                 class: "space-y-4",
                 h2 { class: "text-2xl font-semibold", "Basic Portal" }
                 p { class: "text-sm text-muted-foreground",
-                    "Portal renders content with fixed positioning, appearing above other content."
+                    "Portal moves its content to the end of the app root, out of any clipping or stacking parent."
                 }
 
                 div {
@@ -35,7 +35,6 @@ pub fn PortalDemo() -> Element {
 
                     if show_portal() {
                         Portal {
-                            container: "body",
                             div {
                                 class: "fixed inset-0 bg-black/50 flex items-center justify-center z-50",
                                 onclick: move |_| show_portal.set(false),
@@ -67,7 +66,6 @@ pub fn PortalDemo() -> Element {
                     code {
                         class: "text-sm font-mono",
                         "Portal {{\n"
-                        "    container: \"body\",\n"
                         "    div {{\n"
                         "        class: \"fixed inset-0 bg-black/50\",\n"
                         "        // Your content here\n"
@@ -103,6 +103,48 @@ pub fn TooltipDemo() -> Element {
                     }
                 }
             }
+
+            // Inside an overflow container
+            div {
+                class: "space-y-4",
+                h2 { class: "text-2xl font-semibold", "Inside Overflow Containers" }
+                p { class: "text-sm text-muted-foreground",
+                    "Transformed, overflow-hidden ancestors clip floating content. With portal enabled the tooltip escapes them."
+                }
+                Card {
+                    class: "overflow-hidden h-24 translate-x-0",
+                    CardContent {
+                        class: "flex gap-4",
+                        TooltipProvider {
+                            Tooltip {
+                                TooltipTrigger {
+                                    Button {
+                                        variant: ButtonVariant::Outline,
+                                        "Clipped"
+                                    }
+                                }
+                                TooltipContent {
+                                    side: TooltipSide::Top,
+                                    "Cut off by the card"
+                                }
+                            }
+                            Tooltip {
+                                TooltipTrigger {
+                                    Button {
+                                        variant: ButtonVariant::Outline,
+                                        "Portaled"
+                                    }
+                                }
+                                TooltipContent {
+                                    side: TooltipSide::Top,
+                                    portal: true,
+                                    "Rendered into the body"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
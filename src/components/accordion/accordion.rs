@@ -6,7 +6,7 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use crate::components::accordion::*;
+//! use dioxus_components::*;
 //!
 //! #[component]
 //! fn App() -> Element {
@@ -298,6 +298,7 @@ pub fn AccordionTrigger(props: AccordionTriggerProps) -> Element {
             button {
                 class: "{class_name}",
                 "type": "button",
+                aria_expanded: item_context.is_open(),
                 "data-state": if item_context.is_open() { "open" } else { "closed" },
                 "data-orientation": "{accordion_context.orientation.as_str()}",
                 disabled: item_context.disabled,
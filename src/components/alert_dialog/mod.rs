@@ -0,0 +1,336 @@
+//! # AlertDialog
+//!
+//! A modal confirmation ("Are you sure?") built on [`Dialog`]. It interrupts the user and
+//! expects an answer, so unlike a plain dialog:
+//!
+//! - the content has `role="alertdialog"`,
+//! - clicking outside never closes it,
+//! - focus starts on [`AlertDialogCancel`], the safe choice,
+//! - [`AlertDialogAction`] can run an async action, keeping the dialog open with a
+//!   [`Spinner`] until it finishes.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! async fn delete_project() -> Result<(), String> {
+//!     Ok(())
+//! }
+//!
+//! #[component]
+//! fn DeleteProject() -> Element {
+//!     rsx! {
+//!         AlertDialog {
+//!             AlertDialogTrigger { "Delete project" }
+//!             AlertDialogContent {
+//!                 AlertDialogTitle { "Delete this project?" }
+//!                 AlertDialogDescription { "This cannot be undone." }
+//!                 AlertDialogCancel { "Cancel" }
+//!                 AlertDialogAction {
+//!                     variant: ButtonVariant::Destructive,
+//!                     on_action: move |_| -> AlertDialogActionFuture {
+//!                         Box::pin(async move { delete_project().await.is_ok() })
+//!                     },
+//!                     "Delete"
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::button::{button_variants, ButtonSize, ButtonVariant};
+use crate::components::dialog::{
+    Dialog, DialogContent, DialogContext, DialogDescription, DialogTitle, DialogTrigger,
+};
+use crate::components::portal::PortalContainer;
+use crate::components::spinner::Spinner;
+use crate::platform::{self, ListenerEvent};
+use crate::utils::{self, use_id};
+use dioxus::core::use_drop;
+use dioxus::prelude::*;
+use std::future::Future;
+use std::pin::Pin;
+
+/// What an async [`AlertDialogAction`] returns: a future resolving to whether the dialog
+/// should close (`false` keeps it open, e.g. when the action failed).
+pub type AlertDialogActionFuture = Pin<Box<dyn Future<Output = bool>>>;
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialog Context
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+struct AlertDialogContext {
+    cancel_id: String,
+    /// How many `AlertDialogCancel`s are rendered, so opening knows whether it can focus one.
+    cancel_count: CopyValue<usize>,
+    /// Whether an async action is running. Nothing closes the dialog meanwhile.
+    pending: Signal<bool>,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialog (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialog(
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the alert dialog is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    children: Element,
+) -> Element {
+    let cancel_id = use_id("alert-dialog-cancel");
+    let cancel_count = use_hook(|| CopyValue::new(0));
+    let pending = use_signal(|| false);
+
+    use_context_provider(|| AlertDialogContext {
+        cancel_id,
+        cancel_count,
+        pending,
+    });
+
+    rsx! {
+        Dialog {
+            open,
+            default_open,
+            on_open_change,
+            modal: true,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialogTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    rsx! {
+        DialogTrigger { attributes, {children} }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialogContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when Escape is pressed. Call `prevent_default` on the event to keep the
+    /// alert dialog open. Escape is ignored while an async action is running.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    children: Element,
+) -> Element {
+    let context = use_context::<AlertDialogContext>();
+    let pending = context.pending;
+
+    let combined_class = if class.is_empty() {
+        "alert-dialog-content".to_string()
+    } else {
+        format!("alert-dialog-content {}", class)
+    };
+
+    let on_escape = move |event: Event<ListenerEvent>| {
+        if *pending.peek() {
+            event.prevent_default();
+            return;
+        }
+        if let Some(handler) = &on_escape_key_down {
+            handler.call(event);
+        }
+    };
+
+    // Start on Cancel, the least destructive choice, when there is one
+    let cancel_id = context.cancel_id.clone();
+    let cancel_count = context.cancel_count;
+    let on_open_auto_focus = move |event: Event<()>| {
+        if *cancel_count.peek() > 0 {
+            event.prevent_default();
+            platform::focus_first(&cancel_id);
+        }
+    };
+
+    let mut content_attributes = vec![Attribute::new("role", "alertdialog", None, false)];
+    content_attributes.extend(attributes);
+
+    rsx! {
+        DialogContent {
+            class: combined_class,
+            container,
+            close_on_outside_click: false,
+            on_escape_key_down: on_escape,
+            on_open_auto_focus,
+            attributes: content_attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogTitle
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialogTitle(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    rsx! {
+        DialogTitle { class, attributes, {children} }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogDescription
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialogDescription(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    rsx! {
+        DialogDescription { class, attributes, {children} }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogAction
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialogAction(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Button style of the action
+    #[props(default = ButtonVariant::Default)]
+    variant: ButtonVariant,
+    /// Called when the action is clicked, before the alert dialog closes
+    onclick: Option<EventHandler<MouseEvent>>,
+    /// An async action. The alert dialog stays open, showing a spinner, until the returned
+    /// future resolves, then closes if it resolved to `true`.
+    on_action: Option<Callback<(), AlertDialogActionFuture>>,
+    children: Element,
+) -> Element {
+    let dialog = use_context::<DialogContext>();
+    let context = use_context::<AlertDialogContext>();
+    let mut pending = context.pending;
+
+    // The task dies with this button, so don't leave the dialog stuck in the pending state
+    use_drop(move || {
+        if *pending.peek() {
+            pending.set(false);
+        }
+    });
+
+    let onclick_action = move |event: MouseEvent| {
+        if pending() {
+            return;
+        }
+        if let Some(handler) = &onclick {
+            handler.call(event);
+        }
+
+        let Some(action) = &on_action else {
+            dialog.set_open(false);
+            return;
+        };
+        let future = action.call(());
+        pending.set(true);
+        let dialog = dialog.clone();
+        spawn(async move {
+            let close = future.await;
+            pending.set(false);
+            if close {
+                dialog.set_open(false);
+            }
+        });
+    };
+
+    let class_name = utils::cn(vec![
+        Some(button_variants(variant, ButtonSize::Default).as_str()),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "{class_name}",
+            disabled: pending(),
+            "aria-busy": if pending() { "true" } else { "false" },
+            onclick: onclick_action,
+            ..attributes,
+            if pending() {
+                Spinner {}
+            }
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogCancel
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn AlertDialogCancel(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Button style of the cancel button
+    #[props(default = ButtonVariant::Outline)]
+    variant: ButtonVariant,
+    /// Called when cancel is clicked, before the alert dialog closes
+    onclick: Option<EventHandler<MouseEvent>>,
+    children: Element,
+) -> Element {
+    let dialog = use_context::<DialogContext>();
+    let context = use_context::<AlertDialogContext>();
+    let pending = context.pending;
+
+    let mut cancel_count = context.cancel_count;
+    use_hook(move || *cancel_count.write() += 1);
+    use_drop(move || *cancel_count.write() -= 1);
+
+    let onclick_cancel = move |event: MouseEvent| {
+        if let Some(handler) = &onclick {
+            handler.call(event);
+        }
+        dialog.set_open(false);
+    };
+
+    let class_name = utils::cn(vec![
+        Some(button_variants(variant, ButtonSize::Default).as_str()),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: "{context.cancel_id}",
+            class: "{class_name}",
+            disabled: pending(),
+            onclick: onclick_cancel,
+            ..attributes,
+            {children}
+        }
+    }
+}
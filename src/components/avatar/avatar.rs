@@ -6,7 +6,7 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use components::{Avatar, AvatarImage, AvatarFallback};
+//! use dioxus_components::{Avatar, AvatarImage, AvatarFallback};
 //!
 //! #[component]
 //! fn MyComponent() -> Element {
@@ -6,7 +6,7 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use crate::components::badge::*;
+//! use dioxus_components::*;
 //!
 //! #[component]
 //! fn App() -> Element {
@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 mod badge;
 pub use badge::*;
@@ -4,6 +4,8 @@ use crate::utils;
 
 const BUTTON_CSS: &str = include_str!("./button.css");
 
+const BUTTON_BASE_CLASS: &str = "inline-flex items-center justify-center gap-2 whitespace-nowrap rounded-md text-sm font-medium transition-all disabled:pointer-events-none disabled:opacity-50 [&_svg]:pointer-events-none [&_svg:not([class*='size-'])]:size-4 shrink-0 [&_svg]:shrink-0 outline-none focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:ring-[3px] aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive";
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum ButtonVariant {
     Default,
@@ -50,6 +52,16 @@ impl ButtonSize {
     }
 }
 
+/// The classes a [`Button`] with `variant` and `size` renders, for styling other elements
+/// (links, dialog actions) to look like one.
+pub fn button_variants(variant: ButtonVariant, size: ButtonSize) -> String {
+    utils::cn(vec![
+        Some(BUTTON_BASE_CLASS),
+        Some(variant.as_str()),
+        Some(size.as_str()),
+    ])
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
     #[props(default)]
@@ -89,9 +101,8 @@ pub struct ButtonProps {
 pub fn Button(props: ButtonProps) -> Element {
     let variant = props.variant.unwrap_or(ButtonVariant::Default);
     let size = props.size.unwrap_or(ButtonSize::Default);
-    let base = "inline-flex items-center justify-center gap-2 whitespace-nowrap rounded-md text-sm font-medium transition-all disabled:pointer-events-none disabled:opacity-50 [&_svg]:pointer-events-none [&_svg:not([class*='size-'])]:size-4 shrink-0 [&_svg]:shrink-0 outline-none focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:ring-[3px] aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive";
     let class_name = utils::cn(vec![
-        Some(base),
+        Some(BUTTON_BASE_CLASS),
         Some(variant.as_str()),
         Some(size.as_str()),
         props.class.as_deref(),
@@ -4,7 +4,7 @@ use dioxus::prelude::*;
 /// A Card component that provides a container with consistent styling.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// rsx! {
 ///     Card {
 ///         class: "w-[350px]",
@@ -0,0 +1,255 @@
+//! # Checkbox Group
+//!
+//! A set of checkboxes sharing one list of selected values, with an optional "select all"
+//! parent that shows `Indeterminate` while only some items are checked.
+//!
+//! ```text
+//! CheckboxGroup (owns Vec<String> of selected values)
+//! ├── CheckboxGroupSelectAll (aggregate parent)
+//! │   ├── CheckboxTrigger
+//! │   └── CheckboxLabel
+//! └── Checkbox / CheckboxProvider { value } (one per item)
+//! ```
+//!
+//! Items submit under the group's `name`, one entry per checked value, just like a list
+//! of native checkboxes sharing a name. Resetting the owning form restores `default_value`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Notifications() -> Element {
+//!     let mut channels = use_signal(|| vec!["email".to_string()]);
+//!
+//!     rsx! {
+//!         CheckboxGroup {
+//!             name: "channels",
+//!             value: channels(),
+//!             onchange: move |values| channels.set(values),
+//!             CheckboxGroupSelectAll {
+//!                 CheckboxTrigger { CheckboxIndicator {} }
+//!                 CheckboxLabel { "All channels" }
+//!             }
+//!             CheckboxProvider {
+//!                 value: "email",
+//!                 CheckboxTrigger { CheckboxIndicator {} }
+//!                 CheckboxLabel { "Email" }
+//!             }
+//!             CheckboxProvider {
+//!                 value: "sms",
+//!                 CheckboxTrigger { CheckboxIndicator {} }
+//!                 CheckboxLabel { "SMS" }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::{CheckboxProvider, CheckedState};
+use crate::platform::{use_event_listener, ListenerOptions, ListenerTarget};
+use crate::utils::{self, use_controllable_state, use_id, ControllableState};
+use dioxus::prelude::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * CheckboxGroup Context
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+pub struct CheckboxGroupContext {
+    /// The selected values. Follows the group's `value` prop when controlled.
+    pub values: ControllableState<Vec<String>>,
+    /// The values of the mounted items, in mount order.
+    pub items: Signal<Vec<String>>,
+    pub name: Option<String>,
+    pub form: Option<String>,
+    pub disabled: bool,
+    /// Set for the select-all parent so its checkbox doesn't join the group as an item.
+    pub(super) select_all: bool,
+}
+
+impl CheckboxGroupContext {
+    /// The state of the item with `value`. Subscribes the caller to changes.
+    pub fn item_state(&self, value: &str) -> CheckedState {
+        if self.values.get().iter().any(|selected| selected == value) {
+            CheckedState::Checked
+        } else {
+            CheckedState::Unchecked
+        }
+    }
+
+    /// Adds or removes `value` and reports the new list through `onchange`.
+    pub fn set_item(&self, value: &str, selected: bool) {
+        let mut values = self.values;
+        let mut next = values.peek();
+        next.retain(|existing| existing != value);
+        if selected {
+            next.push(value.to_string());
+        }
+        values.set(next);
+    }
+
+    /// `Checked` when every item is selected, `Unchecked` when none is, otherwise
+    /// `Indeterminate`. Subscribes the caller to changes.
+    pub fn aggregate_state(&self) -> CheckedState {
+        let values = self.values.get();
+        let items = self.items.read();
+        let selected = items.iter().filter(|item| values.contains(item)).count();
+        match selected {
+            0 => CheckedState::Unchecked,
+            n if n == items.len() => CheckedState::Checked,
+            _ => CheckedState::Indeterminate,
+        }
+    }
+
+    /// Selects or clears every item, keeping any selected values that have no item.
+    pub fn set_all(&self, selected: bool) {
+        let items = self.items.peek().clone();
+        let mut values = self.values;
+        let mut next = values.peek();
+        next.retain(|value| !items.contains(value));
+        if selected {
+            next.extend(items);
+        }
+        values.set(next);
+    }
+
+    pub(super) fn register(&self, value: &str) {
+        let mut items = self.items;
+        if !items.peek().iter().any(|item| item == value) {
+            items.write().push(value.to_string());
+        }
+    }
+
+    pub(super) fn unregister(&self, value: &str) {
+        let mut items = self.items;
+        items.write().retain(|item| item != value);
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CheckboxGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxGroupProps {
+    /// The controlled list of selected values
+    #[props(optional)]
+    pub value: Option<Vec<String>>,
+
+    /// The values selected initially when uncontrolled
+    #[props(default)]
+    pub default_value: Vec<String>,
+
+    /// Callback when the user changes the selection
+    #[props(optional)]
+    pub onchange: Option<EventHandler<Vec<String>>>,
+
+    /// The name every item submits its value under
+    #[props(optional)]
+    pub name: Option<String>,
+
+    /// The form ID the items belong to
+    #[props(optional)]
+    pub form: Option<String>,
+
+    /// Whether every item is disabled
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// Additional CSS classes
+    #[props(optional)]
+    pub class: Option<String>,
+
+    /// Children elements (items and an optional CheckboxGroupSelectAll)
+    pub children: Element,
+}
+
+#[component]
+pub fn CheckboxGroup(props: CheckboxGroupProps) -> Element {
+    let default_value = props.default_value.clone();
+    let values = use_controllable_state(props.value.clone(), || default_value, props.onchange);
+    let items = use_signal(Vec::new);
+    let id = use_id("checkbox-group");
+
+    // The group restores its own default on form reset, so items don't each report a
+    // partial change
+    let reset_target = match &props.form {
+        Some(form) => ListenerTarget::Element(form.clone()),
+        None => ListenerTarget::FormOf(id.clone()),
+    };
+    let default_value = props.default_value.clone();
+    use_event_listener(
+        reset_target,
+        "reset",
+        ListenerOptions::default(),
+        true,
+        move |_| {
+            let mut values = values;
+            values.set(default_value.clone());
+        },
+    );
+
+    use_context_provider(|| CheckboxGroupContext {
+        values,
+        items,
+        name: props.name.clone(),
+        form: props.form.clone(),
+        disabled: props.disabled,
+        select_all: false,
+    });
+
+    let class_name = utils::cn(vec![Some("grid gap-3"), props.class.as_deref()]);
+
+    rsx! {
+        div {
+            role: "group",
+            id: "{id}",
+            class: "{class_name}",
+            "data-disabled": if props.disabled { Some("") } else { None },
+            {props.children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CheckboxGroupSelectAll
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxGroupSelectAllProps {
+    /// Whether the select-all checkbox is disabled
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// The ID of the checkbox element (generated when not provided)
+    #[props(optional)]
+    pub id: Option<String>,
+
+    /// Children elements (typically CheckboxTrigger and CheckboxLabel)
+    pub children: Element,
+}
+
+/// The parent checkbox of a [`CheckboxGroup`]: checked when every item is, indeterminate
+/// when some are, and selects or clears them all when clicked. It is not submitted.
+#[component]
+pub fn CheckboxGroupSelectAll(props: CheckboxGroupSelectAllProps) -> Element {
+    let group = use_context::<CheckboxGroupContext>();
+    let context = use_context_provider(|| CheckboxGroupContext {
+        select_all: true,
+        ..group.clone()
+    });
+
+    let on_select_all = context.clone();
+    rsx! {
+        CheckboxProvider {
+            checked: context.aggregate_state(),
+            onchange: move |state: CheckedState| on_select_all.set_all(state.to_bool()),
+            disabled: props.disabled || group.disabled,
+            id: props.id.clone(),
+            {props.children}
+        }
+    }
+}
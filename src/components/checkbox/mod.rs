@@ -4,7 +4,8 @@
 //!
 //! ## Architecture
 //! ```text
-//! CheckboxProvider (state management)
+//! CheckboxGroup (optional, see the `group` module)
+//! └── CheckboxProvider (state management)
 //! └── CheckboxTrigger (button role="checkbox")
 //!     ├── Children (custom content)
 //!     ├── CheckboxIndicator (visual state indicator)
@@ -17,14 +18,14 @@
 //! - ✅ **Indeterminate state support** - Mixed selection status
 //! - ✅ **Full keyboard navigation** - WAI ARIA compliant
 //! - ✅ **Form integration** - Hidden bubble input for native forms
-//! - ✅ **Form reset support** - Restores initial state
+//! - ✅ **Form reset support** - Restores `default_checked` when the owning form resets
 //! - ✅ **Event composition** - Proper event handling and propagation
 //!
 //! ## Example
 //!
-//! ```rust
+//! ```rust,ignore
 //! use dioxus::prelude::*;
-//! use crate::components::checkbox::*;
+//! use dioxus_components::*;
 //!
 //! #[component]
 //! fn App() -> Element {
@@ -49,8 +50,17 @@
 //! }
 //! ```
 
-use crate::utils;
+mod group;
+
+pub use group::*;
+
+use crate::components::presence::use_presence;
+use crate::platform::{self, use_event_listener, ListenerOptions, ListenerTarget};
+use crate::utils::{self, use_controllable_state, use_id, ControllableState};
+use dioxus::core::use_drop;
 use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
 
 const CHECKBOX_CSS: &str = include_str!("./checkbox.css");
 
@@ -97,7 +107,11 @@ impl CheckedState {
 
 #[derive(Clone)]
 pub struct CheckboxContext {
-    pub checked: Signal<CheckedState>,
+    pub id: String,
+    /// The current state. Follows the provider's `checked` prop when controlled.
+    pub checked: ControllableState<CheckedState>,
+    /// The state restored on form reset.
+    pub default_checked: CheckedState,
     pub disabled: bool,
     pub required: bool,
     pub name: Option<String>,
@@ -105,6 +119,23 @@ pub struct CheckboxContext {
     pub value: String,
     pub has_consumer_stopped_propagation: Signal<bool>,
     pub is_form_control: bool,
+    /// Whether a `CheckboxGroup` owns the state, including its select-all parent. The
+    /// group then handles form reset itself.
+    pub in_group: bool,
+}
+
+impl CheckboxContext {
+    /// Toggles the checkbox the way a click does and reports it through `onchange`.
+    pub fn toggle(&self) {
+        let mut checked = self.checked;
+        checked.set(checked.peek().toggle());
+    }
+
+    /// Restores `default_checked` the way a form reset does and reports it through `onchange`.
+    pub fn reset(&self) {
+        let mut checked = self.checked;
+        checked.set(self.default_checked);
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -121,7 +152,8 @@ pub struct CheckboxProviderProps {
     #[props(default = CheckedState::Unchecked)]
     pub default_checked: CheckedState,
 
-    /// Callback when checked state changes
+    /// Callback when the user changes the state (click or form reset). In controlled mode
+    /// the state only changes once `checked` is updated from here.
     #[props(optional)]
     pub onchange: Option<EventHandler<CheckedState>>,
 
@@ -141,37 +173,82 @@ pub struct CheckboxProviderProps {
     #[props(optional)]
     pub form: Option<String>,
 
-    /// The value attribute for form submission
+    /// The value attribute for form submission. Inside a `CheckboxGroup` it identifies the
+    /// item, and the group decides whether it is checked.
     #[props(default = "on".to_string())]
     pub value: String,
 
+    /// The ID of the checkbox element (generated when not provided)
+    #[props(optional)]
+    pub id: Option<String>,
+
     /// Children elements (typically CheckboxTrigger)
     pub children: Element,
 }
 
 #[component]
 pub fn CheckboxProvider(props: CheckboxProviderProps) -> Element {
-    let mut checked = use_signal(|| props.default_checked);
-    let has_consumer_stopped_propagation = use_signal(|| false);
+    // Items of a CheckboxGroup are controlled by the group's list of values
+    let any_group = try_use_context::<CheckboxGroupContext>();
+    let in_group = any_group.is_some();
+    let group = any_group.filter(|group| !group.select_all);
+    let item_group = group.clone();
+    let item_value = props.value.clone();
+    let onchange = props.onchange;
+    let handle_change = use_callback(move |state: CheckedState| {
+        if let Some(group) = &item_group {
+            group.set_item(&item_value, state.to_bool());
+        }
+        if let Some(handler) = onchange {
+            handler.call(state);
+        }
+    });
+    let checked_prop = match &group {
+        Some(group) => Some(group.item_state(&props.value)),
+        None => props.checked,
+    };
 
-    // Override with controlled value if provided
-    if let Some(controlled) = props.checked {
-        checked.set(controlled);
-    }
+    let registration = group.clone().map(|group| (group, props.value.clone()));
+    let unregistration = registration.clone();
+    use_effect(move || {
+        if let Some((group, value)) = &registration {
+            group.register(value);
+        }
+    });
+    use_drop(move || {
+        if let Some((group, value)) = &unregistration {
+            group.unregister(value);
+        }
+    });
+
+    let default_checked = props.default_checked;
+    let checked = use_controllable_state(checked_prop, || default_checked, Some(handle_change));
+    let has_consumer_stopped_propagation = use_signal(|| false);
+    let generated_id = use_id("checkbox");
 
     // Determine if this is a form control
-    let is_form_control = props.form.is_some() || true; // Default to true for SSR
+    // Always render the bubble input so native forms (and SSR output) see the value
+    let is_form_control = true;
 
     // Provide context to children
     use_context_provider(|| CheckboxContext {
+        id: props.id.clone().unwrap_or(generated_id),
         checked,
-        disabled: props.disabled,
+        default_checked,
+        disabled: props.disabled || group.as_ref().is_some_and(|group| group.disabled),
         required: props.required,
-        name: props.name.clone(),
-        form: props.form.clone(),
+        name: props
+            .name
+            .clone()
+            .or_else(|| group.as_ref().and_then(|group| group.name.clone())),
+        form: props
+            .form
+            .clone()
+            .or_else(|| group.as_ref().and_then(|group| group.form.clone())),
         value: props.value.clone(),
         has_consumer_stopped_propagation,
         is_form_control,
+        in_group,
     });
 
     rsx! {
@@ -190,7 +267,7 @@ pub struct CheckboxTriggerProps {
     #[props(optional)]
     pub class: Option<String>,
 
-    /// The ID attribute
+    /// The ID attribute (defaults to the provider's ID)
     #[props(optional)]
     pub id: Option<String>,
 
@@ -209,24 +286,25 @@ pub struct CheckboxTriggerProps {
 #[component]
 pub fn CheckboxTrigger(props: CheckboxTriggerProps) -> Element {
     let context = use_context::<CheckboxContext>();
-    let mut checked = context.checked;
-    let _initial_checked = use_signal(|| checked());
+    let id = props.id.clone().unwrap_or_else(|| context.id.clone());
 
     // Form reset support
-    let form_id = context.form.clone();
-    use_effect(move || {
-        if let Some(_form_id) = &form_id {
-            // TODO: Add form reset listener when form ref available
-            // For now, native HTML form reset will work
-        }
-    });
-
+    let reset_target = match &context.form {
+        Some(form) => ListenerTarget::Element(form.clone()),
+        None => ListenerTarget::FormOf(id.clone()),
+    };
+    let reset_context = context.clone();
+    use_event_listener(
+        reset_target,
+        "reset",
+        ListenerOptions::default(),
+        !context.in_group,
+        move |_| reset_context.reset(),
+    );
+
+    let toggle_context = context.clone();
     let handle_click = move |evt: MouseEvent| {
-        let new_state = checked().toggle();
-        checked.set(new_state);
-
-        // TODO: Call provider's onchange through context if needed
-        // For now, onchange should be passed directly to Checkbox component
+        toggle_context.toggle();
 
         // Call custom onclick if provided
         if let Some(handler) = &props.onclick {
@@ -257,13 +335,13 @@ pub fn CheckboxTrigger(props: CheckboxTriggerProps) -> Element {
         props.class.as_deref(),
     ]);
 
-    let checked_state = checked();
+    let checked_state = context.checked.get();
 
     rsx! {
         button {
             r#type: "button",
             role: "checkbox",
-            id: props.id.as_deref(),
+            id: "{id}",
             class: "{class_name}",
             disabled: context.disabled,
             "aria-checked": if checked_state.is_indeterminate() {
@@ -275,7 +353,7 @@ pub fn CheckboxTrigger(props: CheckboxTriggerProps) -> Element {
             },
             "aria-required": if context.required { "true" } else { "false" },
             "data-state": "{checked_state.data_state()}",
-            "data-disabled": if context.disabled { "" } else { "" },
+            "data-disabled": if context.disabled { Some("") } else { None },
             onclick: handle_click,
             onkeydown: handle_keydown,
             {props.children}
@@ -283,7 +361,7 @@ pub fn CheckboxTrigger(props: CheckboxTriggerProps) -> Element {
 
         // Render bubble input for form controls
         if context.is_form_control {
-            CheckboxBubbleInput {}
+            CheckboxBubbleInput { control: id.clone() }
         }
     }
 }
@@ -312,25 +390,42 @@ pub fn CheckboxIndicator(props: CheckboxIndicatorProps) -> Element {
     let context = use_context::<CheckboxContext>();
 
     let class_name = utils::cn(vec![
-        Some("flex items-center justify-center text-current pointer-events-none"),
+        Some(
+            "checkbox-indicator flex items-center justify-center text-current pointer-events-none",
+        ),
         props.class.as_deref(),
     ]);
 
-    let checked_state = *context.checked.read();
-    let should_render = props.force_mount
-        || checked_state == CheckedState::Checked
-        || checked_state == CheckedState::Indeterminate;
+    let checked_state = context.checked.get();
+    let is_checked = checked_state != CheckedState::Unchecked;
+
+    // Stays mounted after unchecking until its exit animation finishes, still showing
+    // the icon it last had
+    let indicator_id = use_id("checkbox-indicator");
+    let presence = use_presence(is_checked, indicator_id.clone());
+    let last_icon = use_hook(|| Rc::new(Cell::new(checked_state)));
+    if is_checked {
+        last_icon.set(checked_state);
+    }
+    let icon = if presence.is_present() {
+        last_icon.get()
+    } else {
+        CheckedState::Unchecked
+    };
+
+    let should_render = props.force_mount || presence.is_present();
 
     if should_render {
         rsx! {
             span {
+                id: indicator_id,
                 class: "{class_name}",
                 "data-state": "{checked_state.data_state()}",
-                "data-disabled": if context.disabled { "" } else { "" },
+                "data-disabled": if context.disabled { Some("") } else { None },
                 style: "pointer-events: none;",
 
                 // Render default icons if no custom children
-                if checked_state == CheckedState::Checked {
+                if icon == CheckedState::Checked {
                     svg {
                         class: "size-3.5",
                         xmlns: "http://www.w3.org/2000/svg",
@@ -344,7 +439,7 @@ pub fn CheckboxIndicator(props: CheckboxIndicatorProps) -> Element {
                         stroke_linejoin: "round",
                         path { d: "M20 6 9 17l-5-5" }
                     }
-                } else if checked_state == CheckedState::Indeterminate {
+                } else if icon == CheckedState::Indeterminate {
                     svg {
                         class: "size-3.5",
                         xmlns: "http://www.w3.org/2000/svg",
@@ -372,10 +467,28 @@ pub fn CheckboxIndicator(props: CheckboxIndicatorProps) -> Element {
  * CheckboxBubbleInput
  * -----------------------------------------------------------------------------------------------*/
 
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxBubbleInputProps {
+    /// The ID of the checkbox button this input directly follows
+    pub control: String,
+}
+
 #[component]
-pub fn CheckboxBubbleInput() -> Element {
+pub fn CheckboxBubbleInput(props: CheckboxBubbleInputProps) -> Element {
     let context = use_context::<CheckboxContext>();
-    let checked_state = *context.checked.read();
+    let checked_state = context.checked.get();
+
+    // Dispatch native input/change events when the state changes, so plain form
+    // handlers and validation outside Dioxus notice
+    let previous = use_hook(|| Rc::new(Cell::new(checked_state)));
+    let checked = context.checked;
+    use_effect(use_reactive!(|(props,)| {
+        let current = checked.get();
+        if previous.replace(current) != current {
+            let selector = format!("{} + input", platform::id_selector(&props.control));
+            platform::dispatch_events(&selector, &["input", "change"]);
+        }
+    }));
 
     // This input is hidden and used for form submission
     rsx! {
@@ -458,8 +571,8 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
             name: props.name.clone(),
             form: props.form.clone(),
             value: props.value.clone(),
+            id: props.id.clone(),
             CheckboxTrigger {
-                id: props.id.clone(),
                 class: props.class.clone(),
                 {props.children}
             }
@@ -483,8 +596,16 @@ pub struct CheckboxLabelProps {
     pub class: Option<String>,
 }
 
+/// Label for a checkbox. Inside a `CheckboxProvider` it points at that checkbox
+/// automatically; elsewhere pass `for_id`.
 #[component]
 pub fn CheckboxLabel(props: CheckboxLabelProps) -> Element {
+    let context = try_use_context::<CheckboxContext>();
+    let for_id = props
+        .for_id
+        .clone()
+        .or_else(|| context.map(|context| context.id));
+
     let class_name = utils::cn(vec![
         Some("text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70 cursor-pointer"),
         props.class.as_deref(),
@@ -493,7 +614,7 @@ pub fn CheckboxLabel(props: CheckboxLabelProps) -> Element {
     rsx! {
         label {
             class: "{class_name}",
-            r#for: props.for_id.as_deref(),
+            r#for: for_id,
             {props.children}
         }
     }
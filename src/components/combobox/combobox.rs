@@ -0,0 +1,916 @@
+//! # Combobox Component
+//!
+//! A text input paired with a list of suggestions that narrows down as the user types,
+//! for searchable pickers such as choosing a user or tagging an issue. It follows the
+//! WAI-ARIA combobox pattern with list autocomplete: focus stays in the input, and the
+//! highlighted option is announced through `aria-activedescendant`.
+//!
+//! Options come from one of two places:
+//!
+//! - `options`, filtered as the user types. The default filter matches labels containing
+//!   the query, ignoring case; pass `filter` to match differently.
+//! - `load`, an async loader called with the query whenever it changes. The list shows a
+//!   [`Spinner`] until the future resolves, and a newer query cancels an older load.
+//!
+//! With `multiple`, selected options are shown in the input as removable [`Badge`]s and
+//! the list stays open to pick more.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! async fn search_users(query: String) -> Vec<ComboboxOption> {
+//!     vec![ComboboxOption::new("ada", format!("Ada ({query})"))]
+//! }
+//!
+//! #[component]
+//! fn Pickers() -> Element {
+//!     let languages = vec![
+//!         ComboboxOption::new("rs", "Rust"),
+//!         ComboboxOption::new("ts", "TypeScript"),
+//!         ComboboxOption::new("cob", "COBOL").disabled(true),
+//!     ];
+//!
+//!     rsx! {
+//!         Combobox {
+//!             options: languages,
+//!             ComboboxInput { placeholder: "Language…" }
+//!             ComboboxContent {
+//!                 ComboboxEmpty { "No language found." }
+//!             }
+//!         }
+//!         Combobox {
+//!             multiple: true,
+//!             name: "reviewers",
+//!             load: move |query: String| -> ComboboxLoadFuture {
+//!                 Box::pin(search_users(query))
+//!             },
+//!             ComboboxInput { placeholder: "Add reviewers…" }
+//!             ComboboxContent {
+//!                 ComboboxEmpty { "No one matches." }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::badge::{Badge, BadgeVariant};
+use crate::components::dismissable_layer::{use_dismissable_layer, DismissableLayerOptions};
+use crate::components::empty::Empty;
+use crate::components::portal::{Portal, PortalContainer};
+use crate::components::presence::use_presence;
+use crate::components::spinner::Spinner;
+use crate::platform::{use_event_listener, ListenerEvent, ListenerOptions, ListenerTarget};
+use crate::utils::floating::{use_auto_update, use_floating, Align, Anchor, FloatingOptions, Side};
+use crate::utils::{self, use_controllable_state, use_id, use_layer, ControllableState, Layer};
+use dioxus::core::Task;
+use dioxus::html::{ScrollBehavior, ScrollLogicalPosition, ScrollToOptions};
+use dioxus::prelude::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+const COMBOBOX_CSS: &str = include_str!("./combobox.css");
+
+/// What an async `load` returns: a future resolving to the options matching the query.
+pub type ComboboxLoadFuture = Pin<Box<dyn Future<Output = Vec<ComboboxOption>>>>;
+
+/// An option to choose from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComboboxOption {
+    /// The value reported by `on_value_change` and submitted with the form.
+    pub value: String,
+    /// The text shown in the list, in the input and on badges.
+    pub label: String,
+    /// Skip the option in keyboard navigation and ignore selecting it.
+    pub disabled: bool,
+}
+
+impl ComboboxOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The default filter: labels containing the query, ignoring case.
+fn label_contains(option: &ComboboxOption, query: &str) -> bool {
+    option.label.to_lowercase().contains(&query.to_lowercase())
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Combobox state
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+struct ComboboxContext {
+    value: ControllableState<Vec<String>>,
+    /// The value restored on form reset.
+    default_value: Vec<String>,
+    open: ControllableState<bool>,
+    multiple: bool,
+    disabled: bool,
+    /// The id of the form the combobox belongs to, when not the one around it.
+    form: Option<String>,
+    /// What the input shows. With a single value it is the selected label until the user
+    /// types; with `multiple` it is only ever the query.
+    text: Signal<String>,
+    /// What the options are filtered by: the text typed since the list opened.
+    query: Signal<String>,
+    /// The `options` prop.
+    options: Memo<Vec<ComboboxOption>>,
+    /// The options listed for the query, in order.
+    visible: Memo<Vec<ComboboxOption>>,
+    /// The options of the last `load`, if there was one.
+    loaded: Signal<Option<Vec<ComboboxOption>>>,
+    loading: Signal<bool>,
+    /// Options picked so far, to label selected values that a later load no longer lists.
+    picked: Signal<Vec<ComboboxOption>>,
+    /// The value of the highlighted option.
+    active: Signal<Option<String>>,
+    input_id: String,
+    control_id: String,
+    listbox_id: String,
+    content_id: String,
+    /// What the content is placed against: the input, with any badges.
+    control: Signal<Option<Anchor>>,
+    layer: Layer,
+}
+
+impl ComboboxContext {
+    fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    /// Opens the list. `query` filters it; `None` lists everything.
+    fn show(&self, query: Option<String>) {
+        let mut state = self.open;
+        let mut filter = self.query;
+        filter.set(query.unwrap_or_default());
+        // Start on the selected option, as long as it is listed
+        let mut active = self.active;
+        active.set(self.value.peek().first().cloned());
+        state.set(true);
+    }
+
+    /// Closes the list and puts the selected label back in the input.
+    fn hide(&self) {
+        let mut state = self.open;
+        let mut query = self.query;
+        state.set(false);
+        query.set(String::new());
+        self.sync_text();
+    }
+
+    /// Shows the selected label in the input, or clears it for `multiple`.
+    fn sync_text(&self) {
+        let mut text = self.text;
+        let next = match self.value.peek().first() {
+            Some(value) if !self.multiple => self.label(value),
+            _ => String::new(),
+        };
+        if *text.peek() != next {
+            text.set(next);
+        }
+    }
+
+    /// The label of `value`, or the value itself when no option has it.
+    fn label(&self, value: &str) -> String {
+        let loaded = self.loaded.peek();
+        let picked = self.picked.peek();
+        self.options
+            .peek()
+            .iter()
+            .chain(loaded.iter().flatten())
+            .chain(picked.iter())
+            .find(|option| option.value == value)
+            .map(|option| option.label.clone())
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    /// Selects `option`, or with `multiple` toggles it.
+    fn select(&self, option: &ComboboxOption) {
+        if option.disabled {
+            return;
+        }
+        let mut picked = self.picked;
+        if !picked.peek().contains(option) {
+            picked.write().push(option.clone());
+        }
+
+        let mut value = self.value;
+        if self.multiple {
+            let mut values = value.peek();
+            match values.iter().position(|selected| *selected == option.value) {
+                Some(index) => {
+                    values.remove(index);
+                }
+                None => values.push(option.value.clone()),
+            }
+            value.set(values);
+            // Ready to search for the next one
+            let mut text = self.text;
+            let mut query = self.query;
+            text.set(String::new());
+            query.set(String::new());
+        } else {
+            value.set(vec![option.value.clone()]);
+            self.hide();
+        }
+    }
+
+    /// Removes `value` from a `multiple` selection.
+    fn remove(&self, value: &str) {
+        let mut state = self.value;
+        let mut values = state.peek();
+        values.retain(|selected| selected != value);
+        state.set(values);
+    }
+
+    /// Restores `default_value` the way a form reset does.
+    fn reset(&self) {
+        let mut state = self.value;
+        state.set(self.default_value.clone());
+        self.sync_text();
+    }
+
+    /// Highlights the next (or previous) enabled option, staying put at the ends.
+    fn move_active(&self, forward: bool) {
+        let visible = self.visible.peek();
+        let enabled: Vec<&ComboboxOption> = visible.iter().filter(|o| !o.disabled).collect();
+        let current = self
+            .active
+            .peek()
+            .as_ref()
+            .and_then(|value| enabled.iter().position(|option| &option.value == value));
+        let next = match (current, forward) {
+            (None, true) => enabled.first(),
+            (None, false) => enabled.last(),
+            (Some(index), true) => enabled.get(index + 1).or(enabled.last()),
+            (Some(index), false) => enabled.get(index.saturating_sub(1)),
+        };
+        let mut active = self.active;
+        active.set(next.map(|option| option.value.clone()));
+    }
+
+    /// The id of the option at `index` in the list.
+    fn option_id(&self, index: usize) -> String {
+        format!("{}-option-{index}", self.listbox_id)
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Combobox (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn Combobox(
+    /// The options to choose from, filtered by what the user types
+    #[props(default)]
+    options: Vec<ComboboxOption>,
+    /// Decides whether an option matches the query (default: its label contains the
+    /// query, ignoring case)
+    filter: Option<Callback<(ComboboxOption, String), bool>>,
+    /// Loads the options for a query instead, e.g. from a server. Called whenever the
+    /// query changes while the list is open; a newer query cancels the older load, so
+    /// sleeping at the start of the future debounces it.
+    load: Option<Callback<String, ComboboxLoadFuture>>,
+    /// Pick any number of options, shown as removable badges
+    #[props(default = false)]
+    multiple: bool,
+    /// Controlled value: the selected option values (at most one without `multiple`)
+    value: Option<Vec<String>>,
+    /// The values selected by default (uncontrolled), and restored on form reset
+    #[props(default)]
+    default_value: Vec<String>,
+    /// Callback when the selection changes
+    on_value_change: Option<EventHandler<Vec<String>>>,
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the list is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// The name the selected values are submitted under with their form
+    #[props(into)]
+    name: Option<String>,
+    /// The id of the form the combobox belongs to (default: the form around it)
+    #[props(into)]
+    form: Option<String>,
+    /// Whether the combobox can't be used
+    #[props(default = false)]
+    disabled: bool,
+    children: Element,
+) -> Element {
+    let initial_value = default_value.clone();
+    let value = use_controllable_state(value, || initial_value, on_value_change);
+    let open = use_controllable_state(open, || default_open, on_open_change);
+    let query = use_signal(String::new);
+    let mut loaded = use_signal(|| None::<Vec<ComboboxOption>>);
+    let mut loading = use_signal(|| false);
+    let picked = use_signal(Vec::<ComboboxOption>::new);
+    let mut active = use_signal(|| None::<String>);
+    let layer = use_layer(open.get());
+    let input_id = use_id("combobox-input");
+    let control_id = use_id("combobox-control");
+    let listbox_id = use_id("combobox-listbox");
+    let content_id = use_id("combobox-content");
+    let control = use_signal(|| None::<Anchor>);
+
+    let options = use_memo(use_reactive!(|options| options));
+    let visible = use_memo(use_reactive!(|load, filter| {
+        let query = query();
+        if load.is_some() {
+            return loaded().unwrap_or_default();
+        }
+        options()
+            .into_iter()
+            .filter(|option| match (&filter, query.is_empty()) {
+                (_, true) => true,
+                (Some(filter), false) => filter.call((option.clone(), query.clone())),
+                (None, false) => label_contains(option, &query),
+            })
+            .collect()
+    }));
+
+    // The input starts out showing the selected label
+    let label_options = options;
+    let text = use_signal(move || {
+        let value = value.peek();
+        match value.first() {
+            Some(selected) if !multiple => label_options
+                .peek()
+                .iter()
+                .find(|option| option.value == *selected)
+                .map(|option| option.label.clone())
+                .unwrap_or_else(|| selected.clone()),
+            _ => String::new(),
+        }
+    });
+
+    let context = use_context_provider(|| ComboboxContext {
+        value,
+        default_value,
+        open,
+        multiple,
+        disabled,
+        form: form.clone(),
+        text,
+        query,
+        options,
+        visible,
+        loaded,
+        loading,
+        picked,
+        active,
+        input_id,
+        control_id,
+        listbox_id,
+        content_id,
+        control,
+        layer,
+    });
+
+    // Load the options for the query while open, dropping any load still running
+    let mut load_task = use_hook(|| CopyValue::new(None::<Task>));
+    use_effect(move || {
+        let query = query();
+        let is_open = open.get();
+        if let Some(task) = load_task.take() {
+            task.cancel();
+            loading.set(false);
+        }
+        let Some(load) = load.filter(|_| is_open) else {
+            return;
+        };
+        let future = load.call(query);
+        loading.set(true);
+        load_task.set(Some(spawn(async move {
+            let options = future.await;
+            loaded.set(Some(options));
+            loading.set(false);
+            load_task.set(None);
+        })));
+    });
+
+    // Keep an option highlighted, so Enter picks the best match
+    use_effect(move || {
+        let visible = visible();
+        let current = active();
+        let listed = |value: &String| visible.iter().any(|o| &o.value == value && !o.disabled);
+        if current.as_ref().is_some_and(listed) {
+            return;
+        }
+        let first = visible.iter().find(|option| !option.disabled);
+        let next = first.map(|option| option.value.clone());
+        if next != current {
+            active.set(next);
+        }
+    });
+
+    // Follow a value changed from outside, such as a controlled value
+    let sync = context.clone();
+    use_effect(move || {
+        let _ = value.get();
+        if !sync.open.peek() {
+            sync.sync_text();
+        }
+    });
+
+    let values = value.get();
+
+    rsx! {
+        style { {COMBOBOX_CSS} }
+        {children}
+        if let Some(name) = name {
+            for selected in values {
+                input {
+                    key: "{selected}",
+                    r#type: "hidden",
+                    name: "{name}",
+                    form: form.clone(),
+                    disabled,
+                    value: "{selected}",
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ComboboxInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The text input, with the selected badges before it in `multiple` mode.
+///
+/// `attributes` go to the `input`; label it with `aria-label` or a `<label for>`.
+#[component]
+pub fn ComboboxInput(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Shown while the input is empty
+    #[props(into, default = String::new())]
+    placeholder: String,
+) -> Element {
+    let combobox = use_context::<ComboboxContext>();
+    let is_open = combobox.is_open();
+    let disabled = combobox.disabled;
+    let multiple = combobox.multiple;
+    let mut control = combobox.control;
+    let mut text = combobox.text;
+    let mut query = combobox.query;
+
+    // Form reset support
+    let reset_target = match &combobox.form {
+        Some(form) => ListenerTarget::Element(form.clone()),
+        None => ListenerTarget::FormOf(combobox.input_id.clone()),
+    };
+    let reset_combobox = combobox.clone();
+    use_event_listener(
+        reset_target,
+        "reset",
+        ListenerOptions::default(),
+        true,
+        move |_| reset_combobox.reset(),
+    );
+
+    let active_id = match (is_open, combobox.active.read().as_ref()) {
+        (true, Some(active)) => combobox
+            .visible
+            .read()
+            .iter()
+            .position(|option| &option.value == active)
+            .map(|index| combobox.option_id(index)),
+        _ => None,
+    };
+
+    let input_combobox = combobox.clone();
+    let oninput = move |event: FormEvent| {
+        let typed = event.value();
+        text.set(typed.clone());
+        if input_combobox.is_open() {
+            query.set(typed);
+        } else {
+            input_combobox.show(Some(typed));
+        }
+    };
+
+    let click_combobox = combobox.clone();
+    let onclick = move |_| {
+        if !disabled && !click_combobox.is_open() {
+            click_combobox.show(None);
+        }
+    };
+
+    let key_combobox = combobox.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let combobox = &key_combobox;
+        if disabled {
+            return;
+        }
+        let is_open = combobox.is_open();
+        match event.key() {
+            Key::ArrowDown | Key::ArrowUp => {
+                event.prevent_default();
+                if !is_open {
+                    combobox.show(None);
+                } else if !event.modifiers().alt() {
+                    combobox.move_active(event.key() == Key::ArrowDown);
+                }
+            }
+            Key::Enter if is_open => {
+                let active = combobox.active.peek().clone();
+                let option = active.and_then(|active| {
+                    combobox
+                        .visible
+                        .peek()
+                        .iter()
+                        .find(|option| option.value == active)
+                        .cloned()
+                });
+                if let Some(option) = option {
+                    // Don't submit the form
+                    event.prevent_default();
+                    combobox.select(&option);
+                }
+            }
+            // While open, Escape reaches the dismissable layer, which closes the list
+            Key::Escape if !is_open => {
+                if !multiple && !combobox.value.peek().is_empty() {
+                    let mut value = combobox.value;
+                    value.set(Vec::new());
+                }
+                text.set(String::new());
+            }
+            Key::Backspace if multiple && text.peek().is_empty() => {
+                if let Some(last) = combobox.value.peek().last() {
+                    combobox.remove(last);
+                }
+            }
+            Key::Tab if is_open => combobox.hide(),
+            _ => {}
+        }
+    };
+
+    let control_class = utils::cn(vec![
+        Some("border-input dark:bg-input/30 flex min-h-9 w-full min-w-0 flex-wrap items-center gap-1 rounded-md border bg-transparent px-3 py-1 text-base shadow-xs transition-[color,box-shadow] md:text-sm focus-within:border-ring focus-within:ring-ring/50 focus-within:ring-[3px] data-[disabled]:pointer-events-none data-[disabled]:cursor-not-allowed data-[disabled]:opacity-50"),
+        Some(class.as_str()),
+    ]);
+
+    let chips: Vec<(String, String)> = if multiple {
+        combobox
+            .value
+            .get()
+            .into_iter()
+            .map(|value| {
+                let label = combobox.label(&value);
+                (value, label)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let chip_combobox = combobox.clone();
+
+    rsx! {
+        div {
+            id: "{combobox.control_id}",
+            class: "{control_class}",
+            "data-state": if is_open { "open" } else { "closed" },
+            "data-disabled": if disabled { Some("") } else { None },
+            onmounted: move |event: MountedEvent| {
+                control.set(Some(event.data().into()));
+            },
+            for (value, label) in chips {
+                Badge {
+                    key: "{value}",
+                    variant: BadgeVariant::Secondary,
+                    class: "gap-1 pr-1",
+                    "{label}"
+                    button {
+                        r#type: "button",
+                        class: "hover:bg-secondary-foreground/10 rounded-full",
+                        "aria-label": "Remove {label}",
+                        disabled,
+                        onclick: {
+                            let combobox = chip_combobox.clone();
+                            move |_| {
+                                combobox.remove(&value);
+                                crate::platform::focus(&combobox.input_id);
+                            }
+                        },
+                        svg {
+                            class: "size-3",
+                            "aria-hidden": "true",
+                            xmlns: "http://www.w3.org/2000/svg",
+                            width: "24",
+                            height: "24",
+                            view_box: "0 0 24 24",
+                            fill: "none",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            path { d: "M18 6 6 18" }
+                            path { d: "m6 6 12 12" }
+                        }
+                    }
+                }
+            }
+            input {
+                r#type: "text",
+                role: "combobox",
+                id: "{combobox.input_id}",
+                class: "placeholder:text-muted-foreground min-w-16 flex-1 bg-transparent py-1 outline-none",
+                autocomplete: "off",
+                placeholder,
+                disabled,
+                value: "{text}",
+                "aria-autocomplete": "list",
+                "aria-expanded": if is_open { "true" } else { "false" },
+                "aria-controls": "{combobox.listbox_id}",
+                "aria-activedescendant": active_id,
+                oninput,
+                onclick,
+                onkeydown,
+                ..attributes,
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ComboboxContent
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The popup with the list of options, a spinner while loading, and its children (such as
+/// a [`ComboboxEmpty`]) after the list.
+#[component]
+pub fn ComboboxContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The preferred side of the input to render against
+    #[props(default = Side::Bottom)]
+    side: Side,
+    /// The distance in pixels from the input
+    #[props(default = 4)]
+    side_offset: i32,
+    /// The preferred alignment against the input
+    #[props(default = Align::Start)]
+    align: Align,
+    /// Flip and shift the content to keep it inside the viewport
+    #[props(default = true)]
+    avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in
+    #[props(default = 0)]
+    collision_padding: i32,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when a pointer is pressed outside the input and the list. Call
+    /// `prevent_default` to keep it open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    children: Element,
+) -> Element {
+    let combobox = use_context::<ComboboxContext>();
+    let is_open = combobox.is_open();
+    let layer = combobox.layer;
+
+    let mut floating = use_floating(
+        combobox.control,
+        FloatingOptions {
+            side,
+            align,
+            side_offset: side_offset as f64,
+            avoid_collisions,
+            collision_padding: collision_padding as f64,
+            ..FloatingOptions::default()
+        },
+    );
+    use_auto_update(floating, is_open);
+    // As wide as the input
+    let mut width = use_signal(|| None::<f64>);
+
+    let presence = use_presence(is_open, combobox.content_id.clone());
+
+    let dismiss_combobox = combobox.clone();
+    use_dismissable_layer(
+        layer,
+        combobox.content_id.clone(),
+        DismissableLayerOptions {
+            escape: true,
+            outside: true,
+            inside_ids: vec![combobox.control_id.clone()],
+            on_escape_key_down: None,
+            on_pointer_down_outside,
+            on_focus_outside: None,
+            on_interact_outside: None,
+        },
+        move |_| dismiss_combobox.hide(),
+    );
+
+    if !presence.is_present() {
+        return rsx! {};
+    }
+
+    let control = combobox.control;
+    let onmounted = move |event: MountedEvent| {
+        floating.set_content(event.data());
+        spawn(async move {
+            let Some(control) = control.peek().clone() else {
+                return;
+            };
+            if let Some(rect) = control.rect().await {
+                width.set(Some(rect.width));
+            }
+        });
+    };
+
+    let z_index = layer.z_index();
+    let min_width = width()
+        .map(|width| format!(" min-width: {width}px;"))
+        .unwrap_or_default();
+    let placed = floating.position();
+    let placed_side = placed.map(|pos| pos.side).unwrap_or(side);
+    let placed_align = placed.map(|pos| pos.align).unwrap_or(align);
+
+    let class_name = utils::cn(vec![
+        Some("combobox-content bg-popover text-popover-foreground flex max-h-72 flex-col overflow-hidden rounded-md border shadow-md"),
+        Some(class.as_str()),
+    ]);
+
+    let loading = (combobox.loading)();
+    let visible = (combobox.visible)();
+
+    rsx! {
+        Portal {
+            container,
+            div {
+                style: "{floating.style()}{min_width} z-index: {z_index};",
+                onmounted,
+                div {
+                    id: "{combobox.content_id}",
+                    class: "{class_name}",
+                    "data-state": if is_open { "open" } else { "closed" },
+                    "data-side": placed_side.as_str(),
+                    "data-align": placed_align.as_str(),
+                    // Keep focus in the input
+                    onpointerdown: move |event: PointerEvent| event.prevent_default(),
+                    ..attributes,
+                    if loading {
+                        div {
+                            class: "text-muted-foreground flex items-center justify-center gap-2 py-2 text-sm",
+                            Spinner {}
+                        }
+                    }
+                    div {
+                        role: "listbox",
+                        id: "{combobox.listbox_id}",
+                        class: "overflow-y-auto p-1 empty:hidden",
+                        "aria-labelledby": "{combobox.input_id}",
+                        "aria-multiselectable": if combobox.multiple { Some("true") } else { None },
+                        "aria-busy": if loading { "true" } else { "false" },
+                        for (index, option) in visible.into_iter().enumerate() {
+                            ComboboxItem {
+                                key: "{option.value}",
+                                id: combobox.option_id(index),
+                                option,
+                            }
+                        }
+                    }
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ComboboxItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// One option in the list. Highlighting it scrolls it into view.
+#[component]
+fn ComboboxItem(id: String, option: ComboboxOption) -> Element {
+    let combobox = use_context::<ComboboxContext>();
+    let mut active = combobox.active;
+    let is_active = active.read().as_deref() == Some(option.value.as_str());
+    let selected = combobox.value.get().contains(&option.value);
+    let disabled = option.disabled;
+
+    let mut node = use_signal(|| None::<Rc<MountedData>>);
+    use_effect(use_reactive!(|is_active| {
+        if !is_active {
+            return;
+        }
+        if let Some(node) = node() {
+            spawn(async move {
+                let _ = node
+                    .scroll_to_with_options(ScrollToOptions {
+                        behavior: ScrollBehavior::Instant,
+                        vertical: ScrollLogicalPosition::Nearest,
+                        horizontal: ScrollLogicalPosition::Nearest,
+                    })
+                    .await;
+            });
+        }
+    }));
+
+    let hovered = option.value.clone();
+    let onpointermove = move |_| {
+        if !disabled && active.peek().as_deref() != Some(hovered.as_str()) {
+            active.set(Some(hovered.clone()));
+        }
+    };
+    let picked = option.clone();
+    let onclick = move |_| combobox.select(&picked);
+
+    rsx! {
+        div {
+            role: "option",
+            id,
+            class: "relative flex w-full cursor-default items-center gap-2 rounded-sm py-1.5 pr-8 pl-2 text-sm outline-none select-none data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50",
+            "aria-selected": if selected { "true" } else { "false" },
+            "aria-disabled": if disabled { Some("true") } else { None },
+            "data-highlighted": if is_active { Some("") } else { None },
+            "data-disabled": if disabled { Some("") } else { None },
+            "data-state": if selected { "checked" } else { "unchecked" },
+            onmounted: move |event: MountedEvent| node.set(Some(event.data())),
+            onpointermove,
+            onclick,
+            "{option.label}"
+            if selected {
+                span {
+                    class: "pointer-events-none absolute right-2 flex size-3.5 items-center justify-center",
+                    "aria-hidden": "true",
+                    svg {
+                        class: "size-4",
+                        xmlns: "http://www.w3.org/2000/svg",
+                        width: "24",
+                        height: "24",
+                        view_box: "0 0 24 24",
+                        fill: "none",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        stroke_linecap: "round",
+                        stroke_linejoin: "round",
+                        path { d: "M20 6 9 17l-5-5" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ComboboxEmpty
+ * -----------------------------------------------------------------------------------------------*/
+
+/// An [`Empty`] state shown in the content while no option matches the query. Hidden
+/// while options are loading.
+#[component]
+pub fn ComboboxEmpty(
+    /// Additional CSS classes
+    class: Option<String>,
+    children: Element,
+) -> Element {
+    let combobox = use_context::<ComboboxContext>();
+    if (combobox.loading)() || !(combobox.visible)().is_empty() {
+        return rsx! {};
+    }
+
+    let class_name = utils::cn([Some("gap-2 p-6! text-sm"), class.as_deref()]);
+
+    rsx! {
+        Empty {
+            class: class_name,
+            div { role: "status", {children} }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_filter_matches_labels_ignoring_case() {
+        let option = ComboboxOption::new("ts", "TypeScript");
+        assert!(label_contains(&option, "script"));
+        assert!(label_contains(&option, "TYPE"));
+        // Values aren't matched, only what the user can see
+        assert!(!label_contains(&option, "ts"));
+    }
+}
@@ -0,0 +1,810 @@
+//! # Command Component
+//!
+//! A command palette: a search input over a list of commands, ranked by how well they
+//! fuzzy-match what the user types, for keyboard-driven navigation across an app.
+//!
+//! Items are scored with [`fuzzy_score`](crate::utils::fuzzy_score) against their `value`
+//! and `keywords`. Items that don't match are hidden, and the rest are sorted by score:
+//! items within a group, and groups by their best item. Focus stays in the input while
+//! ArrowDown/ArrowUp and Home/End move the highlight, and Enter runs the highlighted
+//! item's `on_select`.
+//!
+//! [`CommandDialog`] shows a palette in a [`Dialog`], opened and closed from anywhere with
+//! a global shortcut (Ctrl+K, or Cmd+K on macOS, by default).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Palette() -> Element {
+//!     let mut open = use_signal(|| false);
+//!     let mut page = use_signal(|| "Dashboard".to_string());
+//!     let mut go = move |to: String| {
+//!         page.set(to);
+//!         open.set(false);
+//!     };
+//!
+//!     rsx! {
+//!         CommandDialog {
+//!             open: open(),
+//!             on_open_change: move |next| open.set(next),
+//!             CommandInput { placeholder: "Type a command or search…" }
+//!             CommandList {
+//!                 CommandEmpty { "No results found." }
+//!                 CommandGroup {
+//!                     heading: "Pages",
+//!                     CommandItem { on_select: move |value| go(value), "Dashboard" }
+//!                     CommandItem {
+//!                         keywords: vec!["preferences".to_string()],
+//!                         on_select: move |value| go(value),
+//!                         "Settings"
+//!                         CommandShortcut { "⌘S" }
+//!                     }
+//!                 }
+//!                 CommandSeparator {}
+//!                 CommandGroup {
+//!                     heading: "Account",
+//!                     CommandItem { disabled: true, "Billing" }
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::dialog::{Dialog, DialogContent, DialogDescription, DialogTitle};
+use crate::components::portal::PortalContainer;
+use crate::platform::{use_event_listener, Hotkey, ListenerOptions, ListenerTarget};
+use crate::utils::{
+    self, element_text, fuzzy_score, use_collection, use_collection_item, use_controllable_state,
+    use_id, Collection, CollectionItem, ControllableState,
+};
+use dioxus::html::{ScrollBehavior, ScrollLogicalPosition, ScrollToOptions};
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/* -------------------------------------------------------------------------------------------------
+ * Command state
+ * -----------------------------------------------------------------------------------------------*/
+
+/// An item registered with its command, for ranking and keyboard navigation.
+#[derive(Clone)]
+struct ItemEntry {
+    id: String,
+    value: String,
+    keywords: Vec<String>,
+    disabled: bool,
+    /// The id of the `CommandGroup` the item is in.
+    group: Option<String>,
+    on_select: Option<EventHandler<String>>,
+}
+
+impl CollectionItem for ItemEntry {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl ItemEntry {
+    /// How well the item matches `search`: the best of its value and keywords.
+    fn score(&self, search: &str) -> f64 {
+        std::iter::once(&self.value)
+            .chain(&self.keywords)
+            .map(|text| fuzzy_score(text, search))
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Which items a search shows, and in what order.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Ranking {
+    /// Whether items are filtered and sorted: filtering is on and the search isn't empty.
+    filtered: bool,
+    /// Each item's score. Items scoring `0.0` are hidden.
+    scores: HashMap<String, f64>,
+    /// The CSS `order` of items and groups within their parent while filtered.
+    order: HashMap<String, usize>,
+    /// The groups with at least one item shown.
+    visible_groups: HashSet<String>,
+    /// The items that can be highlighted, in the order they are shown.
+    navigation: Vec<String>,
+    /// How many items are shown.
+    visible: usize,
+}
+
+/// Scores `items` against `search` and sorts them by score, keeping the order they were
+/// rendered in for ties. Groups are sorted by their best item, among the items outside
+/// any group. Without `filter` every item is shown in its place.
+fn rank(items: &[ItemEntry], search: &str, filter: bool) -> Ranking {
+    let filtered = filter && !search.trim().is_empty();
+    let scores: HashMap<String, f64> = items
+        .iter()
+        .map(|item| {
+            let score = if filtered { item.score(search) } else { 1.0 };
+            (item.id.clone(), score)
+        })
+        .collect();
+
+    // An ungrouped item is a block of its own; a group's items form one block
+    struct Block<'a> {
+        id: &'a str,
+        score: f64,
+        items: Vec<(&'a ItemEntry, f64)>,
+        grouped: bool,
+    }
+    let mut blocks: Vec<Block> = Vec::new();
+    for item in items {
+        let score = scores[&item.id];
+        let group = item.group.as_deref();
+        match group.and_then(|group| blocks.iter_mut().find(|block| block.id == group)) {
+            Some(block) => {
+                block.score = block.score.max(score);
+                block.items.push((item, score));
+            }
+            None => blocks.push(Block {
+                id: group.unwrap_or(&item.id),
+                score,
+                items: vec![(item, score)],
+                grouped: group.is_some(),
+            }),
+        }
+    }
+
+    let mut ranking = Ranking {
+        filtered,
+        ..Ranking::default()
+    };
+    if filtered {
+        // Stable sorts, so ties keep their rendered order
+        blocks.sort_by(|a, b| b.score.total_cmp(&a.score));
+        for (index, block) in blocks.iter_mut().enumerate() {
+            block.items.sort_by(|a, b| b.1.total_cmp(&a.1));
+            ranking.order.insert(block.id.to_string(), index);
+            if block.grouped {
+                for (index, (item, _)) in block.items.iter().enumerate() {
+                    ranking.order.insert(item.id.clone(), index);
+                }
+            }
+        }
+    }
+    for block in &blocks {
+        for (item, score) in &block.items {
+            if *score <= 0.0 {
+                continue;
+            }
+            ranking.visible += 1;
+            if let Some(group) = &item.group {
+                ranking.visible_groups.insert(group.clone());
+            }
+            if !item.disabled {
+                ranking.navigation.push(item.id.clone());
+            }
+        }
+    }
+    ranking.scores = scores;
+    ranking
+}
+
+#[derive(Clone)]
+struct CommandContext {
+    search: ControllableState<String>,
+    /// The items in document order. Their version is zero until the command has mounted.
+    items: Collection<ItemEntry>,
+    ranking: Memo<Ranking>,
+    /// The id of the highlighted item.
+    active: Signal<Option<String>>,
+    input_id: String,
+    list_id: String,
+}
+
+impl CommandContext {
+    /// Runs the `on_select` of item `id`, unless it is disabled.
+    fn select(&self, id: &str) {
+        let item = self
+            .items
+            .borrow()
+            .iter()
+            .find(|item| item.id == id)
+            .cloned();
+        if let Some(ItemEntry {
+            value,
+            disabled: false,
+            on_select: Some(on_select),
+            ..
+        }) = item
+        {
+            on_select.call(value);
+        }
+    }
+}
+
+/// Set by `CommandGroup` for the items inside it.
+#[derive(Clone)]
+struct CommandGroupContext {
+    id: String,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Command (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn Command(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Controlled search text
+    #[props(into)]
+    search: Option<String>,
+    /// Callback when the search text changes
+    on_search_change: Option<EventHandler<String>>,
+    /// Hide and sort items by how well they match the search. Turn it off to filter the
+    /// items yourself, e.g. from a server.
+    #[props(default = true)]
+    should_filter: bool,
+    /// Whether ArrowDown on the last item moves to the first, and ArrowUp back
+    #[props(default = false)]
+    r#loop: bool,
+    children: Element,
+) -> Element {
+    let search = use_controllable_state(search, String::new, on_search_change);
+    let items = use_collection::<ItemEntry>();
+    let mut active = use_signal(|| None::<String>);
+    let input_id = use_id("command-input");
+    let list_id = use_id("command-list");
+
+    let ranked_items = items.clone();
+    let ranking = use_memo(use_reactive!(|should_filter| {
+        let _ = ranked_items.version();
+        rank(&ranked_items.borrow(), &search.get(), should_filter)
+    }));
+
+    let context = use_context_provider(|| CommandContext {
+        search,
+        items: items.clone(),
+        ranking,
+        active,
+        input_id,
+        list_id,
+    });
+
+    // Items register during render; rank them once they have
+    use_effect(move || items.changed());
+
+    // Highlight the best match when the search changes, and move off items that went away
+    let mut last_search = use_hook(|| CopyValue::new(String::new()));
+    use_effect(move || {
+        let ranking = ranking();
+        let query = search.get();
+        let searched = *last_search.peek() != query;
+        last_search.set(query);
+
+        let current = active.peek().clone();
+        let kept = current
+            .as_ref()
+            .filter(|id| ranking.navigation.contains(id));
+        let next = match kept {
+            Some(id) if !searched => Some(id.clone()),
+            _ => ranking.navigation.first().cloned(),
+        };
+        if next != current {
+            active.set(next);
+        }
+    });
+
+    let key_context = context.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let navigation = ranking.peek().navigation.clone();
+        let current = active
+            .peek()
+            .as_ref()
+            .and_then(|id| navigation.iter().position(|item| item == id));
+        let last = navigation.len().checked_sub(1);
+        let next = match event.key() {
+            Key::ArrowDown => match current {
+                Some(index) if Some(index) == last && r#loop => Some(0),
+                Some(index) => Some((index + 1).min(navigation.len() - 1)),
+                None => last.map(|_| 0),
+            },
+            Key::ArrowUp => match current {
+                Some(0) if r#loop => last,
+                Some(index) => Some(index.saturating_sub(1)),
+                None => last,
+            },
+            Key::Home => last.map(|_| 0),
+            Key::End => last,
+            Key::Enter => {
+                if let Some(id) = active.peek().clone() {
+                    // Don't submit a surrounding form
+                    event.prevent_default();
+                    key_context.select(&id);
+                }
+                return;
+            }
+            _ => return,
+        };
+        event.prevent_default();
+        let next = next.map(|index| navigation[index].clone());
+        if next != *active.peek() {
+            active.set(next);
+        }
+    };
+
+    let class_name = utils::cn(vec![
+        Some("bg-popover text-popover-foreground flex h-full w-full flex-col overflow-hidden rounded-md"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            "data-slot": "command",
+            class: "{class_name}",
+            onkeydown,
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The search input. Focus stays here while the arrow keys move through the items.
+///
+/// `attributes` go to the `input`; label it with `aria-label` or a `<label for>`.
+#[component]
+pub fn CommandInput(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Shown while the input is empty
+    #[props(into, default = String::new())]
+    placeholder: String,
+) -> Element {
+    let command = use_context::<CommandContext>();
+    let mut search = command.search;
+    let active = command.active.read().clone();
+
+    let class_name = utils::cn(vec![
+        Some("placeholder:text-muted-foreground flex h-10 w-full rounded-md bg-transparent py-3 text-sm outline-hidden disabled:cursor-not-allowed disabled:opacity-50"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            "data-slot": "command-input-wrapper",
+            class: "flex h-9 items-center gap-2 border-b px-3",
+            svg {
+                class: "size-4 shrink-0 opacity-50",
+                "aria-hidden": "true",
+                xmlns: "http://www.w3.org/2000/svg",
+                width: "24",
+                height: "24",
+                view_box: "0 0 24 24",
+                fill: "none",
+                stroke: "currentColor",
+                stroke_width: "2",
+                stroke_linecap: "round",
+                stroke_linejoin: "round",
+                circle { cx: "11", cy: "11", r: "8" }
+                path { d: "m21 21-4.3-4.3" }
+            }
+            input {
+                r#type: "text",
+                role: "combobox",
+                id: "{command.input_id}",
+                class: "{class_name}",
+                autocomplete: "off",
+                autocorrect: "off",
+                spellcheck: "false",
+                placeholder,
+                value: "{search.get()}",
+                "aria-autocomplete": "list",
+                "aria-expanded": "true",
+                "aria-controls": "{command.list_id}",
+                "aria-activedescendant": active,
+                oninput: move |event: FormEvent| search.set(event.value()),
+                ..attributes,
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandList
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The scrollable list of items, groups and separators.
+#[component]
+pub fn CommandList(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let command = use_context::<CommandContext>();
+
+    let class_name = utils::cn(vec![
+        Some("flex max-h-[300px] scroll-py-1 flex-col overflow-x-hidden overflow-y-auto"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            role: "listbox",
+            id: "{command.list_id}",
+            class: "{class_name}",
+            "aria-labelledby": "{command.input_id}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandEmpty
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Shown while no item matches the search.
+#[component]
+pub fn CommandEmpty(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let command = use_context::<CommandContext>();
+    // Items aren't known until the command mounts
+    if command.items.version() == 0 || command.ranking.read().visible > 0 {
+        return rsx! {};
+    }
+
+    let class_name = utils::cn(vec![Some("py-6 text-center text-sm"), Some(class.as_str())]);
+
+    rsx! {
+        div {
+            role: "status",
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Items under a heading. Hidden while none of its items match the search.
+#[component]
+pub fn CommandGroup(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The heading shown above the items, which also labels the group
+    #[props(into)]
+    heading: Option<String>,
+    children: Element,
+) -> Element {
+    let command = use_context::<CommandContext>();
+    let id = use_id("command-group");
+    let heading_id = format!("{id}-heading");
+    let group_id = id.clone();
+    use_context_provider(|| CommandGroupContext { id: group_id });
+
+    let ranking = command.ranking.read();
+    let hidden = ranking.filtered && !ranking.visible_groups.contains(&id);
+    let order = ranking
+        .order
+        .get(&id)
+        .map(|order| format!("order: {order};"));
+
+    let class_name = utils::cn(vec![
+        Some("text-foreground flex flex-col overflow-hidden p-1"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            id: "{id}",
+            "data-slot": "command-group",
+            class: "{class_name}",
+            hidden,
+            style: order,
+            ..attributes,
+            if let Some(heading) = heading.as_ref() {
+                div {
+                    id: "{heading_id}",
+                    class: "text-muted-foreground px-2 py-1.5 text-xs font-medium",
+                    "{heading}"
+                }
+            }
+            div {
+                role: "group",
+                class: "flex flex-col",
+                "aria-labelledby": heading.as_ref().map(|_| heading_id.clone()),
+                {children}
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A command. Highlighting it scrolls it into view; clicking it or pressing Enter while
+/// it is highlighted runs `on_select` with its value.
+#[component]
+pub fn CommandItem(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The text matched against the search and passed to `on_select` (default: the item's
+    /// text)
+    #[props(into)]
+    value: Option<String>,
+    /// Other words the item matches, such as synonyms
+    #[props(default)]
+    keywords: Vec<String>,
+    /// Skip the item in keyboard navigation and ignore selecting it
+    #[props(default = false)]
+    disabled: bool,
+    /// Called with the item's value when it is selected
+    on_select: Option<EventHandler<String>>,
+    children: Element,
+) -> Element {
+    let command = use_context::<CommandContext>();
+    let group = try_use_context::<CommandGroupContext>().map(|group| group.id);
+    let id = use_id("command-item");
+    let value = value.unwrap_or_else(|| element_text(&children).trim().to_string());
+
+    use_collection_item(
+        &command.items,
+        ItemEntry {
+            id: id.clone(),
+            value: value.clone(),
+            keywords: keywords.clone(),
+            disabled,
+            group,
+            on_select,
+        },
+    );
+    let changed_items = command.items.clone();
+    use_effect(use_reactive!(|value, keywords, disabled| {
+        let _ = (value, keywords, disabled);
+        changed_items.changed();
+    }));
+
+    let mut active = command.active;
+    let is_active = active.read().as_deref() == Some(id.as_str());
+
+    let mut node = use_signal(|| None::<Rc<MountedData>>);
+    use_effect(use_reactive!(|is_active| {
+        if !is_active {
+            return;
+        }
+        if let Some(node) = node() {
+            spawn(async move {
+                let _ = node
+                    .scroll_to_with_options(ScrollToOptions {
+                        behavior: ScrollBehavior::Instant,
+                        vertical: ScrollLogicalPosition::Nearest,
+                        horizontal: ScrollLogicalPosition::Nearest,
+                    })
+                    .await;
+            });
+        }
+    }));
+
+    // Not ranked yet on the first render, so shown
+    let ranking = command.ranking.read();
+    let hidden = ranking.scores.get(&id).is_some_and(|score| *score <= 0.0);
+    let order = ranking
+        .order
+        .get(&id)
+        .map(|order| format!("order: {order};"));
+
+    let hovered_id = id.clone();
+    let onpointermove = move |_| {
+        if !disabled && active.peek().as_deref() != Some(hovered_id.as_str()) {
+            active.set(Some(hovered_id.clone()));
+        }
+    };
+    let selected_id = id.clone();
+    let select_command = command.clone();
+    let onclick = move |_| select_command.select(&selected_id);
+
+    let class_name = utils::cn(vec![
+        Some("relative flex cursor-default items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-hidden select-none data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50 [&_svg]:pointer-events-none [&_svg]:shrink-0 [&_svg:not([class*='size-'])]:size-4 [&_svg:not([class*='text-'])]:text-muted-foreground"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            role: "option",
+            id: "{id}",
+            class: "{class_name}",
+            hidden,
+            style: order,
+            "data-value": "{value}",
+            "aria-selected": if is_active { "true" } else { "false" },
+            "aria-disabled": if disabled { Some("true") } else { None },
+            "data-highlighted": if is_active { Some("") } else { None },
+            "data-disabled": if disabled { Some("") } else { None },
+            onmounted: move |event: MountedEvent| node.set(Some(event.data())),
+            onpointermove,
+            onclick,
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A line between groups. Hidden while searching, since sorting moves groups around.
+#[component]
+pub fn CommandSeparator(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+) -> Element {
+    let command = use_context::<CommandContext>();
+    let hidden = command.ranking.read().filtered;
+
+    let class_name = utils::cn(vec![Some("bg-border -mx-1 h-px"), Some(class.as_str())]);
+
+    rsx! {
+        div {
+            role: "separator",
+            class: "{class_name}",
+            hidden,
+            ..attributes,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandShortcut
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The keyboard shortcut for an item, shown at its end.
+#[component]
+pub fn CommandShortcut(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("text-muted-foreground ml-auto text-xs tracking-widest"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        span {
+            "data-slot": "command-shortcut",
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandDialog
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A [`Command`] in a modal [`Dialog`], toggled from anywhere in the app by `hotkey`.
+/// The children are the command's parts, starting with a [`CommandInput`], which takes
+/// focus when the dialog opens.
+#[component]
+pub fn CommandDialog(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the palette is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// The shortcut that opens and closes the palette, such as `"mod+k"` for Ctrl+K, or
+    /// Cmd+K on macOS. See [`Hotkey`] for the syntax; an empty string turns it off.
+    #[props(default = "mod+k")]
+    hotkey: &'static str,
+    /// The dialog title for screen readers
+    #[props(into, default = "Command Palette".to_string())]
+    title: String,
+    /// The dialog description for screen readers
+    #[props(into, default = "Search for a command to run...".to_string())]
+    description: String,
+    /// Hide and sort items by how well they match the search
+    #[props(default = true)]
+    should_filter: bool,
+    /// Whether ArrowDown on the last item moves to the first, and ArrowUp back
+    #[props(default = false)]
+    r#loop: bool,
+    /// Where the dialog is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    children: Element,
+) -> Element {
+    let mut state = use_controllable_state(open, || default_open, on_open_change);
+
+    let bound = Hotkey::parse(hotkey).is_some();
+    use_event_listener(
+        ListenerTarget::Window,
+        "keydown",
+        ListenerOptions {
+            hotkey: Some(hotkey),
+            prevent_default: true,
+            ..ListenerOptions::default()
+        },
+        bound,
+        move |_| {
+            let open = state.peek();
+            state.set(!open);
+        },
+    );
+
+    let class_name = utils::cn(vec![Some("overflow-hidden p-0"), Some(class.as_str())]);
+
+    rsx! {
+        Dialog {
+            open: state.get(),
+            on_open_change: move |open| state.set(open),
+            DialogContent {
+                class: class_name,
+                container,
+                attributes,
+                DialogTitle { class: "sr-only", "{title}" }
+                DialogDescription { class: "sr-only", "{description}" }
+                Command {
+                    should_filter,
+                    r#loop,
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, value: &str, group: Option<&str>) -> ItemEntry {
+        ItemEntry {
+            id: id.to_string(),
+            value: value.to_string(),
+            keywords: Vec::new(),
+            disabled: false,
+            group: group.map(str::to_string),
+            on_select: None,
+        }
+    }
+
+    #[test]
+    fn ranks_groups_by_their_best_item() {
+        let items = [
+            item("calendar", "Calendar", Some("suggestions")),
+            item("emoji", "Emoji Search", Some("suggestions")),
+            item("profile", "Profile", Some("settings")),
+            item("settings", "Settings", Some("settings")),
+            item("help", "Help", None),
+        ];
+
+        let ranking = rank(&items, "se", true);
+        assert_eq!(ranking.navigation, ["settings", "emoji"]);
+        assert_eq!(ranking.visible, 2);
+        assert_eq!(ranking.order["settings"], 0);
+        assert_eq!(ranking.order["suggestions"], 1);
+        assert_eq!(ranking.scores["help"], 0.0);
+
+        let unfiltered = rank(&items, "se", false);
+        assert!(!unfiltered.filtered && unfiltered.order.is_empty());
+        assert_eq!(unfiltered.navigation.len(), 5);
+    }
+}
@@ -0,0 +1,240 @@
+//! # ContextMenu Component
+//!
+//! A menu opened by right-clicking an area, such as a file in a file browser, at the
+//! pointer position. It shares its items, keyboard support and submenus with
+//! [`DropdownMenu`](crate::components::dropdown_menu::DropdownMenu), and is placed against
+//! the point with the same collision handling: it opens to the right of and below the
+//! pointer, and flips or shifts to stay inside the viewport.
+//!
+//! Browsers that don't send `contextmenu` for a long touch press (such as iOS Safari) are
+//! covered by opening on a long press too, which is on by default with the `mobile`
+//! feature.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn FileRow(name: String) -> Element {
+//!     let mut renaming = use_signal(|| false);
+//!
+//!     rsx! {
+//!         ContextMenu {
+//!             ContextMenuTrigger {
+//!                 class: "flex items-center gap-2 rounded px-2 py-1",
+//!                 "{name}"
+//!             }
+//!             ContextMenuContent {
+//!                 ContextMenuItem { on_select: move |_| renaming.set(true), "Rename" }
+//!                 ContextMenuItem { "Duplicate" }
+//!                 ContextMenuSeparator {}
+//!                 ContextMenuItem { disabled: true, "Delete" }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::menu::{
+    use_menu, MenuContent, MenuContext, MenuTrigger, OpenFocus, MENU_CSS,
+};
+use crate::components::portal::PortalContainer;
+use crate::platform::{clear_timeout, set_timeout, ListenerEvent};
+use crate::utils::floating::{Align, Anchor, Side};
+use crate::utils::merge_attributes;
+use dioxus::core::Task;
+use dioxus::html::events::{
+    oncontextmenu, onpointercancel, onpointerdown, onpointermove, onpointerup,
+};
+use dioxus::prelude::*;
+
+pub use crate::components::menu::{
+    MenuCheckboxItem as ContextMenuCheckboxItem, MenuGroup as ContextMenuGroup,
+    MenuItem as ContextMenuItem, MenuItemIndicator as ContextMenuItemIndicator,
+    MenuLabel as ContextMenuLabel, MenuRadioGroup as ContextMenuRadioGroup,
+    MenuRadioItem as ContextMenuRadioItem, MenuSeparator as ContextMenuSeparator,
+    MenuShortcut as ContextMenuShortcut, MenuSub as ContextMenuSub,
+    MenuSubContent as ContextMenuSubContent, MenuSubTrigger as ContextMenuSubTrigger,
+};
+
+/// How long a touch has to be held still to open the menu.
+const LONG_PRESS_MS: u64 = 700;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenu (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn ContextMenu(
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// Whether the page behind the open menu can't be scrolled or clicked
+    #[props(default = true)]
+    modal: bool,
+    children: Element,
+) -> Element {
+    use_menu(None, false, on_open_change, modal, MenuTrigger::Area);
+
+    rsx! {
+        style { {MENU_CSS} }
+        {children}
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The area that opens the menu when right-clicked.
+#[component]
+pub fn ContextMenuTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    /// Leave right-clicks and long presses to the browser
+    #[props(default = false)]
+    disabled: bool,
+    /// Also open on a long touch press (default: on with the `mobile` feature)
+    #[props(default = cfg!(feature = "mobile"))]
+    long_press: bool,
+    children: Element,
+) -> Element {
+    let menu = use_context::<MenuContext>();
+    let is_open = menu.is_open();
+    let trigger_id = menu.trigger_id.clone();
+    let mut anchor = menu.anchor;
+    let mut press_timer = use_hook(|| CopyValue::new(None::<Task>));
+
+    // Opening again elsewhere while open just moves the menu
+    let open_at = use_callback(move |(x, y): (f64, f64)| {
+        anchor.set(Some(Anchor::point(x, y)));
+        menu.open_with_focus(OpenFocus::Content);
+    });
+
+    let mut cancel_press = move || {
+        if let Some(task) = press_timer.take() {
+            clear_timeout(task);
+        }
+    };
+
+    let mut trigger_attributes = vec![
+        Attribute::new("id", trigger_id, None, false),
+        Attribute::new(
+            "data-state",
+            if is_open { "open" } else { "closed" },
+            None,
+            false,
+        ),
+    ];
+    if disabled {
+        trigger_attributes.push(Attribute::new("data-disabled", "", None, false));
+    } else {
+        trigger_attributes.push(oncontextmenu(move |event: MouseEvent| {
+            event.prevent_default();
+            cancel_press();
+            let point = event.client_coordinates();
+            open_at.call((point.x, point.y));
+        }));
+    }
+    if long_press && !disabled {
+        trigger_attributes.extend([
+            // Keep iOS from showing its own callout for the press
+            Attribute::new("style", "-webkit-touch-callout: none;", None, false),
+            onpointerdown(move |event: PointerEvent| {
+                if event.pointer_type() == "mouse" {
+                    return;
+                }
+                cancel_press();
+                let point = event.client_coordinates();
+                press_timer.set(Some(set_timeout(
+                    move || {
+                        press_timer.set(None);
+                        open_at.call((point.x, point.y));
+                    },
+                    LONG_PRESS_MS,
+                )));
+            }),
+            onpointermove(move |event: PointerEvent| {
+                if event.pointer_type() != "mouse" {
+                    cancel_press();
+                }
+            }),
+            onpointercancel(move |_| cancel_press()),
+            onpointerup(move |_| cancel_press()),
+        ]);
+    }
+    let trigger_attributes = merge_attributes(trigger_attributes, attributes);
+
+    rsx! {
+        div {
+            ..trigger_attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn ContextMenuContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// An offset in pixels along the pointer's vertical axis
+    #[props(default = 0)]
+    align_offset: i32,
+    /// Flip and shift the content to keep it inside the viewport
+    #[props(default = true)]
+    avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in
+    #[props(default = 0)]
+    collision_padding: i32,
+    /// Wrap arrow key navigation from the last item to the first and back
+    #[props(default = false)]
+    r#loop: bool,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when Escape is pressed while this menu is the innermost open one. Call
+    /// `prevent_default` to keep it open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside the menu. Call `prevent_default` to keep it
+    /// open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when focus moves outside the menu. Call `prevent_default` to keep it open.
+    on_focus_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer press or focus lands outside the menu. Call `prevent_default`
+    /// to keep it open.
+    on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called before focus returns to where it was when the menu closes. Call
+    /// `prevent_default` to leave focus alone.
+    on_close_auto_focus: Option<EventHandler<Event<()>>>,
+    /// Keep the content mounted while closed, e.g. to drive its animation from outside
+    #[props(default = false)]
+    force_mount: bool,
+    children: Element,
+) -> Element {
+    rsx! {
+        MenuContent {
+            class,
+            side: Side::Right,
+            side_offset: 2,
+            align: Align::Start,
+            align_offset,
+            avoid_collisions,
+            collision_padding,
+            r#loop,
+            container,
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_focus_outside,
+            on_interact_outside,
+            on_close_auto_focus,
+            force_mount,
+            attributes,
+            {children}
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod context_menu;
+
+pub use context_menu::*;
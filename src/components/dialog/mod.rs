@@ -1,19 +1,42 @@
-use crate::components::portal::Portal;
+use crate::components::dismissable_layer::{
+    use_dismissable_layer, DismissReason, DismissableLayerOptions,
+};
+use crate::components::focus_scope::FocusScope;
+use crate::components::portal::{Portal, PortalContainer};
+use crate::components::presence::use_presence;
+use crate::platform::ListenerEvent;
+use crate::utils::{
+    merge_attributes, use_controllable_state, use_id, use_layer, use_scroll_lock,
+    ControllableState, Layer,
+};
+use dioxus::core::AttributeValue;
 use dioxus::prelude::*;
-use wasm_bindgen::JsCast;
+use std::cell::Cell;
+use std::rc::Rc;
 
 /* -------------------------------------------------------------------------------------------------
  * Dialog Context
  * -----------------------------------------------------------------------------------------------*/
 
 #[derive(Clone)]
-struct DialogContext {
-    open: Signal<bool>,
+pub(crate) struct DialogContext {
+    open: ControllableState<bool>,
     modal: bool,
+    trigger_id: String,
     content_id: String,
     title_id: String,
     description_id: String,
-    on_open_change: Option<EventHandler<bool>>,
+    /// The dialog's place among open overlays; decides z-index and who gets Escape.
+    layer: Layer,
+}
+
+impl DialogContext {
+    /// Opens or closes the dialog and reports the change through `on_open_change`. A
+    /// controlled dialog only changes once its parent passes the new state back down.
+    pub(crate) fn set_open(&self, open: bool) {
+        let mut state = self.open;
+        state.set(open);
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -35,42 +58,24 @@ pub fn Dialog(
     children: Element,
 ) -> Element {
     // Controlled vs Uncontrolled state
-    let mut internal_open = use_signal(|| open.unwrap_or(default_open));
-
-    // Sync controlled state if provided
-    use_effect(move || {
-        if let Some(controlled_open) = open {
-            internal_open.set(controlled_open);
-        }
-    });
+    let open = use_controllable_state(open, || default_open, on_open_change);
 
     // Generate unique IDs for accessibility
-    let content_id = use_memo(move || {
-        format!(
-            "dialog-content-{}",
-            (js_sys::Math::random() * 1_000_000_000.0) as u64
-        )
-    });
-    let title_id = use_memo(move || {
-        format!(
-            "dialog-title-{}",
-            (js_sys::Math::random() * 1_000_000_000.0) as u64
-        )
-    });
-    let description_id = use_memo(move || {
-        format!(
-            "dialog-description-{}",
-            (js_sys::Math::random() * 1_000_000_000.0) as u64
-        )
-    });
+    let trigger_id = use_id("dialog-trigger");
+    let content_id = use_id("dialog-content");
+    let title_id = use_id("dialog-title");
+    let description_id = use_id("dialog-description");
+
+    let layer = use_layer(open.get());
 
     let context = DialogContext {
-        open: internal_open,
+        open,
         modal,
-        content_id: content_id().to_string(),
-        title_id: title_id().to_string(),
-        description_id: description_id().to_string(),
-        on_open_change,
+        trigger_id,
+        content_id,
+        title_id,
+        description_id,
+        layer,
     };
 
     use_context_provider(|| context);
@@ -88,25 +93,19 @@ pub fn DialogTrigger(
     children: Element,
 ) -> Element {
     let context = use_context::<DialogContext>();
-    let mut open = context.open;
-
-    let onclick = move |_event: Event<MouseData>| {
-        let new_state = !open();
-        open.set(new_state);
+    let open = context.open;
 
-        // Call on_open_change callback if provided
-        if let Some(handler) = &context.on_open_change {
-            handler.call(new_state);
-        }
-    };
+    let toggle = context.clone();
+    let onclick = move |_event: Event<MouseData>| toggle.set_open(!open.peek());
 
     rsx! {
         button {
             r#type: "button",
+            id: context.trigger_id,
             "aria-haspopup": "dialog",
-            "aria-expanded": if open() { "true" } else { "false" },
+            "aria-expanded": if open.get() { "true" } else { "false" },
             "aria-controls": context.content_id,
-            "data-state": if open() { "open" } else { "closed" },
+            "data-state": if open.get() { "open" } else { "closed" },
             onclick: onclick,
             ..attributes,
             {children}
@@ -122,19 +121,29 @@ pub fn DialogTrigger(
 pub fn DialogOverlay(
     #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
     #[props(default = String::new())] class: String,
+    /// Keep the overlay mounted while closed, e.g. to drive its animation from outside
+    #[props(default = false)]
+    force_mount: bool,
     children: Element,
 ) -> Element {
     let context = use_context::<DialogContext>();
     let open = context.open;
 
+    // Stays mounted after closing until its exit animation finishes
+    let overlay_id = use_id("dialog-overlay");
+    let presence = use_presence(open.get(), overlay_id.clone());
+
     if !context.modal {
         return rsx! { {children} };
     }
 
-    if !open() {
+    if !force_mount && !presence.is_present() {
         return rsx! {};
     }
 
+    // A closing overlay no longer blocks the page
+    let pointer_events = if open.get() { "auto" } else { "none" };
+
     let combined_class = if class.is_empty() {
         "dialog-overlay".to_string()
     } else {
@@ -143,9 +152,10 @@ pub fn DialogOverlay(
 
     rsx! {
         div {
+            id: overlay_id,
             class: combined_class,
-            "data-state": if open() { "open" } else { "closed" },
-            style: "position: fixed; inset: 0; background-color: rgba(0, 0, 0, 0.5); z-index: 9998; pointer-events: auto;",
+            "data-state": if open.get() { "open" } else { "closed" },
+            style: "position: fixed; inset: 0; background-color: rgba(0, 0, 0, 0.5); z-index: {context.layer.z_index()}; pointer-events: {pointer_events};",
             ..attributes,
             {children}
         }
@@ -160,25 +170,40 @@ pub fn DialogOverlay(
 pub fn DialogContent(
     #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
     #[props(default = String::new())] class: String,
-    /// Container element selector for the portal (default: "body")
-    #[props(default = "body".to_string())]
-    container: String,
-    /// Whether clicking outside should close the dialog
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Whether clicking outside should close the dialog. Non-modal dialogs also close when
+    /// focus moves outside.
     #[props(default = true)]
     close_on_outside_click: bool,
     /// Whether pressing Escape should close the dialog
     #[props(default = true)]
     close_on_escape: bool,
+    /// Called when Escape is pressed while the dialog is open. Call `prevent_default` on the
+    /// event to keep the dialog open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Non-modal only: called when a pointer is pressed outside the dialog. Call
+    /// `prevent_default` to keep it open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Non-modal only: called when a pointer press or focus lands outside the dialog. Call
+    /// `prevent_default` to keep it open.
+    on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called before focus moves into the dialog when it opens. Call `prevent_default` to
+    /// focus something else yourself.
+    on_open_auto_focus: Option<EventHandler<Event<()>>>,
+    /// Keep the content mounted while closed, e.g. to drive its animation from outside
+    #[props(default = false)]
+    force_mount: bool,
     children: Element,
 ) -> Element {
     let context = use_context::<DialogContext>();
-    let mut open = context.open;
-    let content_id = context.content_id.clone();
+    let open = context.open;
     let is_modal = context.modal;
 
-    if !open() {
-        return rsx! {};
-    }
+    // Stays mounted after closing until its exit animation finishes
+    let presence = use_presence(open.get(), context.content_id.clone());
 
     let combined_class = if class.is_empty() {
         "dialog-content".to_string()
@@ -191,177 +216,98 @@ pub fn DialogContent(
     let content_id_for_jsx = context.content_id.clone();
     let title_id_for_jsx = context.title_id.clone();
     let description_id_for_jsx = context.description_id.clone();
-    let on_open_change = context.on_open_change.clone();
-
-    // Body scroll lock for modal dialogs (with layout shift prevention)
-    use_effect(move || {
-        if open() && is_modal {
-            // Lock body scroll using JavaScript with scrollbar width compensation
-            let lock_scroll_js = r#"
-                (function() {
-                    if (!document.body) return;
-                    
-                    // Calculate scrollbar width before hiding it
-                    const scrollbarWidth = window.innerWidth - document.documentElement.clientWidth;
-                    
-                    // Store original values for restoration
-                    window._originalOverflow = document.body.style.overflow;
-                    window._originalPaddingRight = document.body.style.paddingRight;
-                    
-                    // Lock scroll and compensate for scrollbar width
-                    document.body.style.overflow = 'hidden';
-                    if (scrollbarWidth > 0) {
-                        document.body.style.paddingRight = scrollbarWidth + 'px';
-                    }
-                })();
-            "#;
 
-            if let Ok(_) = js_sys::eval(lock_scroll_js) {
-                // Scroll is locked with layout shift prevention
-            }
-        } else {
-            // Unlock scroll and restore original padding
-            let unlock_scroll_js = r#"
-                (function() {
-                    if (!document.body) return;
-                    
-                    // Restore original values
-                    document.body.style.overflow = window._originalOverflow || '';
-                    document.body.style.paddingRight = window._originalPaddingRight || '';
-                    
-                    // Clean up stored values
-                    delete window._originalOverflow;
-                    delete window._originalPaddingRight;
-                })();
-            "#;
-
-            let _ = js_sys::eval(unlock_scroll_js);
-        }
-    });
+    // Body scroll lock for modal dialogs, released when the dialog closes or this
+    // component unmounts. Nested dialogs share one reference-counted lock.
+    use_scroll_lock(open.get() && is_modal);
+
+    // Close on Escape while this is the topmost open layer. Modal dialogs close from their
+    // backdrop; non-modal ones have none, so they close on pointer presses and focus outside.
+    // The trigger counts as inside, since it already toggles the dialog.
+    let layer = context.layer;
+    let dismiss_context = context.clone();
+    let interacted_outside = use_hook(|| Rc::new(Cell::new(false)));
+    let dismissed_outside = interacted_outside.clone();
+    use_dismissable_layer(
+        layer,
+        context.content_id.clone(),
+        DismissableLayerOptions {
+            escape: close_on_escape,
+            outside: !is_modal && close_on_outside_click,
+            inside_ids: vec![context.trigger_id.clone()],
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_interact_outside,
+            ..DismissableLayerOptions::default()
+        },
+        move |reason| {
+            dismissed_outside.set(reason != DismissReason::Escape);
+            dismiss_context.set_open(false);
+        },
+    );
 
-    // Focus trap for modal dialogs
-    use_effect(move || {
-        if !open() || !is_modal {
-            return;
+    // Handle backdrop click
+    let on_backdrop_click = move |_event: Event<MouseData>| {
+        // Only the topmost dialog reacts, so an outer dialog never closes from under an
+        // inner one
+        if close_on_outside_click && layer.is_topmost() {
+            context.set_open(false);
         }
+    };
+
+    if !force_mount && !presence.is_present() {
+        return rsx! {};
+    }
 
-        let dialog_id = content_id.clone();
-
-        // Set up focus trap using JavaScript
-        let trap_js = format!(
-            r#"
-            (function() {{
-                const dialog = document.getElementById('{}');
-                if (!dialog) return;
-                
-                // Focus first focusable element
-                const focusableElements = dialog.querySelectorAll(
-                    'button, [href], input, select, textarea, [tabindex]:not([tabindex="-1"])'
-                );
-                if (focusableElements.length > 0) {{
-                    focusableElements[0].focus();
-                }}
-                
-                // Set up focus trap
-                const handleTab = (e) => {{
-                    if (e.key !== 'Tab') return;
-                    
-                    const focusable = Array.from(focusableElements);
-                    const firstFocusable = focusable[0];
-                    const lastFocusable = focusable[focusable.length - 1];
-                    
-                    if (e.shiftKey) {{
-                        if (document.activeElement === firstFocusable) {{
-                            lastFocusable.focus();
-                            e.preventDefault();
-                        }}
-                    }} else {{
-                        if (document.activeElement === lastFocusable) {{
-                            firstFocusable.focus();
-                            e.preventDefault();
-                        }}
-                    }}
-                }};
-                
-                dialog.addEventListener('keydown', handleTab);
-                
-                // Store cleanup function
-                window._dialogFocusTrapCleanup = () => {{
-                    dialog.removeEventListener('keydown', handleTab);
-                }};
-            }})();
-            "#,
-            dialog_id
-        );
-
-        let _ = js_sys::eval(&trap_js);
-    });
-
-    // Enhanced Escape key handler
-    use_effect(move || {
-        if !close_on_escape || !open() {
+    let z_index = layer.z_index();
+    let pointer_events = if open.get() { "auto" } else { "none" };
+    let state = if open.get() { "open" } else { "closed" };
+
+    // Content mounted while closed doesn't take focus. Opening starts without an outside
+    // interaction, so closing later returns focus unless one happens.
+    let outside_interaction = interacted_outside.clone();
+    let on_mount_auto_focus = move |event: Event<()>| {
+        outside_interaction.set(false);
+        if !open.peek() {
+            event.prevent_default();
             return;
         }
-
-        let escape_handler_js = format!(
-            r#"
-            (function() {{
-                const handleEscape = (e) => {{
-                    if (e.key === 'Escape') {{
-                        e.preventDefault();
-                        e.stopPropagation();
-                        // This will be handled by the Dialog state
-                    }}
-                }};
-                
-                document.addEventListener('keydown', handleEscape);
-                
-                window._dialogEscapeCleanup = () => {{
-                    document.removeEventListener('keydown', handleEscape);
-                }};
-            }})();
-            "#
-        );
-
-        let _ = js_sys::eval(&escape_handler_js);
-
-        // Also set up Dioxus event handler
-        let ctx_for_handler = context.clone();
-        let closure =
-            wasm_bindgen::closure::Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
-                if e.key() == "Escape" {
-                    e.prevent_default();
-                    e.stop_propagation();
-                    open.set(false);
-
-                    // Call on_open_change callback if provided
-                    if let Some(handler) = &ctx_for_handler.on_open_change {
-                        handler.call(false);
-                    }
-                }
-            }) as Box<dyn FnMut(_)>);
-
-        if let Some(window) = web_sys::window() {
-            if let Some(document) = window.document() {
-                let _ = document
-                    .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
-            }
+        if let Some(handler) = &on_open_auto_focus {
+            handler.call(event);
         }
+    };
 
-        closure.forget();
-    });
-
-    // Handle backdrop click
-    let on_backdrop_click = move |_event: Event<MouseData>| {
-        if close_on_outside_click {
-            open.set(false);
-
-            // Call on_open_change callback if provided
-            if let Some(handler) = &on_open_change {
-                handler.call(false);
+    // A caller `style` is appended so the content keeps its position and z-index; other
+    // caller attributes replace the defaults of the same name, and caller listeners run
+    // first
+    let mut style = format!("position: fixed; z-index: {};", z_index + 1);
+    let mut overrides = Vec::new();
+    for attribute in attributes {
+        match (attribute.name, &attribute.value) {
+            ("style", AttributeValue::Text(extra)) => {
+                style.push(' ');
+                style.push_str(extra);
             }
+            _ => overrides.push(attribute),
         }
-    };
+    }
+
+    let content_attributes = vec![
+        Attribute::new("role", "dialog", None, false),
+        Attribute::new("id", content_id_for_jsx, None, false),
+        Attribute::new("aria-labelledby", title_id_for_jsx, None, false),
+        Attribute::new("aria-describedby", description_id_for_jsx, None, false),
+        Attribute::new(
+            "aria-modal",
+            if modal { "true" } else { "false" },
+            None,
+            false,
+        ),
+        Attribute::new("data-state", state, None, false),
+        Attribute::new("class", combined_class, None, false),
+        Attribute::new("style", style, None, false),
+    ];
+    let content_attributes = merge_attributes(content_attributes, overrides);
 
     rsx! {
         Portal {
@@ -370,22 +316,24 @@ pub fn DialogContent(
             if modal {
                 div {
                     class: "dialog-backdrop",
-                    style: "position: fixed; inset: 0; z-index: 9998;",
+                    "data-state": state,
+                    style: "position: fixed; inset: 0; z-index: {z_index}; pointer-events: {pointer_events};",
                     onclick: on_backdrop_click,
                 }
             }
-            // Dialog content
-            div {
-                role: "dialog",
-                id: content_id_for_jsx,
-                "aria-labelledby": title_id_for_jsx,
-                "aria-describedby": description_id_for_jsx,
-                "aria-modal": if modal { "true" } else { "false" },
-                "data-state": if open() { "open" } else { "closed" },
-                class: combined_class,
-                style: "position: fixed; z-index: 9999;",
-                tabindex: "-1",
-                ..attributes,
+            // Dialog content. Modal dialogs keep focus inside; focus returns to the trigger
+            // when the content unmounts
+            FocusScope {
+                trapped: modal && open.get(),
+                r#loop: true,
+                on_mount_auto_focus,
+                // Leave focus wherever the user clicked or tabbed to
+                on_unmount_auto_focus: move |event: Event<()>| {
+                    if interacted_outside.get() {
+                        event.prevent_default();
+                    }
+                },
+                attributes: content_attributes,
                 {children}
             }
         }
@@ -458,16 +406,7 @@ pub fn DialogClose(
     children: Element,
 ) -> Element {
     let context = use_context::<DialogContext>();
-    let mut open = context.open;
-
-    let onclick = move |_event: Event<MouseData>| {
-        open.set(false);
-
-        // Call on_open_change callback if provided
-        if let Some(handler) = &context.on_open_change {
-            handler.call(false);
-        }
-    };
+    let onclick = move |_event: Event<MouseData>| context.set_open(false);
 
     rsx! {
         button {
@@ -0,0 +1,244 @@
+//! # DismissableLayer
+//!
+//! Dismisses an overlay when the user interacts outside it:
+//!
+//! - pressing a pointer (mouse, touch, pen) down outside the element,
+//! - moving focus outside the element,
+//! - pressing Escape.
+//!
+//! Each can be cancelled by calling `prevent_default` on the event passed to
+//! `on_pointer_down_outside`, `on_focus_outside`, `on_interact_outside` (both outside
+//! interactions) or `on_escape_key_down`. Only the topmost open layer reacts, so
+//! interacting with a nested popover doesn't dismiss the dialog it was opened from.
+//!
+//! Unlike a full-screen backdrop this doesn't block the page, so it suits non-modal
+//! overlays such as popovers and menus. Components use [`use_dismissable_layer`] on their
+//! own element; [`DismissableLayer`] wraps arbitrary content in a `div` that does the same:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Filters() -> Element {
+//!     let mut open = use_signal(|| false);
+//!
+//!     rsx! {
+//!         button { onclick: move |_| open.set(true), "Filters" }
+//!         if open() {
+//!             DismissableLayer {
+//!                 class: "filters-panel",
+//!                 on_dismiss: move |_| open.set(false),
+//!                 label { input { r#type: "checkbox" } "In stock" }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::platform::{use_event_listener, ListenerEvent, ListenerOptions, ListenerTarget};
+use crate::utils::{take_id_attribute, use_id, use_layer, use_layer_escape, Layer};
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Why a layer was dismissed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DismissReason {
+    Escape,
+    PointerDownOutside,
+    FocusOutside,
+}
+
+/// What dismisses a layer managed by [`use_dismissable_layer`], and the callbacks that can
+/// cancel it.
+#[derive(Clone, Default, PartialEq)]
+pub struct DismissableLayerOptions {
+    /// Dismiss on Escape.
+    pub escape: bool,
+    /// Dismiss on a pointer press or focus outside the element.
+    pub outside: bool,
+    /// Ids of other elements that count as part of the layer, such as the trigger that
+    /// toggles it.
+    pub inside_ids: Vec<String>,
+    /// Called on Escape. Call `prevent_default` to keep the layer.
+    pub on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside. Call `prevent_default` to keep the layer.
+    pub on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when focus moves outside. Call `prevent_default` to keep the layer.
+    pub on_focus_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called after either outside handler, unless it prevented the dismissal. Call
+    /// `prevent_default` to keep the layer.
+    pub on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+}
+
+/// Calls `on_dismiss` when the user interacts outside the element with `element_id`, or
+/// presses Escape, while `layer` is the topmost open layer.
+///
+/// The outside listeners are only attached while the layer is open.
+pub fn use_dismissable_layer(
+    layer: Layer,
+    element_id: String,
+    options: DismissableLayerOptions,
+    on_dismiss: impl FnMut(DismissReason) + 'static,
+) {
+    let on_dismiss = use_callback(on_dismiss);
+
+    let escape_options = options.clone();
+    use_layer_escape(layer, move |key: ListenerEvent| {
+        if !escape_options.escape {
+            return;
+        }
+        let event = Event::new(Rc::new(key), false);
+        if let Some(handler) = &escape_options.on_escape_key_down {
+            handler.call(event.clone());
+        }
+        if event.default_action_enabled() {
+            on_dismiss.call(DismissReason::Escape);
+        }
+    });
+
+    // Decides whether an event outside the element dismisses the layer
+    let outside = move |event: ListenerEvent,
+                        handler: Option<EventHandler<Event<ListenerEvent>>>,
+                        on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+                        reason: DismissReason| {
+        let event = Event::new(Rc::new(event), false);
+        if let Some(handler) = &handler {
+            handler.call(event.clone());
+        }
+        if !event.default_action_enabled() {
+            return;
+        }
+        if let Some(handler) = &on_interact_outside {
+            handler.call(event.clone());
+        }
+        if event.default_action_enabled() {
+            on_dismiss.call(reason);
+        }
+    };
+
+    // Nested layers can be portaled anywhere, so only the topmost layer treats an event
+    // outside its own element as outside
+    let is_outside = {
+        let element_id = element_id.clone();
+        let inside_ids = options.inside_ids.clone();
+        move |event: &ListenerEvent| {
+            layer.is_topmost()
+                && !event.is_within(&element_id)
+                && !inside_ids.iter().any(|id| event.is_within(id))
+        }
+    };
+
+    let active = options.outside && layer.is_open();
+    let capture = ListenerOptions {
+        capture: true,
+        ..ListenerOptions::default()
+    };
+
+    // Pressing a pointer also moves focus. That focus change is part of the press, which
+    // was already judged when it started (possibly while another layer was on top).
+    let pressing = use_hook(|| Rc::new(Cell::new(false)));
+
+    let pointer_options = options.clone();
+    let pointer_is_outside = is_outside.clone();
+    let pressed = pressing.clone();
+    use_event_listener(
+        ListenerTarget::Document,
+        "pointerdown",
+        capture,
+        active,
+        move |event| {
+            pressed.set(true);
+            if pointer_is_outside(&event) {
+                outside(
+                    event,
+                    pointer_options.on_pointer_down_outside,
+                    pointer_options.on_interact_outside,
+                    DismissReason::PointerDownOutside,
+                );
+            }
+        },
+    );
+
+    let released = pressing.clone();
+    use_event_listener(
+        ListenerTarget::Document,
+        "pointerup",
+        capture,
+        active,
+        move |_| released.set(false),
+    );
+
+    use_event_listener(
+        ListenerTarget::Document,
+        "focusin",
+        capture,
+        active,
+        move |event| {
+            if !pressing.get() && is_outside(&event) {
+                outside(
+                    event,
+                    options.on_focus_outside,
+                    options.on_interact_outside,
+                    DismissReason::FocusOutside,
+                );
+            }
+        },
+    );
+}
+
+#[component]
+pub fn DismissableLayer(
+    /// Whether Escape dismisses the layer
+    #[props(default = true)]
+    dismiss_on_escape: bool,
+    /// Called on Escape. Call `prevent_default` to keep the layer.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside. Call `prevent_default` to keep the layer.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when focus moves outside. Call `prevent_default` to keep the layer.
+    on_focus_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called on any interaction outside that wasn't prevented. Call `prevent_default` to
+    /// keep the layer.
+    on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when the layer should go away; the owner decides how to hide it.
+    on_dismiss: Option<EventHandler<DismissReason>>,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let generated_id = use_id("dismissable-layer");
+
+    let mut attributes = attributes;
+    let id = take_id_attribute(&mut attributes, generated_id);
+
+    // Mounted means open
+    let layer = use_layer(true);
+    use_dismissable_layer(
+        layer,
+        id.clone(),
+        DismissableLayerOptions {
+            escape: dismiss_on_escape,
+            outside: true,
+            inside_ids: Vec::new(),
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_focus_outside,
+            on_interact_outside,
+        },
+        move |reason| {
+            if let Some(handler) = &on_dismiss {
+                handler.call(reason);
+            }
+        },
+    );
+
+    rsx! {
+        div {
+            id,
+            "data-dismissable-layer": "",
+            ..attributes,
+            {children}
+        }
+    }
+}
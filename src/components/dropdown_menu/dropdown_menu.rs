@@ -0,0 +1,183 @@
+//! # DropdownMenu Component
+//!
+//! A menu of actions or options opened from a button, with checkbox and radio items,
+//! labels, separators, keyboard shortcut hints and nested submenus. It follows the
+//! WAI-ARIA menu button pattern: the trigger opens the menu with a click, Enter, Space or
+//! ArrowDown, the arrow keys, Home/End and typing a letter move between items (skipping
+//! disabled ones), and Escape closes one submenu level at a time.
+//!
+//! Selecting an item closes the menu unless its `on_select` handler calls
+//! `prevent_default`, e.g. to toggle several checkbox items in a row.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn FileMenu() -> Element {
+//!     let mut untitled = use_signal(|| 0);
+//!     let mut panel = use_signal(|| "bottom".to_string());
+//!
+//!     rsx! {
+//!         DropdownMenu {
+//!             DropdownMenuTrigger { "File" }
+//!             DropdownMenuContent {
+//!                 align: DropdownMenuAlign::Start,
+//!                 DropdownMenuItem {
+//!                     on_select: move |_| untitled += 1,
+//!                     "New file"
+//!                     DropdownMenuShortcut { "⌘N" }
+//!                 }
+//!                 DropdownMenuSub {
+//!                     DropdownMenuSubTrigger { "Open recent" }
+//!                     DropdownMenuSubContent {
+//!                         DropdownMenuItem { "notes.md" }
+//!                         DropdownMenuItem { "todo.md" }
+//!                     }
+//!                 }
+//!                 DropdownMenuSeparator {}
+//!                 DropdownMenuLabel { "Panel position" }
+//!                 DropdownMenuRadioGroup {
+//!                     value: panel(),
+//!                     on_value_change: move |value| panel.set(value),
+//!                     DropdownMenuRadioItem {
+//!                         value: "bottom",
+//!                         DropdownMenuItemIndicator { "•" }
+//!                         "Bottom"
+//!                     }
+//!                     DropdownMenuRadioItem {
+//!                         value: "right",
+//!                         DropdownMenuItemIndicator { "•" }
+//!                         "Right"
+//!                     }
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::menu::{use_menu, MenuContext, MenuTrigger, OpenFocus, MENU_CSS};
+use crate::utils::floating::{Align, Side};
+use dioxus::html::input_data::MouseButton;
+use dioxus::prelude::*;
+
+pub use crate::components::menu::{
+    MenuCheckboxItem as DropdownMenuCheckboxItem, MenuContent as DropdownMenuContent,
+    MenuGroup as DropdownMenuGroup, MenuItem as DropdownMenuItem,
+    MenuItemIndicator as DropdownMenuItemIndicator, MenuLabel as DropdownMenuLabel,
+    MenuRadioGroup as DropdownMenuRadioGroup, MenuRadioItem as DropdownMenuRadioItem,
+    MenuSeparator as DropdownMenuSeparator, MenuShortcut as DropdownMenuShortcut,
+    MenuSub as DropdownMenuSub, MenuSubContent as DropdownMenuSubContent,
+    MenuSubTrigger as DropdownMenuSubTrigger,
+};
+
+/// The preferred side of the trigger to render the content against.
+pub type DropdownMenuSide = Side;
+
+/// The preferred alignment of the content against the trigger.
+pub type DropdownMenuAlign = Align;
+
+/* -------------------------------------------------------------------------------------------------
+ * DropdownMenu (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn DropdownMenu(
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the menu is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// Whether the page behind the open menu can't be scrolled or clicked
+    #[props(default = true)]
+    modal: bool,
+    children: Element,
+) -> Element {
+    use_menu(
+        open,
+        default_open,
+        on_open_change,
+        modal,
+        MenuTrigger::Button,
+    );
+
+    rsx! {
+        style { {MENU_CSS} }
+        {children}
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DropdownMenuTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn DropdownMenuTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    /// Whether the trigger is disabled
+    #[props(default = false)]
+    disabled: bool,
+    children: Element,
+) -> Element {
+    let menu = use_context::<MenuContext>();
+    let is_open = menu.is_open();
+    let trigger_id = menu.trigger_id.clone();
+    let content_id = menu.content_id.clone();
+    let mut anchor = menu.anchor;
+
+    // Open on press rather than click, like native menus
+    let pointer_menu = menu.clone();
+    let onpointerdown = move |event: PointerEvent| {
+        let primary = event.trigger_button() == Some(MouseButton::Primary);
+        if disabled || !primary || event.modifiers().ctrl() {
+            return;
+        }
+        if pointer_menu.is_open() {
+            pointer_menu.set_open(false);
+        } else {
+            // Keep focus off the trigger so it can move into the content
+            event.prevent_default();
+            pointer_menu.open_with_focus(OpenFocus::Content);
+        }
+    };
+
+    let onkeydown = move |event: KeyboardEvent| {
+        if disabled {
+            return;
+        }
+        match event.key() {
+            Key::Enter | Key::ArrowDown => {}
+            Key::Character(character) if character == " " => {}
+            _ => return,
+        }
+        event.prevent_default();
+        if event.key() != Key::ArrowDown && menu.is_open() {
+            menu.set_open(false);
+        } else {
+            menu.open_with_focus(OpenFocus::First);
+        }
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: trigger_id,
+            disabled,
+            "aria-haspopup": "menu",
+            "aria-expanded": if is_open { "true" } else { "false" },
+            "aria-controls": if is_open { Some(content_id) } else { None },
+            "data-state": if is_open { "open" } else { "closed" },
+            "data-disabled": if disabled { Some("") } else { None },
+            onmounted: move |event: MountedEvent| anchor.set(Some(event.data().into())),
+            onpointerdown,
+            onkeydown,
+            ..attributes,
+            {children}
+        }
+    }
+}
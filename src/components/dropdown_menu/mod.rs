@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod dropdown_menu;
+
+pub use dropdown_menu::*;
@@ -4,7 +4,7 @@ use dioxus::prelude::*;
 /// An Empty state component for displaying "no content" states.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// rsx! {
 ///     Empty {
 ///         EmptyHeader {
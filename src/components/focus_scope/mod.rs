@@ -0,0 +1,119 @@
+//! # FocusScope
+//!
+//! Manages keyboard focus for overlays such as dialogs and popovers.
+//!
+//! - On mount it remembers the focused element and focuses the first tabbable element
+//!   inside the scope (or the scope itself).
+//! - While `trapped`, Tab and Shift+Tab stay inside the scope; with `loop` they wrap from
+//!   the last tabbable element to the first and back. Tabbables are looked up on every
+//!   key press, so content rendered after mount is included.
+//! - On unmount it returns focus to the element remembered on mount.
+//!
+//! Both automatic focus moves can be cancelled by calling `prevent_default` in
+//! `on_mount_auto_focus` / `on_unmount_auto_focus`.
+//!
+//! FocusScope renders a single `div` and forwards every global attribute to it, so an
+//! overlay can use it directly as its content element:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Panel() -> Element {
+//!     rsx! {
+//!         FocusScope {
+//!             trapped: true,
+//!             r#loop: true,
+//!             role: "dialog",
+//!             class: "panel",
+//!             button { "First" }
+//!             button { "Last" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::platform::{self, FocusReturn};
+use crate::utils::{take_id_attribute, use_id};
+use dioxus::core::use_drop;
+use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[component]
+pub fn FocusScope(
+    /// Keep Tab and Shift+Tab focus inside the scope
+    #[props(default = false)]
+    trapped: bool,
+    /// Wrap focus from the last tabbable element to the first (and back) on Tab
+    #[props(default = false)]
+    r#loop: bool,
+    /// Called before focus moves into the scope on mount. Call `prevent_default` to keep focus
+    /// where it is.
+    on_mount_auto_focus: Option<EventHandler<Event<()>>>,
+    /// Called before focus returns to the previously focused element on unmount. Call
+    /// `prevent_default` to leave focus alone.
+    on_unmount_auto_focus: Option<EventHandler<Event<()>>>,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let generated_id = use_id("focus-scope");
+
+    let mut attributes = attributes;
+    let id = take_id_attribute(&mut attributes, generated_id);
+
+    let focus_return = use_hook(|| Rc::new(RefCell::new(None::<FocusReturn>)));
+    let unmount_handler = use_hook(|| Rc::new(Cell::new(None)));
+    unmount_handler.set(on_unmount_auto_focus);
+
+    let mount_return = focus_return.clone();
+    let mount_id = id.clone();
+    let onmounted = move |_| {
+        *mount_return.borrow_mut() = Some(FocusReturn::capture());
+
+        let event = Event::new(Rc::new(()), false);
+        if let Some(handler) = &on_mount_auto_focus {
+            handler.call(event.clone());
+        }
+        if event.default_action_enabled() {
+            platform::focus_first(&mount_id);
+        }
+    };
+
+    use_drop(move || {
+        let Some(target) = focus_return.borrow_mut().take() else {
+            return;
+        };
+        let event = Event::new(Rc::new(()), false);
+        if let Some(handler) = unmount_handler.get() {
+            handler.call(event.clone());
+        }
+        if event.default_action_enabled() {
+            target.restore();
+        }
+    });
+
+    let key_id = id.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let modifiers = event.modifiers();
+        let has_other_modifier = modifiers.ctrl() || modifiers.alt() || modifiers.meta();
+        if event.key() != Key::Tab || has_other_modifier || !(trapped || r#loop) {
+            return;
+        }
+        // Move focus ourselves so the tabbables are looked up again on every press
+        event.prevent_default();
+        platform::focus_next(&key_id, !modifiers.shift(), r#loop);
+    };
+
+    rsx! {
+        div {
+            id: "{id}",
+            tabindex: "-1",
+            onmounted,
+            onkeydown,
+            ..attributes,
+            {children}
+        }
+    }
+}
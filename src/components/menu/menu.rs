@@ -0,0 +1,1189 @@
+//! # Menu
+//!
+//! The parts every menu shares: floating content with WAI-ARIA menu keyboard support,
+//! items, checkbox and radio items, labels, separators, shortcuts and submenus. Each kind
+//! of menu (such as [`DropdownMenu`](crate::components::dropdown_menu::DropdownMenu))
+//! provides its own root and trigger through [`use_menu`] and re-exports these parts under
+//! its own names.
+//!
+//! Inside the content:
+//!
+//! - ArrowDown/ArrowUp move between items, skipping disabled ones, and Home/End jump to
+//!   the first/last item;
+//! - typing focuses the next item whose text starts with what was typed;
+//! - Enter, Space or a click selects the focused item, which closes the whole menu unless
+//!   its `on_select` handler calls `prevent_default`;
+//! - ArrowRight, Enter or Space on a sub trigger opens its submenu, and ArrowLeft inside
+//!   a submenu closes it again;
+//! - Escape closes the innermost open menu, one level at a time.
+
+use crate::components::checkbox::CheckedState;
+use crate::components::dismissable_layer::{
+    use_dismissable_layer, DismissReason, DismissableLayerOptions,
+};
+use crate::components::focus_scope::FocusScope;
+use crate::components::portal::{Portal, PortalContainer};
+use crate::components::presence::use_presence;
+use crate::platform::{self, clear_timeout, set_timeout, ListenerEvent};
+use crate::utils::floating::{use_auto_update, use_floating, Align, Anchor, FloatingOptions, Side};
+use crate::utils::{
+    self, element_text, merge_attributes, take_id_attribute, typeahead_match, use_collection,
+    use_collection_item, use_controllable_state, use_id, use_layer, use_scroll_lock, use_typeahead,
+    Collection, CollectionItem, ControllableState, Layer, Typeahead,
+};
+use dioxus::core::Task;
+use dioxus::html::events::{
+    onblur, onclick, onfocus, onkeydown, onmounted, onpointerenter, onpointerleave,
+};
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+pub(crate) const MENU_CSS: &str = include_str!("./menu.css");
+
+/// How long the pointer has to rest on a sub trigger before its submenu opens.
+const SUB_OPEN_DELAY_MS: u64 = 100;
+
+/// Where focus goes when a menu's content opens.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum OpenFocus {
+    /// The content itself, when opened with a pointer.
+    Content,
+    /// The first enabled item, when opened with the keyboard.
+    First,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Menu state
+ * -----------------------------------------------------------------------------------------------*/
+
+/// What opens a menu's root level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MenuTrigger {
+    /// A button, which labels the menu and gets focus back when it closes.
+    Button,
+    /// An area of the page, such as the target of a context menu. Focus goes back to
+    /// wherever it was before the menu opened.
+    Area,
+}
+
+/// The state of the whole menu, shared by every submenu.
+#[derive(Clone, Copy)]
+pub(crate) struct MenuRootContext {
+    open: ControllableState<bool>,
+    modal: bool,
+    trigger: MenuTrigger,
+    /// Whether the menu was dismissed by interacting outside it, in which case focus stays
+    /// where the user put it.
+    dismissed_outside: CopyValue<bool>,
+}
+
+impl MenuRootContext {
+    /// Closes the menu and all its submenus.
+    pub(crate) fn close(&self) {
+        let mut open = self.open;
+        open.set(false);
+    }
+}
+
+/// An item registered with the menu level it belongs to, for keyboard navigation.
+#[derive(Clone, Debug, PartialEq)]
+struct ItemEntry {
+    id: String,
+    text: String,
+    disabled: bool,
+}
+
+impl CollectionItem for ItemEntry {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// One level of a menu: the root menu, or a submenu.
+#[derive(Clone)]
+pub(crate) struct MenuContext {
+    open: ControllableState<bool>,
+    pub(crate) trigger_id: String,
+    pub(crate) content_id: String,
+    /// What the content is positioned against: the trigger, or the sub trigger of a
+    /// submenu.
+    pub(crate) anchor: Signal<Option<Anchor>>,
+    open_focus: CopyValue<OpenFocus>,
+    /// The level's place among open overlays; decides z-index and who gets Escape.
+    layer: Layer,
+    /// The level's items in document order.
+    items: Collection<ItemEntry>,
+    highlighted: Signal<Option<String>>,
+    typeahead: Typeahead,
+    /// Content ids of the menus this submenu was opened from; empty for the root.
+    parent_content_ids: Vec<String>,
+}
+
+impl MenuContext {
+    pub(crate) fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    pub(crate) fn set_open(&self, open: bool) {
+        let mut state = self.open;
+        state.set(open);
+    }
+
+    /// Opens this level, moving focus to `focus` once the content mounts.
+    pub(crate) fn open_with_focus(&self, focus: OpenFocus) {
+        let mut open_focus = self.open_focus;
+        open_focus.set(focus);
+        self.set_open(true);
+    }
+
+    fn is_sub(&self) -> bool {
+        !self.parent_content_ids.is_empty()
+    }
+
+    fn enabled_items(&self) -> Vec<ItemEntry> {
+        self.items
+            .borrow()
+            .iter()
+            .filter(|item| !item.disabled)
+            .cloned()
+            .collect()
+    }
+
+    /// Focuses the first enabled item, or the content if there is none.
+    fn focus_first_item(&self) {
+        match self.enabled_items().first() {
+            Some(item) => platform::focus(&item.id),
+            None => platform::focus(&self.content_id),
+        }
+    }
+}
+
+/// Provides the state of a menu's root level. Menus call this in their root component and
+/// render their own trigger against [`MenuContext`].
+pub(crate) fn use_menu(
+    open: Option<bool>,
+    default_open: bool,
+    on_open_change: Option<EventHandler<bool>>,
+    modal: bool,
+    trigger: MenuTrigger,
+) -> MenuContext {
+    let open = use_controllable_state(open, || default_open, on_open_change);
+    let dismissed_outside = use_hook(|| CopyValue::new(false));
+    use_context_provider(|| MenuRootContext {
+        open,
+        modal,
+        trigger,
+        dismissed_outside,
+    });
+    use_menu_level(open, Vec::new())
+}
+
+fn use_menu_level(open: ControllableState<bool>, parent_content_ids: Vec<String>) -> MenuContext {
+    let trigger_id = use_id("menu-trigger");
+    let content_id = use_id("menu-content");
+    let anchor = use_signal(|| None::<Anchor>);
+    let open_focus = use_hook(|| CopyValue::new(OpenFocus::Content));
+    let layer = use_layer(open.get());
+    let items = use_collection();
+    let highlighted = use_signal(|| None::<String>);
+    let typeahead = use_typeahead();
+
+    use_context_provider(|| MenuContext {
+        open,
+        trigger_id,
+        content_id,
+        anchor,
+        open_focus,
+        layer,
+        items,
+        highlighted,
+        typeahead,
+        parent_content_ids,
+    })
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The preferred side of the trigger to render against
+    #[props(default = Side::Bottom)]
+    side: Side,
+    /// The distance in pixels from the trigger
+    #[props(default = 4)]
+    side_offset: i32,
+    /// The alignment against the trigger. Collisions shift the content rather than change it.
+    #[props(default = Align::Center)]
+    align: Align,
+    /// An offset in pixels from the "start" or "end" alignment options
+    #[props(default = 0)]
+    align_offset: i32,
+    /// Flip and shift the content to keep it inside the viewport
+    #[props(default = true)]
+    avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in
+    #[props(default = 0)]
+    collision_padding: i32,
+    /// Wrap arrow key navigation from the last item to the first and back
+    #[props(default = false)]
+    r#loop: bool,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when Escape is pressed while this menu is the innermost open one. Call
+    /// `prevent_default` to keep it open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside the menu. Call `prevent_default` to keep it
+    /// open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when focus moves outside the menu. Call `prevent_default` to keep it open.
+    on_focus_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer press or focus lands outside the menu. Call `prevent_default`
+    /// to keep it open.
+    on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called before focus returns to the trigger when the menu closes. Call
+    /// `prevent_default` to leave focus alone.
+    on_close_auto_focus: Option<EventHandler<Event<()>>>,
+    /// Keep the content mounted while closed, e.g. to drive its animation from outside
+    #[props(default = false)]
+    force_mount: bool,
+    children: Element,
+) -> Element {
+    let root = use_context::<MenuRootContext>();
+    let menu = use_context::<MenuContext>();
+    let is_open = menu.is_open();
+    let is_sub = menu.is_sub();
+    let modal = root.modal && !is_sub;
+    let layer = menu.layer;
+
+    let mut floating = use_floating(
+        menu.anchor,
+        FloatingOptions {
+            side,
+            align,
+            side_offset: side_offset as f64,
+            align_offset: align_offset as f64,
+            avoid_collisions,
+            collision_padding: collision_padding as f64,
+            ..FloatingOptions::default()
+        },
+    );
+
+    // Follow the trigger while the page scrolls or resizes
+    use_auto_update(floating, is_open);
+
+    // Stays mounted after closing until its exit animation finishes
+    let presence = use_presence(is_open, menu.content_id.clone());
+
+    use_scroll_lock(is_open && modal);
+
+    // Whether an outside interaction landed in one of the menus this submenu was opened
+    // from, e.g. hovering another item there. That closes only this submenu.
+    let within_parent = use_hook(|| Rc::new(Cell::new(false)));
+    let parent_content_ids = menu.parent_content_ids.clone();
+    let entered_parent = within_parent.clone();
+    let on_interact_outside = use_callback(move |event: Event<ListenerEvent>| {
+        entered_parent.set(parent_content_ids.iter().any(|id| event.is_within(id)));
+        if let Some(handler) = &on_interact_outside {
+            handler.call(event);
+        }
+    });
+
+    // Whether this submenu was closed by an interaction in its parent menu, which then
+    // keeps the focus
+    let sub_dismissed = use_hook(|| Rc::new(Cell::new(false)));
+    let dismissed = sub_dismissed.clone();
+    let level = menu.clone();
+    use_dismissable_layer(
+        layer,
+        menu.content_id.clone(),
+        DismissableLayerOptions {
+            escape: true,
+            outside: true,
+            inside_ids: vec![menu.trigger_id.clone()],
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_focus_outside,
+            on_interact_outside: Some(on_interact_outside),
+        },
+        move |reason| {
+            let outside = reason != DismissReason::Escape;
+            if is_sub && (!outside || within_parent.get()) {
+                dismissed.set(outside);
+                level.set_open(false);
+                return;
+            }
+            // A modal menu sends focus back to the trigger however it closed
+            let mut dismissed_outside = root.dismissed_outside;
+            dismissed_outside.set(outside && !root.modal);
+            root.close();
+        },
+    );
+
+    if !force_mount && !presence.is_present() {
+        return rsx! {};
+    }
+
+    let z_index = layer.z_index();
+    let pointer_events = if is_open { "auto" } else { "none" };
+    let state = if is_open { "open" } else { "closed" };
+
+    let position = floating.position();
+    let placed_side = position.map(|pos| pos.side).unwrap_or(side);
+    let placed_align = position.map(|pos| pos.align).unwrap_or(align);
+
+    // Focus the content or the first item, except for a submenu opened by hovering its
+    // trigger, which keeps focus so the pointer can move on to other items
+    let mount_menu = menu.clone();
+    let mount_dismissed = sub_dismissed.clone();
+    let on_mount_auto_focus = move |event: Event<()>| {
+        mount_dismissed.set(false);
+        let mut dismissed_outside = root.dismissed_outside;
+        if !is_sub {
+            dismissed_outside.set(false);
+        }
+        if !is_open {
+            event.prevent_default();
+            return;
+        }
+        match *mount_menu.open_focus.peek() {
+            OpenFocus::First => {
+                event.prevent_default();
+                mount_menu.focus_first_item();
+            }
+            OpenFocus::Content if is_sub => event.prevent_default(),
+            OpenFocus::Content => {}
+        }
+    };
+
+    // Send focus back to the trigger, unless the user moved it elsewhere or the whole menu
+    // is closing, in which case the root's trigger gets it
+    let trigger_id = menu.trigger_id.clone();
+    let labelled_by_trigger = is_sub || root.trigger == MenuTrigger::Button;
+    let on_unmount_auto_focus = move |event: Event<()>| {
+        let left = if is_sub {
+            sub_dismissed.get() || !root.open.peek()
+        } else {
+            *root.dismissed_outside.peek()
+        };
+        if left {
+            event.prevent_default();
+            return;
+        }
+        if let Some(handler) = &on_close_auto_focus {
+            handler.call(event.clone());
+        }
+        // A trigger opened with a pointer never had focus, so focus it explicitly; an
+        // area trigger leaves focus to be restored where it was
+        if event.default_action_enabled() && labelled_by_trigger {
+            event.prevent_default();
+            platform::focus(&trigger_id);
+        }
+    };
+
+    let keyboard_menu = menu.clone();
+    let onkeydown_handler = move |event: KeyboardEvent| {
+        // Keys pressed in a submenu would otherwise reach its parents' content too, since
+        // events bubble through the component tree rather than the DOM
+        event.stop_propagation();
+        let menu = &keyboard_menu;
+        let items = menu.enabled_items();
+        let current = menu
+            .highlighted
+            .peek()
+            .as_ref()
+            .and_then(|id| items.iter().position(|item| &item.id == id));
+
+        let target = match event.key() {
+            Key::ArrowDown => match current {
+                None => Some(0),
+                Some(index) if index + 1 < items.len() => Some(index + 1),
+                Some(_) if r#loop => Some(0),
+                Some(index) => Some(index),
+            },
+            Key::ArrowUp => match current {
+                None => items.len().checked_sub(1),
+                Some(0) if r#loop => items.len().checked_sub(1),
+                Some(index) => Some(index.saturating_sub(1)),
+            },
+            Key::Home => Some(0),
+            Key::End => items.len().checked_sub(1),
+            Key::ArrowLeft if menu.is_sub() => {
+                event.prevent_default();
+                menu.set_open(false);
+                return;
+            }
+            // Menus aren't part of the tab order; Tab neither leaves nor moves through them
+            Key::Tab => {
+                event.prevent_default();
+                return;
+            }
+            Key::Character(character) => {
+                let modifiers = event.modifiers();
+                if character.chars().count() != 1
+                    || modifiers.ctrl()
+                    || modifiers.alt()
+                    || modifiers.meta()
+                {
+                    return;
+                }
+                let search = menu.typeahead.push(&character);
+                let texts: Vec<&str> = items.iter().map(|item| item.text.as_str()).collect();
+                typeahead_match(&texts, current, &search)
+            }
+            _ => return,
+        };
+
+        event.prevent_default();
+        if let Some(item) = target.and_then(|index| items.get(index)) {
+            platform::focus(&item.id);
+        }
+    };
+
+    let class_name = utils::cn(vec![
+        Some("menu-content bg-popover text-popover-foreground min-w-[8rem] overflow-hidden rounded-md border p-1 shadow-md outline-none"),
+        Some(class.as_str()),
+    ]);
+
+    // Caller attributes replace the defaults of the same name; caller listeners run first
+    let content_attributes = vec![
+        Attribute::new("role", "menu", None, false),
+        Attribute::new("id", menu.content_id.clone(), None, false),
+        Attribute::new("aria-orientation", "vertical", None, false),
+        Attribute::new(
+            "aria-labelledby",
+            labelled_by_trigger.then(|| menu.trigger_id.clone()),
+            None,
+            false,
+        ),
+        Attribute::new("data-state", state, None, false),
+        Attribute::new("data-side", placed_side.as_str(), None, false),
+        Attribute::new("data-align", placed_align.as_str(), None, false),
+        Attribute::new("class", class_name, None, false),
+        onkeydown(onkeydown_handler),
+    ];
+    let content_attributes = merge_attributes(content_attributes, attributes);
+
+    rsx! {
+        Portal {
+            container,
+            // Modal menus block the page; pressing it counts as outside and closes them
+            if modal {
+                div {
+                    class: "menu-backdrop",
+                    "data-state": state,
+                    style: "position: fixed; inset: 0; z-index: {z_index}; pointer-events: {pointer_events};",
+                }
+            }
+            // The wrapper is what gets positioned, so the content's own transform is free
+            // for animations
+            div {
+                "data-menu-content-wrapper": "",
+                style: "{floating.style()} z-index: {z_index + 1};",
+                onmounted: move |event: MountedEvent| floating.set_content(event.data()),
+                FocusScope {
+                    on_mount_auto_focus,
+                    on_unmount_auto_focus,
+                    attributes: content_attributes,
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Items
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The submenu a `MenuSubTrigger` opens, and the timer that opens it on hover.
+#[derive(Clone)]
+struct SubTarget {
+    menu: MenuContext,
+    open_timer: CopyValue<Option<Task>>,
+}
+
+/// Registers an item with `menu` for keyboard navigation and typeahead, and builds the
+/// attributes every kind of item shares: focus on hover, `data-highlighted`, disabled
+/// state, and `on_select` on click, Enter or Space. A sub trigger opens its submenu
+/// instead, also on ArrowRight and after resting the pointer on it.
+fn use_menu_item(
+    menu: &MenuContext,
+    id: String,
+    text: String,
+    disabled: bool,
+    sub: Option<SubTarget>,
+    on_select: impl FnMut() + 'static,
+) -> Vec<Attribute> {
+    use_collection_item(
+        &menu.items,
+        ItemEntry {
+            id: id.clone(),
+            text,
+            disabled,
+        },
+    );
+
+    let mut on_select = on_select;
+    let select_sub = sub.clone();
+    let select = use_callback(move |keyboard: bool| {
+        if disabled {
+            return;
+        }
+        match &select_sub {
+            Some(sub) if sub.menu.open.peek() && keyboard => sub.menu.focus_first_item(),
+            Some(sub) if sub.menu.open.peek() => {}
+            Some(sub) => sub.menu.open_with_focus(if keyboard {
+                OpenFocus::First
+            } else {
+                OpenFocus::Content
+            }),
+            None => on_select(),
+        }
+    });
+
+    let mut highlighted = menu.highlighted;
+    let is_highlighted = highlighted.read().as_deref() == Some(id.as_str());
+    let typeahead = menu.typeahead;
+    let opens_sub = sub.is_some();
+
+    let mut attributes = vec![
+        Attribute::new("id", id.clone(), None, false),
+        Attribute::new("tabindex", "-1", None, false),
+    ];
+    if is_highlighted {
+        attributes.push(Attribute::new("data-highlighted", "", None, false));
+    }
+    if disabled {
+        attributes.push(Attribute::new("data-disabled", "", None, false));
+        attributes.push(Attribute::new("aria-disabled", "true", None, false));
+    }
+
+    let hovered_id = id.clone();
+    let content_id = menu.content_id.clone();
+    let hover_sub = sub.clone();
+    let left_content_id = menu.content_id.clone();
+    let focused_id = id.clone();
+    attributes.extend([
+        onclick(move |_| select.call(false)),
+        onkeydown(move |event: KeyboardEvent| {
+            let key = event.key();
+            // While typing ahead, Space is part of the search
+            let is_space = key == Key::Character(" ".to_string()) && !typeahead.is_searching();
+            if key == Key::Enter || is_space || (opens_sub && key == Key::ArrowRight) {
+                event.prevent_default();
+                event.stop_propagation();
+                select.call(true);
+            }
+        }),
+        onpointerenter(move |event: PointerEvent| {
+            if event.pointer_type() == "touch" {
+                return;
+            }
+            if disabled {
+                platform::focus(&content_id);
+                return;
+            }
+            platform::focus(&hovered_id);
+            if let Some(SubTarget {
+                menu,
+                mut open_timer,
+            }) = hover_sub.clone()
+            {
+                if !menu.open.peek() && open_timer.peek().is_none() {
+                    let timer = open_timer;
+                    open_timer.set(Some(set_timeout(
+                        move || {
+                            let mut timer = timer;
+                            timer.set(None);
+                            menu.open_with_focus(OpenFocus::Content);
+                        },
+                        SUB_OPEN_DELAY_MS,
+                    )));
+                }
+            }
+        }),
+        onpointerleave(move |event: PointerEvent| {
+            if event.pointer_type() == "touch" {
+                return;
+            }
+            match &sub {
+                Some(SubTarget { menu, open_timer }) => {
+                    let mut open_timer = *open_timer;
+                    if let Some(task) = open_timer.take() {
+                        clear_timeout(task);
+                    }
+                    // Keep the highlight while the pointer moves into the open submenu
+                    if !menu.open.peek() {
+                        platform::focus(&left_content_id);
+                    }
+                }
+                None => platform::focus(&left_content_id),
+            }
+        }),
+        onfocus(move |_| highlighted.set(Some(focused_id.clone()))),
+        onblur(move |_| {
+            if highlighted.peek().as_deref() == Some(id.as_str()) {
+                highlighted.set(None);
+            }
+        }),
+    ]);
+    attributes
+}
+
+/// Runs an item's `on_select` handler, then closes the menu unless it was prevented.
+fn select_item(root: MenuRootContext, on_select: Option<EventHandler<Event<()>>>) {
+    let event = Event::new(Rc::new(()), false);
+    if let Some(handler) = &on_select {
+        handler.call(event.clone());
+    }
+    if event.default_action_enabled() {
+        root.close();
+    }
+}
+
+const ITEM_CLASS: &str = "menu-item relative flex cursor-default items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-none select-none data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50";
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuItem
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuItem(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Skip the item in keyboard navigation and ignore selecting it
+    #[props(default = false)]
+    disabled: bool,
+    /// The text typeahead matches (default: the item's text)
+    #[props(into)]
+    text_value: Option<String>,
+    /// Called when the item is selected with a click, Enter or Space. Call
+    /// `prevent_default` to keep the menu open.
+    on_select: Option<EventHandler<Event<()>>>,
+    children: Element,
+) -> Element {
+    let root = use_context::<MenuRootContext>();
+    let menu = use_context::<MenuContext>();
+    let generated_id = use_id("menu-item");
+
+    let mut attributes = attributes;
+    let id = take_id_attribute(&mut attributes, generated_id);
+    let text = text_value.unwrap_or_else(|| element_text(&children));
+
+    let mut item_attributes = use_menu_item(&menu, id, text, disabled, None, move || {
+        select_item(root, on_select)
+    });
+    item_attributes.extend([
+        Attribute::new("role", "menuitem", None, false),
+        Attribute::new(
+            "class",
+            utils::cn(vec![Some(ITEM_CLASS), Some(class.as_str())]),
+            None,
+            false,
+        ),
+    ]);
+
+    rsx! {
+        div {
+            ..merge_attributes(item_attributes, attributes),
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuCheckboxItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Whether the item around a [`MenuItemIndicator`] is checked.
+#[derive(Clone, Copy)]
+struct ItemIndicatorContext {
+    checked: Memo<CheckedState>,
+}
+
+fn aria_checked(state: CheckedState) -> &'static str {
+    match state {
+        CheckedState::Checked => "true",
+        CheckedState::Unchecked => "false",
+        CheckedState::Indeterminate => "mixed",
+    }
+}
+
+#[component]
+pub fn MenuCheckboxItem(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Controlled checked state
+    checked: Option<CheckedState>,
+    /// Whether the item is checked by default (uncontrolled)
+    #[props(default = CheckedState::Unchecked)]
+    default_checked: CheckedState,
+    /// Callback when the checked state changes
+    on_checked_change: Option<EventHandler<CheckedState>>,
+    /// Skip the item in keyboard navigation and ignore selecting it
+    #[props(default = false)]
+    disabled: bool,
+    /// The text typeahead matches (default: the item's text)
+    #[props(into)]
+    text_value: Option<String>,
+    /// Called after the checked state changes. Call `prevent_default` to keep the menu
+    /// open.
+    on_select: Option<EventHandler<Event<()>>>,
+    children: Element,
+) -> Element {
+    let root = use_context::<MenuRootContext>();
+    let menu = use_context::<MenuContext>();
+    let generated_id = use_id("menu-checkbox-item");
+
+    let mut attributes = attributes;
+    let id = take_id_attribute(&mut attributes, generated_id);
+    let text = text_value.unwrap_or_else(|| element_text(&children));
+
+    let mut state = use_controllable_state(checked, || default_checked, on_checked_change);
+    let checked = use_memo(move || state.get());
+    use_context_provider(|| ItemIndicatorContext { checked });
+
+    let mut item_attributes = use_menu_item(&menu, id, text, disabled, None, move || {
+        state.set(state.peek().toggle());
+        select_item(root, on_select);
+    });
+    item_attributes.extend([
+        Attribute::new("role", "menuitemcheckbox", None, false),
+        Attribute::new("aria-checked", aria_checked(checked()), None, false),
+        Attribute::new("data-state", checked().data_state(), None, false),
+        Attribute::new(
+            "class",
+            utils::cn(vec![Some(ITEM_CLASS), Some("pl-8"), Some(class.as_str())]),
+            None,
+            false,
+        ),
+    ]);
+
+    rsx! {
+        div {
+            ..merge_attributes(item_attributes, attributes),
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuRadioGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct MenuRadioGroupContext {
+    value: ControllableState<String>,
+}
+
+#[component]
+pub fn MenuRadioGroup(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    /// Controlled value: the `value` of the checked item
+    #[props(into)]
+    value: Option<String>,
+    /// The value checked by default (uncontrolled)
+    #[props(default = String::new(), into)]
+    default_value: String,
+    /// Callback when another item is checked
+    on_value_change: Option<EventHandler<String>>,
+    children: Element,
+) -> Element {
+    let value = use_controllable_state(value, || default_value, on_value_change);
+    use_context_provider(|| MenuRadioGroupContext { value });
+
+    rsx! {
+        div {
+            role: "group",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuRadioItem
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuRadioItem(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The group value this item stands for
+    #[props(into)]
+    value: String,
+    /// Skip the item in keyboard navigation and ignore selecting it
+    #[props(default = false)]
+    disabled: bool,
+    /// The text typeahead matches (default: the item's text)
+    #[props(into)]
+    text_value: Option<String>,
+    /// Called after the item is checked. Call `prevent_default` to keep the menu open.
+    on_select: Option<EventHandler<Event<()>>>,
+    children: Element,
+) -> Element {
+    let root = use_context::<MenuRootContext>();
+    let menu = use_context::<MenuContext>();
+    let mut group = use_context::<MenuRadioGroupContext>();
+    let generated_id = use_id("menu-radio-item");
+
+    let mut attributes = attributes;
+    let id = take_id_attribute(&mut attributes, generated_id);
+    let text = text_value.unwrap_or_else(|| element_text(&children));
+
+    let checked = use_memo(use_reactive!(|value| {
+        if group.value.get() == value {
+            CheckedState::Checked
+        } else {
+            CheckedState::Unchecked
+        }
+    }));
+    use_context_provider(|| ItemIndicatorContext { checked });
+
+    let selected_value = value.clone();
+    let mut item_attributes = use_menu_item(&menu, id, text, disabled, None, move || {
+        group.value.set(selected_value.clone());
+        select_item(root, on_select);
+    });
+    item_attributes.extend([
+        Attribute::new("role", "menuitemradio", None, false),
+        Attribute::new("aria-checked", aria_checked(checked()), None, false),
+        Attribute::new("data-state", checked().data_state(), None, false),
+        Attribute::new(
+            "class",
+            utils::cn(vec![Some(ITEM_CLASS), Some("pl-8"), Some(class.as_str())]),
+            None,
+            false,
+        ),
+    ]);
+
+    rsx! {
+        div {
+            ..merge_attributes(item_attributes, attributes),
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuItemIndicator
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Renders its children only while the surrounding checkbox or radio item is checked (or
+/// indeterminate), e.g. a check mark.
+#[component]
+pub fn MenuItemIndicator(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let context = use_context::<ItemIndicatorContext>();
+    let state = (context.checked)();
+    if state == CheckedState::Unchecked {
+        return rsx! {};
+    }
+
+    let class_name = utils::cn(vec![
+        Some("pointer-events-none absolute left-2 flex size-3.5 items-center justify-center"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        span {
+            class: "{class_name}",
+            "data-state": state.data_state(),
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuGroup(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            role: "group",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuLabel
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A heading for a group of items. It can't be focused or selected.
+#[component]
+pub fn MenuLabel(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("px-2 py-1.5 text-sm font-medium"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuSeparator(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("bg-border -mx-1 my-1 h-px"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            role: "separator",
+            "aria-orientation": "horizontal",
+            class: "{class_name}",
+            ..attributes,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuShortcut
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The keyboard shortcut of an item, shown at its end. Purely visual: the app handles
+/// the shortcut itself.
+#[component]
+pub fn MenuShortcut(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("text-muted-foreground ml-auto text-xs tracking-widest"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        span {
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuSub
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The menu a [`MenuSub`] was opened from, which its sub trigger belongs to.
+#[derive(Clone)]
+struct MenuSubContext {
+    parent: MenuContext,
+}
+
+/// A nested menu: a [`MenuSubTrigger`] item and the [`MenuSubContent`] it opens.
+#[component]
+pub fn MenuSub(
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the submenu is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    children: Element,
+) -> Element {
+    let parent = use_context::<MenuContext>();
+    let open = use_controllable_state(open, || default_open, on_open_change);
+
+    let mut parent_content_ids = parent.parent_content_ids.clone();
+    parent_content_ids.push(parent.content_id.clone());
+    use_context_provider(|| MenuSubContext { parent });
+    use_menu_level(open, parent_content_ids);
+
+    rsx! {
+        {children}
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuSubTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuSubTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Skip the item in keyboard navigation and never open the submenu
+    #[props(default = false)]
+    disabled: bool,
+    /// The text typeahead matches (default: the item's text)
+    #[props(into)]
+    text_value: Option<String>,
+    children: Element,
+) -> Element {
+    let sub = use_context::<MenuContext>();
+    let parent = use_context::<MenuSubContext>().parent;
+    let is_open = sub.is_open();
+    let text = text_value.unwrap_or_else(|| element_text(&children));
+    let open_timer = use_hook(|| CopyValue::new(None::<Task>));
+
+    let target = SubTarget {
+        menu: sub.clone(),
+        open_timer,
+    };
+    let mut item_attributes = use_menu_item(
+        &parent,
+        sub.trigger_id.clone(),
+        text,
+        disabled,
+        Some(target),
+        || {},
+    );
+
+    let mut anchor = sub.anchor;
+    item_attributes.extend([
+        Attribute::new("role", "menuitem", None, false),
+        Attribute::new("aria-haspopup", "menu", None, false),
+        Attribute::new(
+            "aria-expanded",
+            if is_open { "true" } else { "false" },
+            None,
+            false,
+        ),
+        Attribute::new(
+            "data-state",
+            if is_open { "open" } else { "closed" },
+            None,
+            false,
+        ),
+        Attribute::new(
+            "class",
+            utils::cn(vec![
+                Some(ITEM_CLASS),
+                Some("data-[state=open]:bg-accent data-[state=open]:text-accent-foreground"),
+                Some(class.as_str()),
+            ]),
+            None,
+            false,
+        ),
+        onmounted(move |event: MountedEvent| anchor.set(Some(event.data().into()))),
+    ]);
+    if is_open {
+        item_attributes.push(Attribute::new(
+            "aria-controls",
+            sub.content_id.clone(),
+            None,
+            false,
+        ));
+    }
+
+    rsx! {
+        div {
+            ..merge_attributes(item_attributes, attributes),
+            {children}
+            svg {
+                class: "ml-auto size-4",
+                "aria-hidden": "true",
+                view_box: "0 0 24 24",
+                fill: "none",
+                stroke: "currentColor",
+                stroke_width: "2",
+                stroke_linecap: "round",
+                stroke_linejoin: "round",
+                path { d: "m9 18 6-6-6-6" }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuSubContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn MenuSubContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The distance in pixels from the sub trigger
+    #[props(default = 0)]
+    side_offset: i32,
+    /// An offset in pixels from the sub trigger's top, lining the first item up with it
+    #[props(default = -5)]
+    align_offset: i32,
+    /// Flip and shift the content to keep it inside the viewport
+    #[props(default = true)]
+    avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in
+    #[props(default = 0)]
+    collision_padding: i32,
+    /// Wrap arrow key navigation from the last item to the first and back
+    #[props(default = false)]
+    r#loop: bool,
+    /// Where the content is portaled (default: like the root menu's content)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when Escape is pressed while this submenu is the innermost open one. Call
+    /// `prevent_default` to keep it open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside the submenu. Call `prevent_default` to keep
+    /// it open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when focus moves outside the submenu. Call `prevent_default` to keep it open.
+    on_focus_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer press or focus lands outside the submenu. Call
+    /// `prevent_default` to keep it open.
+    on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Keep the content mounted while closed, e.g. to drive its animation from outside
+    #[props(default = false)]
+    force_mount: bool,
+    children: Element,
+) -> Element {
+    rsx! {
+        MenuContent {
+            class,
+            side: Side::Right,
+            side_offset,
+            align: Align::Start,
+            align_offset,
+            avoid_collisions,
+            collision_padding,
+            r#loop,
+            container,
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_focus_outside,
+            on_interact_outside,
+            force_mount,
+            attributes,
+            {children}
+        }
+    }
+}
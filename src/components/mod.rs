@@ -1,23 +1,50 @@
 pub mod accordion;
+pub mod alert_dialog;
 pub mod avatar;
 pub mod badge;
 pub mod button;
 pub mod card;
 pub mod checkbox;
+pub mod combobox;
+pub mod command;
+pub mod context_menu;
 pub mod dialog;
+pub mod dismissable_layer;
+pub mod dropdown_menu;
 pub mod empty;
+pub mod focus_scope;
+pub(crate) mod menu;
+pub mod popover;
 pub mod portal;
+pub mod presence;
+pub mod radio_group;
+pub mod select;
+pub mod sheet;
 pub mod spinner;
+pub mod tabs;
 pub mod tooltip;
 
 pub use accordion::*;
+pub use alert_dialog::*;
 pub use avatar::*;
 pub use badge::*;
 pub use button::*;
 pub use card::*;
 pub use checkbox::*;
+pub use combobox::*;
+pub use command::*;
+pub use context_menu::*;
 pub use dialog::*;
+pub use dismissable_layer::*;
+pub use dropdown_menu::*;
 pub use empty::*;
+pub use focus_scope::*;
+pub use popover::*;
 pub use portal::*;
+pub use presence::*;
+pub use radio_group::*;
+pub use select::*;
+pub use sheet::*;
 pub use spinner::*;
+pub use tabs::*;
 pub use tooltip::*;
@@ -0,0 +1,423 @@
+//! # Popover Component
+//!
+//! Rich, interactive content in a portal, positioned against a trigger and opened by
+//! clicking it. Unlike [`Tooltip`](crate::components::tooltip::Tooltip) it can hold
+//! inputs and buttons: focus moves into it when it opens and back to the trigger when it
+//! closes.
+//!
+//! Popovers are non-modal by default and close when a pointer is pressed or focus moves
+//! outside them, or on Escape. With `modal: true` focus is trapped inside, the page can't
+//! be scrolled or clicked, and pressing anywhere outside closes it.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Filters() -> Element {
+//!     rsx! {
+//!         Popover {
+//!             PopoverTrigger { "Filters" }
+//!             PopoverContent {
+//!                 side: PopoverSide::Bottom,
+//!                 align: PopoverAlign::Start,
+//!                 label { input { r#type: "checkbox" } "In stock" }
+//!                 PopoverClose { "Done" }
+//!                 PopoverArrow {}
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::dismissable_layer::{
+    use_dismissable_layer, DismissReason, DismissableLayerOptions,
+};
+use crate::components::focus_scope::FocusScope;
+use crate::components::portal::{Portal, PortalContainer};
+use crate::components::presence::use_presence;
+use crate::platform::ListenerEvent;
+use crate::utils::floating::{
+    use_auto_update, use_floating, Align, Anchor, FloatingArrow, FloatingOptions, Side,
+};
+use crate::utils::{
+    self, merge_attributes, use_controllable_state, use_id, use_layer, use_scroll_lock,
+    ControllableState, Layer,
+};
+use dioxus::core::use_drop;
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+const POPOVER_CSS: &str = include_str!("./popover.css");
+
+/// The preferred side of the anchor to render the content against.
+pub type PopoverSide = Side;
+
+/// The preferred alignment of the content against the anchor.
+pub type PopoverAlign = Align;
+
+/* -------------------------------------------------------------------------------------------------
+ * Popover (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+struct PopoverContext {
+    open: ControllableState<bool>,
+    modal: bool,
+    trigger_id: String,
+    content_id: String,
+    /// What the content is positioned against: the `PopoverAnchor` if there is one,
+    /// otherwise the trigger.
+    anchor: Signal<Option<Anchor>>,
+    trigger_ref: Signal<Option<Rc<MountedData>>>,
+    has_custom_anchor: Signal<bool>,
+    /// The popover's place among open overlays; decides z-index and who gets Escape.
+    layer: Layer,
+}
+
+#[component]
+pub fn Popover(
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the popover is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// Whether the popover is modal: focus stays inside it and the page behind it can't be
+    /// scrolled or clicked
+    #[props(default = false)]
+    modal: bool,
+    children: Element,
+) -> Element {
+    let open = use_controllable_state(open, || default_open, on_open_change);
+
+    let trigger_id = use_id("popover-trigger");
+    let content_id = use_id("popover-content");
+    let anchor = use_signal(|| None::<Anchor>);
+    let trigger_ref = use_signal(|| None::<Rc<MountedData>>);
+    let has_custom_anchor = use_signal(|| false);
+
+    let layer = use_layer(open.get());
+
+    use_context_provider(|| PopoverContext {
+        open,
+        modal,
+        trigger_id,
+        content_id,
+        anchor,
+        trigger_ref,
+        has_custom_anchor,
+        layer,
+    });
+
+    rsx! {
+        style { {POPOVER_CSS} }
+        {children}
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn PopoverTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let context = use_context::<PopoverContext>();
+    let mut open = context.open;
+    let is_open = open.get();
+
+    let mut anchor = context.anchor;
+    let mut trigger_ref = context.trigger_ref;
+    let has_custom_anchor = context.has_custom_anchor;
+    let onmounted = move |event: MountedEvent| {
+        let element = event.data();
+        trigger_ref.set(Some(element.clone()));
+        if !*has_custom_anchor.peek() {
+            anchor.set(Some(element.into()));
+        }
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            id: context.trigger_id,
+            "aria-haspopup": "dialog",
+            "aria-expanded": if is_open { "true" } else { "false" },
+            "aria-controls": context.content_id,
+            "data-state": if is_open { "open" } else { "closed" },
+            onmounted,
+            onclick: move |_| open.set(!open.peek()),
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverAnchor
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Positions the content against this element instead of the trigger, e.g. a whole input
+/// group whose trigger is a small button at its end.
+#[component]
+pub fn PopoverAnchor(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let context = use_context::<PopoverContext>();
+    let mut anchor = context.anchor;
+    let mut has_custom_anchor = context.has_custom_anchor;
+    let trigger_ref = context.trigger_ref;
+
+    // Fall back to the trigger once the anchor goes away
+    use_drop(move || {
+        has_custom_anchor.set(false);
+        anchor.set(trigger_ref.peek().clone().map(Anchor::from));
+    });
+
+    rsx! {
+        div {
+            onmounted: move |event: MountedEvent| {
+                has_custom_anchor.set(true);
+                anchor.set(Some(event.data().into()));
+            },
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn PopoverContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The preferred side of the anchor to render against
+    #[props(default = PopoverSide::Bottom)]
+    side: PopoverSide,
+    /// The distance in pixels from the anchor
+    #[props(default = 4)]
+    side_offset: i32,
+    /// The alignment against the anchor. Collisions shift the content rather than change it.
+    #[props(default = PopoverAlign::Center)]
+    align: PopoverAlign,
+    /// An offset in pixels from the "start" or "end" alignment options
+    #[props(default = 0)]
+    align_offset: i32,
+    /// Flip and shift the content to keep it inside the viewport
+    #[props(default = true)]
+    avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in
+    #[props(default = 0)]
+    collision_padding: i32,
+    /// The padding between the arrow and the edges of the content
+    #[props(default = 0)]
+    arrow_padding: i32,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when Escape is pressed while the popover is open. Call `prevent_default` to
+    /// keep it open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside the popover. Call `prevent_default` to keep
+    /// it open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when focus moves outside the popover. Call `prevent_default` to keep it open.
+    on_focus_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer press or focus lands outside the popover. Call
+    /// `prevent_default` to keep it open.
+    on_interact_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called before focus moves into the popover when it opens. Call `prevent_default` to
+    /// keep focus where it is.
+    on_open_auto_focus: Option<EventHandler<Event<()>>>,
+    /// Called before focus returns to the trigger when the popover closes. Call
+    /// `prevent_default` to leave focus alone.
+    on_close_auto_focus: Option<EventHandler<Event<()>>>,
+    /// Keep the content mounted while closed, e.g. to drive its animation from outside
+    #[props(default = false)]
+    force_mount: bool,
+    children: Element,
+) -> Element {
+    let context = use_context::<PopoverContext>();
+    let mut open = context.open;
+    let is_open = open.get();
+    let modal = context.modal;
+    let layer = context.layer;
+
+    let mut floating = use_floating(
+        context.anchor,
+        FloatingOptions {
+            side,
+            align,
+            side_offset: side_offset as f64,
+            align_offset: align_offset as f64,
+            avoid_collisions,
+            collision_padding: collision_padding as f64,
+            arrow_padding: arrow_padding as f64,
+            ..FloatingOptions::default()
+        },
+    );
+    use_context_provider(|| floating);
+
+    // Follow the anchor while the page scrolls or resizes
+    use_auto_update(floating, is_open);
+
+    // Stays mounted after closing until its exit animation finishes
+    let presence = use_presence(is_open, context.content_id.clone());
+
+    use_scroll_lock(is_open && modal);
+
+    // Close on Escape and on pointer presses or focus outside while this is the topmost
+    // open layer. The trigger counts as inside, since it already toggles the popover.
+    let interacted_outside = use_hook(|| Rc::new(Cell::new(false)));
+    let dismissed_outside = interacted_outside.clone();
+    use_dismissable_layer(
+        layer,
+        context.content_id.clone(),
+        DismissableLayerOptions {
+            escape: true,
+            outside: true,
+            inside_ids: vec![context.trigger_id.clone()],
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_focus_outside,
+            on_interact_outside,
+        },
+        move |reason| {
+            // A modal popover sends focus back to the trigger however it closed
+            dismissed_outside.set(!modal && reason != DismissReason::Escape);
+            open.set(false);
+        },
+    );
+
+    if !force_mount && !presence.is_present() {
+        return rsx! {};
+    }
+
+    let z_index = layer.z_index();
+    let pointer_events = if is_open { "auto" } else { "none" };
+    let state = if is_open { "open" } else { "closed" };
+
+    let position = floating.position();
+    let placed_side = position.map(|pos| pos.side).unwrap_or(side);
+    let placed_align = position.map(|pos| pos.align).unwrap_or(align);
+
+    // Content mounted while closed doesn't take focus. Opening starts without an outside
+    // interaction, so closing later returns focus unless one happens.
+    let outside_interaction = interacted_outside.clone();
+    let on_mount_auto_focus = move |event: Event<()>| {
+        outside_interaction.set(false);
+        if !is_open {
+            event.prevent_default();
+            return;
+        }
+        if let Some(handler) = &on_open_auto_focus {
+            handler.call(event);
+        }
+    };
+
+    // Leave focus wherever the user clicked or tabbed to
+    let on_unmount_auto_focus = move |event: Event<()>| {
+        if interacted_outside.get() {
+            event.prevent_default();
+            return;
+        }
+        if let Some(handler) = &on_close_auto_focus {
+            handler.call(event);
+        }
+    };
+
+    let class_name = utils::cn(vec![
+        Some("popover-content bg-popover text-popover-foreground w-72 rounded-md border p-4 shadow-md outline-none"),
+        Some(class.as_str()),
+    ]);
+
+    // Caller attributes replace the defaults of the same name; caller listeners run first
+    let content_attributes = vec![
+        Attribute::new("role", "dialog", None, false),
+        Attribute::new("id", context.content_id.clone(), None, false),
+        Attribute::new("data-state", state, None, false),
+        Attribute::new("data-side", placed_side.as_str(), None, false),
+        Attribute::new("data-align", placed_align.as_str(), None, false),
+        Attribute::new("class", class_name, None, false),
+    ];
+    let content_attributes = merge_attributes(content_attributes, attributes);
+
+    rsx! {
+        Portal {
+            container,
+            // Modal popovers block the page; pressing it counts as outside and closes them
+            if modal {
+                div {
+                    class: "popover-backdrop",
+                    "data-state": state,
+                    style: "position: fixed; inset: 0; z-index: {z_index}; pointer-events: {pointer_events};",
+                }
+            }
+            // The wrapper is what gets positioned, so the content's own transform is free
+            // for animations
+            div {
+                "data-popover-content-wrapper": "",
+                style: "{floating.style()} z-index: {z_index + 1};",
+                onmounted: move |event: MountedEvent| floating.set_content(event.data()),
+                FocusScope {
+                    trapped: modal && is_open,
+                    r#loop: modal,
+                    on_mount_auto_focus,
+                    on_unmount_auto_focus,
+                    attributes: content_attributes,
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn PopoverArrow(
+    #[props(default = String::new())] class: String,
+    #[props(default = 10)] width: u32,
+    #[props(default = 5)] height: u32,
+) -> Element {
+    let class_name = utils::cn(vec![Some("fill-popover"), Some(class.as_str())]);
+
+    rsx! {
+        FloatingArrow { class: class_name, width, height }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverClose
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn PopoverClose(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let mut open = use_context::<PopoverContext>().open;
+
+    rsx! {
+        button {
+            r#type: "button",
+            onclick: move |_| open.set(false),
+            ..attributes,
+            {children}
+        }
+    }
+}
@@ -1,4 +1,11 @@
+use crate::platform::PortalMount;
+use crate::utils::use_id;
+use dioxus::core::use_drop;
 use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub use crate::platform::PortalContainer;
 
 /// Props for the Portal component
 #[derive(Props, Clone, PartialEq)]
@@ -6,11 +13,11 @@ pub struct PortalProps {
     /// The content to be portaled
     children: Element,
 
-    /// Optional container selector where the content should be appended
-    /// If None, defaults to document.body
-    /// Use CSS selectors like "body", "#root", ".container"
-    #[props(default = "main".to_string())]
-    container: String,
+    /// Where the content is moved: a CSS selector such as "body", "#root" or ".container",
+    /// or (on the web) an element. Defaults to the nearest [`PortalProvider`]'s container,
+    /// or the app root (`#main`) without one.
+    #[props(into)]
+    container: Option<PortalContainer>,
 
     /// Optional class name for the portal wrapper div
     #[props(default = None)]
@@ -19,6 +26,10 @@ pub struct PortalProps {
     /// Optional id for the portal wrapper div
     #[props(default = None)]
     id: Option<String>,
+
+    /// Optional inline style for the portal wrapper div
+    #[props(default = None)]
+    style: Option<String>,
 }
 
 /// Portal component that renders children into a different part of the DOM tree
@@ -27,15 +38,15 @@ pub struct PortalProps {
 /// It allows you to render components outside of the parent component's DOM hierarchy.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// rsx! {
 ///     div {
 ///         "This is in the normal hierarchy"
 ///         Portal {
-///             container: "body",
+///             container: "#overlays",
 ///             class: "modal-overlay",
 ///             div {
-///                 "This will be rendered directly in document.body"
+///                 "This will be rendered inside #overlays"
 ///             }
 ///         }
 ///     }
@@ -43,59 +54,101 @@ pub struct PortalProps {
 /// ```
 ///
 /// # Implementation Notes
-/// This implementation uses inline JavaScript in an onmounted event to physically move
-/// the portal element to the specified container in the DOM, matching React's createPortal behavior.
+/// The wrapper div is rendered in place, then moved into the container as soon as it
+/// mounts, and removed from there when the portal unmounts. It gets no styling of its own.
+///
+/// Dioxus handles bubbling events such as `onclick` at the element the app is mounted
+/// into, so the container must be inside it for handlers in the portaled content to fire.
 #[component]
 pub fn Portal(props: PortalProps) -> Element {
-    let mut mounted = use_signal(|| false);
+    let context = try_use_context::<PortalContext>();
+    let generated_id = use_id("portal");
+    let portal_id = props.id.clone().unwrap_or(generated_id);
 
-    // Generate a unique ID using JavaScript's Math.random() (WASM-compatible)
-    let portal_id = format!(
-        "portal-{}",
-        (js_sys::Math::random() * 1_000_000_000.0) as u64
-    );
+    let container = props
+        .container
+        .clone()
+        .or_else(|| context.map(|context| context.container.cloned()))
+        .unwrap_or_default();
 
-    // Set mounted after first render
-    use_effect(move || {
-        mounted.set(true);
-    });
+    // The container the wrapper was moved into, if it has mounted
+    let mount = use_hook(|| Rc::new(RefCell::new(None::<(PortalContainer, PortalMount)>)));
 
-    // Only render when mounted (client-side only)
-    if !mounted() {
-        return rsx! {};
+    // Follow a container that changes after mounting
+    if let Some((current, moved)) = mount.borrow_mut().as_mut() {
+        if *current != container {
+            // Move the mounted element rather than replacing the mount, whose drop would
+            // remove it from the document
+            moved.move_to(&container);
+            *current = container.clone();
+        }
     }
 
-    let container = props.container.clone();
-    let portal_id_clone = portal_id.clone();
+    let on_mounted = {
+        let mount = mount.clone();
+        let portal_id = portal_id.clone();
+        let container = container.clone();
+        move |_| {
+            *mount.borrow_mut() =
+                Some((container.clone(), PortalMount::new(&portal_id, &container)));
+        }
+    };
+
+    use_drop(move || {
+        mount.borrow_mut().take();
+    });
 
     rsx! {
         div {
             class: props.class.clone(),
-            id: props.id.clone(),
-            style: "position: fixed; z-index: 9999; inset: 0;",
-            "data-portal-id": portal_id.clone(),
-            "data-portal-container": props.container.clone(),
-
-            // Move portal to target container when mounted
-            onmounted: move |_| {
-                let script = format!(
-                    r#"
-                    setTimeout(function() {{
-                        const portalElement = document.querySelector('[data-portal-id="{}"]');
-                        const targetContainer = document.querySelector('#{}');
-                        
-                        if (portalElement && targetContainer) {{
-                            targetContainer.appendChild(portalElement);
-                        }}
-                    }}, 50);
-                    "#,
-                    portal_id_clone, container
-                );
-
-                let _ = js_sys::Function::new_no_args(&script).call0(&wasm_bindgen::JsValue::NULL);
-            },
-
+            id: portal_id,
+            style: props.style.clone(),
+            onmounted: on_mounted,
             {props.children}
         }
     }
 }
+
+/* -------------------------------------------------------------------------------------------------
+ * PortalProvider
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct PortalContext {
+    container: Signal<PortalContainer>,
+}
+
+/// Sets the default container for every [`Portal`] below it, including the ones inside
+/// dialogs and tooltips, unless they name their own.
+///
+/// # Example
+/// ```rust,ignore
+/// rsx! {
+///     PortalProvider {
+///         container: "#overlays",
+///         App {}
+///     }
+///     div { id: "overlays" }
+/// }
+/// ```
+#[component]
+pub fn PortalProvider(
+    /// The default container for portals in this subtree
+    #[props(into)]
+    container: PortalContainer,
+    children: Element,
+) -> Element {
+    let context = use_context_provider(|| PortalContext {
+        container: Signal::new(container.clone()),
+    });
+    use_effect(use_reactive((&container,), move |(container,)| {
+        let mut current = context.container;
+        if *current.peek() != container {
+            current.set(container);
+        }
+    }));
+
+    rsx! {
+        {children}
+    }
+}
@@ -0,0 +1,162 @@
+//! # Presence
+//!
+//! Keeps content mounted while it animates out, so `data-state="closed"` CSS animations
+//! and transitions get to play before the element disappears.
+//!
+//! When `present` turns false the content stays rendered (with `data-state="closed"`)
+//! and the element's computed style is checked for animations and transitions:
+//!
+//! - with none, it unmounts right away;
+//! - otherwise it unmounts on `animationend`/`transitionend`, or once the longest
+//!   animation's duration has passed if no end event arrives (for example because the
+//!   element was hidden mid-animation).
+//!
+//! Components use [`use_presence`] on their own element; [`Presence`] wraps arbitrary
+//! content in a `div` that does the same:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Toast(visible: bool) -> Element {
+//!     rsx! {
+//!         Presence {
+//!             present: visible,
+//!             class: "toast",
+//!             "Saved"
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::platform::{self, use_event_listener, ListenerEvent, ListenerOptions, ListenerTarget};
+use crate::utils::{take_id_attribute, use_id};
+use dioxus::core::Task;
+use dioxus::prelude::*;
+
+/// Extra time allowed after an animation's computed duration for its end event to arrive.
+const EXIT_GRACE_MS: u64 = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Phase {
+    /// Present, or absent but not yet checked for an exit animation.
+    Mounted,
+    /// Absent and playing an exit animation.
+    Exiting,
+    Unmounted,
+}
+
+/// Whether content managed by [`use_presence`] should be rendered.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UsePresence {
+    present: bool,
+    phase: Signal<Phase>,
+}
+
+impl UsePresence {
+    /// Whether to render the content: while present, and while its exit animation plays.
+    /// Subscribes the caller to changes.
+    pub fn is_present(&self) -> bool {
+        self.present || *self.phase.read() != Phase::Unmounted
+    }
+}
+
+/// Tracks whether the element with `element_id` should stay mounted: while `present`, and
+/// afterwards until its exit animation finishes.
+///
+/// Render the element while [`UsePresence::is_present`] is true, with a `data-state` that
+/// follows `present` (so CSS can start the exit animation).
+pub fn use_presence(present: bool, element_id: String) -> UsePresence {
+    let mut phase = use_signal(|| {
+        if present {
+            Phase::Mounted
+        } else {
+            Phase::Unmounted
+        }
+    });
+    let mut exit_task = use_signal(|| None::<Task>);
+
+    // Ends the exit once the element's own animation or transition finishes; those of its
+    // children bubble up to it too
+    let exiting = *phase.read() == Phase::Exiting;
+    let target_id = element_id.clone();
+    let on_end = move |event: ListenerEvent| {
+        if event.target_id == target_id && *phase.peek() == Phase::Exiting {
+            phase.set(Phase::Unmounted);
+        }
+    };
+    use_event_listener(
+        ListenerTarget::Element(element_id.clone()),
+        "animationend",
+        ListenerOptions::default(),
+        exiting,
+        on_end.clone(),
+    );
+    use_event_listener(
+        ListenerTarget::Element(element_id.clone()),
+        "transitionend",
+        ListenerOptions::default(),
+        exiting,
+        on_end,
+    );
+
+    use_effect(use_reactive((&present,), move |(present,)| {
+        if let Some(task) = exit_task.write().take() {
+            task.cancel();
+        }
+        if present {
+            if *phase.peek() != Phase::Mounted {
+                phase.set(Phase::Mounted);
+            }
+            return;
+        }
+        if *phase.peek() != Phase::Mounted {
+            return;
+        }
+
+        // The element has just re-rendered as closed, so its computed style now shows
+        // whether an exit animation started
+        phase.set(Phase::Exiting);
+        let id = element_id.clone();
+        exit_task.set(Some(spawn(async move {
+            let duration = platform::animation_duration(&id).await;
+            if duration > 0 {
+                platform::sleep(duration + EXIT_GRACE_MS).await;
+            }
+            if *phase.peek() == Phase::Exiting {
+                phase.set(Phase::Unmounted);
+            }
+        })));
+    }));
+
+    UsePresence { present, phase }
+}
+
+#[component]
+pub fn Presence(
+    /// Whether the content should be shown. It stays mounted after this turns false until
+    /// its exit animation finishes.
+    present: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let generated_id = use_id("presence");
+
+    let mut attributes = attributes;
+    let id = take_id_attribute(&mut attributes, generated_id);
+
+    let presence = use_presence(present, id.clone());
+    if !presence.is_present() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            id,
+            "data-state": if present { "open" } else { "closed" },
+            ..attributes,
+            {children}
+        }
+    }
+}
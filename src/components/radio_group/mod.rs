@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod radio_group;
+
+pub use radio_group::*;
@@ -0,0 +1,528 @@
+//! # RadioGroup Component
+//!
+//! A set of checkable buttons, known as radio buttons, where no more than one can be
+//! checked at a time. It is the single-choice counterpart of [`Checkbox`], built the same
+//! way: `button`s with `role="radio"`, an indicator that animates in and out, and a hidden
+//! input that takes part in native forms.
+//!
+//! ## Architecture
+//! ```text
+//! RadioGroup (state, role="radiogroup")
+//! ├── RadioGroupItem (button role="radio")
+//! │   └── RadioGroupIndicator (visual state indicator)
+//! └── RadioGroupBubbleInput (one hidden form input for the whole group)
+//! ```
+//!
+//! ## Keyboard
+//! The items form one tab stop, on the checked item (or the first enabled one). The arrow
+//! keys move focus and check the item they land on, wrapping at the ends and skipping
+//! disabled items; with an `orientation`, only the arrow keys along it do. Space checks
+//! the focused item, and Enter does nothing, as in native radio groups.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Density() -> Element {
+//!     rsx! {
+//!         RadioGroup {
+//!             default_value: "comfortable",
+//!             name: "density",
+//!             aria_label: "Density",
+//!             for (value, label) in [("default", "Default"), ("comfortable", "Comfortable")] {
+//!                 div {
+//!                     key: "{value}",
+//!                     class: "flex items-center gap-3",
+//!                     RadioGroupItem { value, id: "density-{value}", RadioGroupIndicator {} }
+//!                     label { r#for: "density-{value}", "{label}" }
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`Checkbox`]: crate::components::checkbox::Checkbox
+
+use crate::components::presence::use_presence;
+use crate::platform::{self, use_event_listener, ListenerOptions, ListenerTarget};
+use crate::utils::{
+    self, roving_index, use_collection, use_collection_item, use_controllable_state, use_id,
+    Collection, CollectionItem, ControllableState,
+};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const RADIO_GROUP_CSS: &str = include_str!("./radio_group.css");
+
+/* -------------------------------------------------------------------------------------------------
+ * Types
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The direction the items are laid out in, which limits the arrow keys that move between
+/// them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RadioGroupOrientation {
+    /// ArrowLeft/ArrowRight move between items.
+    Horizontal,
+    /// ArrowUp/ArrowDown move between items.
+    Vertical,
+}
+
+impl RadioGroupOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RadioGroupOrientation::Horizontal => "horizontal",
+            RadioGroupOrientation::Vertical => "vertical",
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RadioGroup Context
+ * -----------------------------------------------------------------------------------------------*/
+
+/// An item registered with its group, for keyboard navigation and the tab stop.
+#[derive(Clone)]
+struct ItemEntry {
+    id: String,
+    value: String,
+    disabled: bool,
+}
+
+impl CollectionItem for ItemEntry {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Clone)]
+struct RadioGroupContext {
+    /// The value of the checked item, or an empty string for none.
+    value: ControllableState<String>,
+    /// The value restored on form reset.
+    default_value: String,
+    disabled: bool,
+    required: bool,
+    name: Option<String>,
+    form: Option<String>,
+    orientation: Option<RadioGroupOrientation>,
+    r#loop: bool,
+    /// The items in document order. Their version is zero until the group has mounted.
+    items: Collection<ItemEntry>,
+}
+
+impl RadioGroupContext {
+    /// Checks the item with `value` and reports it through `on_value_change`.
+    fn check(&self, value: &str) {
+        if self.value.peek() != value {
+            let mut state = self.value;
+            state.set(value.to_string());
+        }
+    }
+
+    /// Restores `default_value` the way a form reset does.
+    fn reset(&self) {
+        self.check(&self.default_value.clone());
+    }
+
+    fn enabled_items(&self) -> Vec<ItemEntry> {
+        self.items
+            .borrow()
+            .iter()
+            .filter(|item| !item.disabled)
+            .cloned()
+            .collect()
+    }
+
+    /// The item Tab moves to: the checked one, or the first enabled one when none is
+    /// checked or the checked item is disabled.
+    fn tab_stop(&self) -> Option<String> {
+        let mounted = self.items.version() > 0;
+        let value = self.value.get();
+        let items = self.items.borrow();
+        match items.iter().find(|item| item.value == value) {
+            Some(item) if !item.disabled => return Some(item.id.clone()),
+            // Until mounted, the checked item may simply not have rendered yet
+            None if !mounted && !value.is_empty() => return None,
+            _ => {}
+        }
+        items
+            .iter()
+            .find(|item| !item.disabled)
+            .map(|item| item.id.clone())
+    }
+}
+
+/// Set by `RadioGroupItem` for its indicator.
+#[derive(Clone, Copy)]
+struct RadioGroupItemContext {
+    checked: Memo<bool>,
+    disabled: bool,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RadioGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupProps {
+    /// The controlled value of the checked item
+    #[props(optional, into)]
+    pub value: Option<String>,
+
+    /// The value of the item checked by default when uncontrolled, restored on form reset
+    #[props(into, default = String::new())]
+    pub default_value: String,
+
+    /// Callback when the user checks an item (click, arrow keys or form reset). In
+    /// controlled mode the value only changes once `value` is updated from here.
+    #[props(optional)]
+    pub on_value_change: Option<EventHandler<String>>,
+
+    /// Whether every item is disabled
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// Whether an item has to be checked before the form can be submitted
+    #[props(default = false)]
+    pub required: bool,
+
+    /// The name the checked value is submitted under
+    #[props(optional, into)]
+    pub name: Option<String>,
+
+    /// The form ID this group belongs to
+    #[props(optional, into)]
+    pub form: Option<String>,
+
+    /// The direction the items are laid out in. Without it all arrow keys move.
+    #[props(optional)]
+    pub orientation: Option<RadioGroupOrientation>,
+
+    /// Whether the arrow keys move from the last item to the first, and back
+    #[props(default = true)]
+    pub r#loop: bool,
+
+    /// The ID of the group element (generated when not provided)
+    #[props(optional, into)]
+    pub id: Option<String>,
+
+    /// Additional CSS classes
+    #[props(optional, into)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// Children elements (RadioGroupItems and their labels)
+    pub children: Element,
+}
+
+#[component]
+pub fn RadioGroup(props: RadioGroupProps) -> Element {
+    let default_value = props.default_value.clone();
+    let value =
+        use_controllable_state(props.value.clone(), || default_value, props.on_value_change);
+    let generated_id = use_id("radio-group");
+    let id = props.id.clone().unwrap_or(generated_id);
+    let items = use_collection();
+
+    let context = use_context_provider(|| RadioGroupContext {
+        value,
+        default_value: props.default_value.clone(),
+        disabled: props.disabled,
+        required: props.required,
+        name: props.name.clone(),
+        form: props.form.clone(),
+        orientation: props.orientation,
+        r#loop: props.r#loop,
+        items,
+    });
+
+    // Form reset support
+    let reset_target = match &context.form {
+        Some(form) => ListenerTarget::Element(form.clone()),
+        None => ListenerTarget::FormOf(id.clone()),
+    };
+    let reset_context = context.clone();
+    use_event_listener(
+        reset_target,
+        "reset",
+        ListenerOptions::default(),
+        true,
+        move |_| reset_context.reset(),
+    );
+
+    let class_name = utils::cn(vec![
+        Some("grid gap-3 data-[orientation=horizontal]:auto-cols-max data-[orientation=horizontal]:grid-flow-col"),
+        props.class.as_deref(),
+    ]);
+    let orientation = props.orientation.map(|orientation| orientation.as_str());
+
+    rsx! {
+        style { {RADIO_GROUP_CSS} }
+        div {
+            role: "radiogroup",
+            id: "{id}",
+            class: "{class_name}",
+            "aria-required": if props.required { "true" } else { "false" },
+            "aria-orientation": orientation,
+            "data-orientation": orientation,
+            "data-disabled": if props.disabled { Some("") } else { None },
+            ..props.attributes,
+            {props.children}
+        }
+        RadioGroupBubbleInput { control: id.clone() }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RadioGroupItem
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupItemProps {
+    /// The value submitted with the form while this item is checked
+    #[props(into)]
+    pub value: String,
+
+    /// Whether the item is disabled
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// The ID attribute, for a `<label for>` (generated when not provided)
+    #[props(optional, into)]
+    pub id: Option<String>,
+
+    /// Additional CSS classes
+    #[props(optional, into)]
+    pub class: Option<String>,
+
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// Children elements (typically RadioGroupIndicator)
+    #[props(default)]
+    pub children: Element,
+}
+
+#[component]
+pub fn RadioGroupItem(props: RadioGroupItemProps) -> Element {
+    let group = use_context::<RadioGroupContext>();
+    let generated_id = use_id("radio-group-item");
+    let id = props.id.clone().unwrap_or(generated_id);
+    let value = props.value.clone();
+    let disabled = group.disabled || props.disabled;
+
+    use_collection_item(
+        &group.items,
+        ItemEntry {
+            id: id.clone(),
+            value: value.clone(),
+            disabled,
+        },
+    );
+    let changed_items = group.items.clone();
+    use_effect(use_reactive!(|value, disabled| {
+        let _ = (value, disabled);
+        changed_items.changed();
+    }));
+
+    let checked_value = value.clone();
+    let checked_state = group.value;
+    let checked = use_memo(use_reactive!(
+        |checked_value| checked_state.get() == checked_value
+    ));
+    use_context_provider(|| RadioGroupItemContext { checked, disabled });
+    let is_checked = checked();
+    let tab_stop = group.tab_stop().as_deref() == Some(id.as_str());
+
+    let click_group = group.clone();
+    let click_value = value.clone();
+    let handle_click = move |_| {
+        if !disabled {
+            click_group.check(&click_value);
+        }
+    };
+
+    let key_group = group.clone();
+    let key_id = id.clone();
+    let handle_keydown = move |event: KeyboardEvent| {
+        let key = event.key();
+        // According to WAI ARIA, radio groups don't activate items on Enter keypress
+        if key == Key::Enter {
+            event.prevent_default();
+            return;
+        }
+        let items = key_group.enabled_items();
+        let Some(current) = items.iter().position(|item| item.id == key_id) else {
+            return;
+        };
+        let (horizontal, vertical) = match key_group.orientation {
+            Some(RadioGroupOrientation::Horizontal) => (true, false),
+            Some(RadioGroupOrientation::Vertical) => (false, true),
+            None => (true, true),
+        };
+        let Some(next) = roving_index(
+            &key,
+            current,
+            items.len(),
+            horizontal,
+            vertical,
+            key_group.r#loop,
+        ) else {
+            return;
+        };
+        event.prevent_default();
+        let item = &items[next];
+        platform::focus(&item.id);
+        // Arrow keys check the item they move to; Home and End only move focus
+        if matches!(
+            key,
+            Key::ArrowUp | Key::ArrowDown | Key::ArrowLeft | Key::ArrowRight
+        ) {
+            key_group.check(&item.value);
+        }
+    };
+
+    let class_name = utils::cn(vec![
+        Some("border-input text-primary focus-visible:border-ring focus-visible:ring-ring/50 aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive dark:bg-input/30 aspect-square size-4 shrink-0 rounded-full border shadow-xs transition-[color,box-shadow] outline-none focus-visible:ring-[3px] disabled:cursor-not-allowed disabled:opacity-50"),
+        props.class.as_deref(),
+    ]);
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "radio",
+            id: "{id}",
+            class: "{class_name}",
+            disabled,
+            tabindex: if tab_stop { "0" } else { "-1" },
+            "aria-checked": if is_checked { "true" } else { "false" },
+            "data-state": if is_checked { "checked" } else { "unchecked" },
+            "data-disabled": if disabled { Some("") } else { None },
+            value: "{value}",
+            onclick: handle_click,
+            onkeydown: handle_keydown,
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RadioGroupIndicator
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupIndicatorProps {
+    /// Additional CSS classes
+    #[props(optional, into)]
+    pub class: Option<String>,
+
+    /// Force mount even when unchecked (for animations)
+    #[props(default = false)]
+    pub force_mount: bool,
+
+    /// Children elements (a custom icon instead of the dot)
+    #[props(default)]
+    pub children: Element,
+}
+
+#[component]
+pub fn RadioGroupIndicator(props: RadioGroupIndicatorProps) -> Element {
+    let item = use_context::<RadioGroupItemContext>();
+    let is_checked = (item.checked)();
+
+    // Stays mounted after unchecking until its exit animation finishes
+    let indicator_id = use_id("radio-group-indicator");
+    let presence = use_presence(is_checked, indicator_id.clone());
+
+    if !props.force_mount && !presence.is_present() {
+        return rsx! {};
+    }
+
+    let class_name = utils::cn(vec![
+        Some("radio-group-indicator relative flex items-center justify-center pointer-events-none"),
+        props.class.as_deref(),
+    ]);
+    let has_children = props.children != VNode::empty();
+
+    rsx! {
+        span {
+            id: indicator_id,
+            class: "{class_name}",
+            "data-state": if is_checked { "checked" } else { "unchecked" },
+            "data-disabled": if item.disabled { Some("") } else { None },
+            if has_children {
+                {props.children}
+            } else {
+                // Circle Icon from Lucide
+                svg {
+                    class: "fill-primary absolute top-1/2 left-1/2 size-2 -translate-x-1/2 -translate-y-1/2",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    width: "24",
+                    height: "24",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    circle { cx: "12", cy: "12", r: "10" }
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RadioGroupBubbleInput
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupBubbleInputProps {
+    /// The ID of the radio group element this input directly follows
+    pub control: String,
+}
+
+/// The hidden input that submits the group's value with its form. One radio input stands
+/// for the whole group: checked while an item is, so `required` validation works, and
+/// carrying the checked item's value. `RadioGroup` renders it.
+#[component]
+pub fn RadioGroupBubbleInput(props: RadioGroupBubbleInputProps) -> Element {
+    let group = use_context::<RadioGroupContext>();
+    let value = group.value.get();
+
+    // Dispatch native input/change events when the value changes, so plain form
+    // handlers and validation outside Dioxus notice
+    let previous = use_hook(|| Rc::new(RefCell::new(value.clone())));
+    let state = group.value;
+    use_effect(use_reactive!(|(props,)| {
+        let current = state.get();
+        if previous.replace(current.clone()) != current {
+            let selector = format!("{} + input", platform::id_selector(&props.control));
+            platform::dispatch_events(&selector, &["input", "change"]);
+        }
+    }));
+
+    rsx! {
+        input {
+            r#type: "radio",
+            "aria-hidden": "true",
+            checked: !value.is_empty(),
+            required: group.required,
+            disabled: group.disabled,
+            name: group.name.as_deref(),
+            value: "{value}",
+            form: group.form.as_deref(),
+            tabindex: -1,
+            style: "position: absolute; pointer-events: none; opacity: 0; margin: 0; transform: translateX(-100%);",
+        }
+    }
+}
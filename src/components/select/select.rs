@@ -0,0 +1,1430 @@
+//! # Select Component
+//!
+//! A button that opens a list of options to pick one from, for when a native `<select>`
+//! can't be styled to fit in. It follows the WAI-ARIA select-only combobox pattern:
+//!
+//! - pressing the trigger, or Enter, Space, ArrowDown or ArrowUp on it, opens the list with
+//!   the selected item focused;
+//! - typing on the closed trigger selects the next item starting with the typed text, and
+//!   typing in the open list focuses it;
+//! - ArrowDown/ArrowUp and Home/End move between items, skipping disabled ones;
+//! - Enter, Space or a click selects the focused item and closes the list, and Escape or a
+//!   press outside closes it without changing the value.
+//!
+//! By default the list opens over the trigger, with the selected item lined up with the
+//! trigger's value like a native select (`SelectPosition::ItemAligned`). Use
+//! `SelectPosition::Popper` to open it below the trigger like a dropdown menu.
+//!
+//! Like `Checkbox`, the value is mirrored into a hidden `<select>`, so it is submitted with
+//! its form under `name`, takes part in `required` validation, and is restored to
+//! `default_value` when the form resets.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn FruitPicker() -> Element {
+//!     rsx! {
+//!         form {
+//!             Select {
+//!                 name: "fruit",
+//!                 required: true,
+//!                 SelectTrigger {
+//!                     class: "w-[180px]",
+//!                     SelectValue { placeholder: "Pick a fruit" }
+//!                 }
+//!                 SelectContent {
+//!                     SelectScrollUpButton {}
+//!                     SelectGroup {
+//!                         SelectLabel { "Fruits" }
+//!                         SelectItem {
+//!                             value: "apple",
+//!                             SelectItemText { "Apple" }
+//!                             SelectItemIndicator { "✓" }
+//!                         }
+//!                         SelectItem {
+//!                             value: "banana",
+//!                             SelectItemText { "Banana" }
+//!                             SelectItemIndicator { "✓" }
+//!                         }
+//!                     }
+//!                     SelectSeparator {}
+//!                     SelectItem {
+//!                         value: "durian",
+//!                         disabled: true,
+//!                         SelectItemText { "Durian" }
+//!                     }
+//!                     SelectScrollDownButton {}
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::button::{button_variants, ButtonSize, ButtonVariant};
+use crate::components::dismissable_layer::{use_dismissable_layer, DismissableLayerOptions};
+use crate::components::focus_scope::FocusScope;
+use crate::components::portal::{Portal, PortalContainer};
+use crate::components::presence::use_presence;
+use crate::platform::{self, use_event_listener, ListenerEvent, ListenerOptions, ListenerTarget};
+use crate::utils::floating::{
+    use_auto_update, use_floating, viewport_rect, Align, Anchor, FloatingOptions, Rect, Side,
+};
+use crate::utils::{
+    self, element_text, merge_attributes, typeahead_match, use_collection, use_collection_item,
+    use_controllable_state, use_id, use_layer, use_scroll_lock, use_typeahead, Collection,
+    CollectionItem, ControllableState, Layer, Typeahead,
+};
+use dioxus::core::{use_drop, Task};
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::html::input_data::MouseButton;
+use dioxus::html::ScrollBehavior;
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const SELECT_CSS: &str = include_str!("./select.css");
+
+/// How close item-aligned content may come to the viewport edges.
+const CONTENT_MARGIN: f64 = 10.0;
+
+/// How far a scroll button scrolls the list on each step while hovered.
+const SCROLL_STEP_PX: f64 = 32.0;
+
+/// How often a hovered scroll button scrolls the list.
+const SCROLL_INTERVAL_MS: u64 = 50;
+
+/// How the open list is placed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SelectPosition {
+    /// Over the trigger, with the selected item lined up with the trigger's value, like a
+    /// native select.
+    #[default]
+    ItemAligned,
+    /// Next to the trigger, like a dropdown menu, using the content's `side` and `align`.
+    Popper,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Select state
+ * -----------------------------------------------------------------------------------------------*/
+
+/// An item registered with its select, for keyboard navigation, typeahead, the value shown
+/// on the trigger, and the options of the hidden `<select>`.
+#[derive(Clone)]
+struct ItemEntry {
+    id: String,
+    value: String,
+    disabled: bool,
+    /// The item's `text_value`.
+    text_value: Option<String>,
+    /// The text of its `SelectItemText`.
+    item_text: String,
+    /// The item's own text, for items without a `SelectItemText`.
+    own_text: String,
+    node: Option<Rc<MountedData>>,
+    text_node: Option<Rc<MountedData>>,
+}
+
+impl ItemEntry {
+    /// The text shown for the item on the trigger and matched by typeahead.
+    fn text(&self) -> &str {
+        match &self.text_value {
+            Some(text) => text,
+            None if !self.item_text.is_empty() => &self.item_text,
+            None => &self.own_text,
+        }
+    }
+}
+
+impl CollectionItem for ItemEntry {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn carry_over(&mut self, previous: &Self) {
+        self.item_text = previous.item_text.clone();
+        self.node = previous.node.clone();
+        self.text_node = previous.text_node.clone();
+    }
+}
+
+#[derive(Clone)]
+struct SelectContext {
+    value: ControllableState<String>,
+    /// The value restored on form reset.
+    default_value: String,
+    open: ControllableState<bool>,
+    disabled: bool,
+    required: bool,
+    name: Option<String>,
+    form: Option<String>,
+    trigger_id: String,
+    content_id: String,
+    /// What the content is placed against.
+    trigger: Signal<Option<Anchor>>,
+    /// The `SelectValue` element, which the selected item lines up with.
+    value_node: CopyValue<Option<Rc<MountedData>>>,
+    /// The items in document order. They are rendered while the list is closed too,
+    /// hidden, so the trigger always knows them.
+    items: Collection<ItemEntry>,
+    highlighted: Signal<Option<String>>,
+    typeahead: Typeahead,
+    layer: Layer,
+}
+
+impl SelectContext {
+    fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    fn set_open(&self, open: bool) {
+        let mut state = self.open;
+        state.set(open);
+    }
+
+    /// Selects `value` and closes the list.
+    fn select(&self, value: String) {
+        let mut state = self.value;
+        state.set(value);
+        self.set_open(false);
+    }
+
+    /// Restores `default_value` the way a form reset does.
+    fn reset(&self) {
+        let mut state = self.value;
+        state.set(self.default_value.clone());
+    }
+
+    fn enabled_items(&self) -> Vec<ItemEntry> {
+        self.items
+            .borrow()
+            .iter()
+            .filter(|item| !item.disabled)
+            .cloned()
+            .collect()
+    }
+
+    /// The text of the item with `value`, if there is one.
+    fn text_of(&self, value: &str) -> Option<String> {
+        self.items
+            .borrow()
+            .iter()
+            .find(|item| item.value == value)
+            .map(|item| item.text().to_string())
+    }
+
+    /// The item the open list starts on: the selected one, or the first enabled item.
+    fn current_item(&self) -> Option<ItemEntry> {
+        let value = self.value.peek();
+        let items = self.enabled_items();
+        items
+            .iter()
+            .find(|item| item.value == value)
+            .or(items.first())
+            .cloned()
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Select (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn Select(
+    /// Controlled value: the `value` of the selected item, or an empty string for none
+    #[props(into)]
+    value: Option<String>,
+    /// The value selected by default (uncontrolled), and restored on form reset
+    #[props(into, default = String::new())]
+    default_value: String,
+    /// Callback when the user selects another item (or a form reset restores the default)
+    on_value_change: Option<EventHandler<String>>,
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the list is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// The name the value is submitted under with its form
+    #[props(into)]
+    name: Option<String>,
+    /// The id of the form the select belongs to (default: the form around it)
+    #[props(into)]
+    form: Option<String>,
+    /// Whether the form can't be submitted without a value
+    #[props(default = false)]
+    required: bool,
+    /// Whether the select can't be opened or changed
+    #[props(default = false)]
+    disabled: bool,
+    children: Element,
+) -> Element {
+    let initial_value = default_value.clone();
+    let value = use_controllable_state(value, || initial_value, on_value_change);
+    let open = use_controllable_state(open, || default_open, on_open_change);
+    let trigger_id = use_id("select-trigger");
+    let content_id = use_id("select-content");
+    let trigger = use_signal(|| None::<Anchor>);
+    let value_node = use_hook(|| CopyValue::new(None::<Rc<MountedData>>));
+    let items = use_collection();
+    let highlighted = use_signal(|| None::<String>);
+    let typeahead = use_typeahead();
+    let layer = use_layer(open.get());
+
+    use_context_provider(|| SelectContext {
+        value,
+        default_value,
+        open,
+        disabled,
+        required,
+        name,
+        form,
+        trigger_id,
+        content_id,
+        trigger,
+        value_node,
+        items,
+        highlighted,
+        typeahead,
+        layer,
+    });
+
+    rsx! {
+        style { {SELECT_CSS} }
+        {children}
+        SelectBubbleInput {}
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The button showing the value, styled like an outline `Button`.
+#[component]
+pub fn SelectTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let select = use_context::<SelectContext>();
+    let is_open = select.is_open();
+    let disabled = select.disabled;
+    let has_value = !select.value.get().is_empty();
+    let mut anchor = select.trigger;
+
+    // Form reset support
+    let reset_target = match &select.form {
+        Some(form) => ListenerTarget::Element(form.clone()),
+        None => ListenerTarget::FormOf(select.trigger_id.clone()),
+    };
+    let reset_select = select.clone();
+    use_event_listener(
+        reset_target,
+        "reset",
+        ListenerOptions::default(),
+        true,
+        move |_| reset_select.reset(),
+    );
+
+    // Mice open the list on press, like native selects; touch opens it on click, so
+    // scrolling the page past the trigger doesn't
+    let mut pointer_type = use_hook(|| CopyValue::new(String::from("touch")));
+    let pointer_select = select.clone();
+    let onpointerdown = move |event: PointerEvent| {
+        pointer_type.set(event.pointer_type());
+        let primary = event.trigger_button() == Some(MouseButton::Primary);
+        if disabled || !primary || event.modifiers().ctrl() || event.pointer_type() != "mouse" {
+            return;
+        }
+        // Keep focus off the trigger so it can move to the selected item
+        event.prevent_default();
+        pointer_select.set_open(!pointer_select.open.peek());
+    };
+
+    let click_select = select.clone();
+    let onclick = move |_| {
+        if !disabled && *pointer_type.peek() != "mouse" {
+            click_select.set_open(true);
+        }
+    };
+
+    let trigger_id = select.trigger_id.clone();
+    let content_id = select.content_id.clone();
+    let required = select.required;
+    let onkeydown = move |event: KeyboardEvent| {
+        if disabled {
+            return;
+        }
+        let typeahead = select.typeahead;
+        match event.key() {
+            Key::Enter | Key::ArrowDown | Key::ArrowUp => {}
+            Key::Character(character) if character == " " && !typeahead.is_searching() => {}
+            Key::Character(character) => {
+                let modifiers = event.modifiers();
+                if character.chars().count() != 1
+                    || modifiers.ctrl()
+                    || modifiers.alt()
+                    || modifiers.meta()
+                {
+                    return;
+                }
+                // Typing on the closed trigger selects the match right away
+                let items = select.enabled_items();
+                let value = select.value.peek();
+                let current = items.iter().position(|item| item.value == value);
+                let search = typeahead.push(&character);
+                let texts: Vec<&str> = items.iter().map(|item| item.text()).collect();
+                if let Some(item) = typeahead_match(&texts, current, &search).map(|i| &items[i]) {
+                    let mut state = select.value;
+                    state.set(item.value.clone());
+                }
+                return;
+            }
+            _ => return,
+        }
+        event.prevent_default();
+        select.set_open(true);
+    };
+
+    let class_name = utils::cn(vec![
+        Some(button_variants(ButtonVariant::Outline, ButtonSize::Default).as_str()),
+        Some("justify-between font-normal data-[placeholder]:text-muted-foreground"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "combobox",
+            id: trigger_id,
+            class: "{class_name}",
+            disabled,
+            "aria-controls": content_id,
+            "aria-expanded": if is_open { "true" } else { "false" },
+            "aria-required": if required { "true" } else { "false" },
+            "aria-autocomplete": "none",
+            "data-state": if is_open { "open" } else { "closed" },
+            "data-disabled": if disabled { Some("") } else { None },
+            "data-placeholder": if has_value { None } else { Some("") },
+            onmounted: move |event: MountedEvent| {
+                anchor.set(Some(event.data().into()));
+            },
+            onpointerdown,
+            onclick,
+            onkeydown,
+            ..attributes,
+            {children}
+            svg {
+                class: "size-4 opacity-50",
+                "aria-hidden": "true",
+                xmlns: "http://www.w3.org/2000/svg",
+                width: "24",
+                height: "24",
+                view_box: "0 0 24 24",
+                fill: "none",
+                stroke: "currentColor",
+                stroke_width: "2",
+                stroke_linecap: "round",
+                stroke_linejoin: "round",
+                path { d: "m6 9 6 6 6-6" }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectValue
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The text of the selected item, or `placeholder` while there is none. Goes inside the
+/// trigger.
+#[component]
+pub fn SelectValue(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    /// Shown while no item is selected
+    #[props(into, default = String::new())]
+    placeholder: String,
+) -> Element {
+    let select = use_context::<SelectContext>();
+    let mut value_node = select.value_node;
+    let value = select.value.get();
+    // Re-read the texts whenever an item changes
+    let _ = select.items.version();
+    let text = if value.is_empty() {
+        placeholder
+    } else {
+        select.text_of(&value).unwrap_or(value)
+    };
+
+    rsx! {
+        span {
+            class: "line-clamp-1 flex items-center gap-2",
+            style: "pointer-events: none;",
+            onmounted: move |event: MountedEvent| value_node.set(Some(event.data())),
+            ..attributes,
+            "{text}"
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectBubbleInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The hidden native `<select>` mirroring the value for form submission and validation.
+#[component]
+fn SelectBubbleInput() -> Element {
+    let select = use_context::<SelectContext>();
+    let id = use_id("select-bubble");
+    let value = select.value.get();
+    let _ = select.items.version();
+    let options: Vec<(String, String, bool)> = select
+        .items
+        .borrow()
+        .iter()
+        .map(|item| (item.value.clone(), item.text().to_string(), item.disabled))
+        .collect();
+
+    // Dispatch native input/change events when the value changes, so plain form handlers
+    // and validation outside Dioxus notice
+    let previous = use_hook(|| Rc::new(RefCell::new(value.clone())));
+    let state = select.value;
+    let bubble_id = id.clone();
+    use_effect(move || {
+        let current = state.get();
+        if *previous.borrow() != current {
+            previous.replace(current);
+            platform::dispatch_events(&platform::id_selector(&bubble_id), &["input", "change"]);
+        }
+    });
+
+    rsx! {
+        select {
+            id,
+            "aria-hidden": "true",
+            tabindex: -1,
+            name: select.name.as_deref(),
+            form: select.form.as_deref(),
+            required: select.required,
+            disabled: select.disabled,
+            style: "position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap;",
+            // An empty value fails `required`, like an unanswered native select
+            if value.is_empty() {
+                option { value: "", selected: true }
+            }
+            for (option_value, text, disabled) in options {
+                option {
+                    key: "{option_value}",
+                    selected: option_value == value,
+                    disabled,
+                    value: "{option_value}",
+                    "{text}"
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectContent
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Where item-aligned content goes, from [`item_aligned_position`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ItemAlignedPosition {
+    x: f64,
+    y: f64,
+    /// Wide enough to cover the trigger.
+    min_width: f64,
+    /// Tall enough to fill the viewport, less the margins.
+    max_height: f64,
+    /// How far to scroll the list so the item still lines up after the content was moved
+    /// to stay inside the viewport.
+    scroll_top: f64,
+}
+
+/// Places content over the trigger so that `item_text` (the selected item's text) sits
+/// exactly over `value` (the trigger's value), then keeps it inside the viewport.
+///
+/// The content, item and item text rectangles are measured with the content unscrolled,
+/// wherever it happens to be; only their offsets from each other matter.
+fn item_aligned_position(
+    trigger: Rect,
+    value: Rect,
+    content: Rect,
+    item: Rect,
+    item_text: Rect,
+    viewport: Rect,
+) -> ItemAlignedPosition {
+    // Horizontally, line the texts up and cover at least the trigger
+    let text_offset = item_text.x - content.x;
+    let min_width = trigger.right() - value.x + text_offset;
+    let width = content.width.max(min_width);
+    let max_x = (viewport.right() - CONTENT_MARGIN - width).max(CONTENT_MARGIN);
+    let x = (value.x - text_offset).clamp(CONTENT_MARGIN, max_x);
+
+    // Vertically, put the item's middle on the trigger's middle
+    let item_middle = item.y - content.y + item.height / 2.0;
+    let trigger_middle = trigger.y + trigger.height / 2.0;
+    let max_height = (viewport.height - 2.0 * CONTENT_MARGIN).max(0.0);
+    let height = content.height.min(max_height);
+    let max_y = (viewport.bottom() - CONTENT_MARGIN - height).max(CONTENT_MARGIN);
+    let y = (trigger_middle - item_middle).clamp(CONTENT_MARGIN, max_y);
+    let scroll_top = (y + item_middle - trigger_middle).max(0.0);
+
+    ItemAlignedPosition {
+        x,
+        y,
+        min_width,
+        max_height,
+        scroll_top,
+    }
+}
+
+/// The content's scroll container, shared with the scroll buttons.
+#[derive(Clone, Copy)]
+struct SelectViewportContext {
+    viewport: CopyValue<Option<Rc<MountedData>>>,
+    can_scroll_up: Signal<bool>,
+    can_scroll_down: Signal<bool>,
+}
+
+impl SelectViewportContext {
+    /// Measures the viewport again to show or hide the scroll buttons.
+    fn update(&self) {
+        let viewport = self.viewport;
+        let mut can_scroll_up = self.can_scroll_up;
+        let mut can_scroll_down = self.can_scroll_down;
+        spawn(async move {
+            let Some(node) = viewport.cloned() else {
+                return;
+            };
+            let (Ok(offset), Ok(size), Ok(rect)) = (
+                node.get_scroll_offset().await,
+                node.get_scroll_size().await,
+                node.get_client_rect().await,
+            ) else {
+                return;
+            };
+            let up = offset.y > 0.0;
+            let down = offset.y.ceil() + rect.size.height < size.height;
+            if *can_scroll_up.peek() != up {
+                can_scroll_up.set(up);
+            }
+            if *can_scroll_down.peek() != down {
+                can_scroll_down.set(down);
+            }
+        });
+    }
+
+    /// Scrolls the viewport by `delta` pixels, or to `delta` from the top with `absolute`.
+    async fn scroll(&self, delta: f64, absolute: bool) {
+        let Some(node) = self.viewport.cloned() else {
+            return;
+        };
+        let top = if absolute {
+            delta
+        } else {
+            match node.get_scroll_offset().await {
+                Ok(offset) => offset.y + delta,
+                Err(_) => return,
+            }
+        };
+        let _ = node
+            .scroll(PixelsVector2D::new(0.0, top), ScrollBehavior::Instant)
+            .await;
+        self.update();
+    }
+}
+
+#[component]
+pub fn SelectContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Over the trigger like a native select, or next to it like a dropdown menu
+    #[props(default)]
+    position: SelectPosition,
+    /// The preferred side of the trigger to render against (`Popper` only)
+    #[props(default = Side::Bottom)]
+    side: Side,
+    /// The distance in pixels from the trigger (`Popper` only)
+    #[props(default = 4)]
+    side_offset: i32,
+    /// The preferred alignment against the trigger (`Popper` only)
+    #[props(default = Align::Start)]
+    align: Align,
+    /// An offset in pixels from the "start" or "end" alignment options (`Popper` only)
+    #[props(default = 0)]
+    align_offset: i32,
+    /// Flip and shift the content to keep it inside the viewport (`Popper` only)
+    #[props(default = true)]
+    avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in
+    /// (`Popper` only)
+    #[props(default = 0)]
+    collision_padding: i32,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Called when Escape is pressed while the list is open. Call `prevent_default` to keep
+    /// it open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called when a pointer is pressed outside the list. Call `prevent_default` to keep it
+    /// open.
+    on_pointer_down_outside: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Called before focus returns to the trigger when the list closes. Call
+    /// `prevent_default` to leave focus alone.
+    on_close_auto_focus: Option<EventHandler<Event<()>>>,
+    children: Element,
+) -> Element {
+    let select = use_context::<SelectContext>();
+    let is_open = select.is_open();
+    let layer = select.layer;
+    let popper = position == SelectPosition::Popper;
+
+    let mut floating = use_floating(
+        select.trigger,
+        FloatingOptions {
+            side,
+            align,
+            side_offset: side_offset as f64,
+            align_offset: align_offset as f64,
+            avoid_collisions,
+            collision_padding: collision_padding as f64,
+            ..FloatingOptions::default()
+        },
+    );
+    use_auto_update(floating, is_open && popper);
+    let mut aligned = use_signal(|| None::<ItemAlignedPosition>);
+
+    let viewport = SelectViewportContext {
+        viewport: use_hook(|| CopyValue::new(None)),
+        can_scroll_up: use_signal(|| false),
+        can_scroll_down: use_signal(|| false),
+    };
+    use_context_provider(|| viewport);
+
+    // Stays mounted after closing until its exit animation finishes
+    let presence = use_presence(is_open, select.content_id.clone());
+
+    use_scroll_lock(is_open);
+
+    // An item-aligned list would no longer line up with the trigger
+    let resize_select = select.clone();
+    use_event_listener(
+        ListenerTarget::Window,
+        "resize",
+        ListenerOptions::default(),
+        is_open && !popper,
+        move |_| resize_select.set_open(false),
+    );
+
+    let dismiss_select = select.clone();
+    use_dismissable_layer(
+        layer,
+        select.content_id.clone(),
+        DismissableLayerOptions {
+            escape: true,
+            outside: true,
+            inside_ids: vec![select.trigger_id.clone()],
+            on_escape_key_down,
+            on_pointer_down_outside,
+            on_focus_outside: None,
+            on_interact_outside: None,
+        },
+        move |_| dismiss_select.set_open(false),
+    );
+
+    // Scroll the list so the aligned item lines up, once the position is applied
+    use_effect(move || {
+        if let Some(position) = aligned() {
+            spawn(async move { viewport.scroll(position.scroll_top, true).await });
+        }
+    });
+
+    if !presence.is_present() {
+        // Closed, the items still render, hidden, so the trigger can show the selected
+        // item's text and type ahead through them, and the form gets their options
+        return rsx! {
+            div {
+                hidden: true,
+                style: "display: none;",
+                {children}
+            }
+        };
+    }
+
+    let z_index = layer.z_index();
+    let pointer_events = if is_open { "auto" } else { "none" };
+    let state = if is_open { "open" } else { "closed" };
+
+    let wrapper_style = if popper {
+        floating.style()
+    } else {
+        match aligned() {
+            Some(pos) => format!(
+                "position: fixed; left: {}px; top: {}px; min-width: {}px;",
+                pos.x, pos.y, pos.min_width
+            ),
+            None => "position: fixed; left: 0; top: 0; visibility: hidden;".to_string(),
+        }
+    };
+    let max_height = match aligned() {
+        Some(pos) if !popper && pos.max_height.is_finite() => {
+            format!(" max-height: {}px;", pos.max_height)
+        }
+        _ => String::new(),
+    };
+
+    let align_select = select.clone();
+    let onmounted_wrapper = move |event: MountedEvent| {
+        let content = event.data();
+        if popper {
+            floating.set_content(content);
+            return;
+        }
+        let select = align_select.clone();
+        spawn(async move {
+            let Some(trigger) = select.trigger.peek().clone() else {
+                return;
+            };
+            let (Some(trigger_rect), Ok(content_rect)) =
+                (trigger.rect().await, content.get_client_rect().await)
+            else {
+                return;
+            };
+            let content_rect = Rect::from(content_rect);
+            let value_node = select.value_node.cloned();
+            let value_rect = match value_node {
+                Some(node) => node.get_client_rect().await.ok().map(Rect::from),
+                None => None,
+            }
+            .unwrap_or(trigger_rect);
+            let item = select.current_item();
+            let item_rect = match item.as_ref().and_then(|item| item.node.clone()) {
+                Some(node) => node.get_client_rect().await.ok().map(Rect::from),
+                None => None,
+            }
+            .unwrap_or(Rect::new(content_rect.x, content_rect.y, 0.0, 0.0));
+            let text_rect = match item.and_then(|item| item.text_node) {
+                Some(node) => node.get_client_rect().await.ok().map(Rect::from),
+                None => None,
+            }
+            .unwrap_or(item_rect);
+
+            aligned.set(Some(item_aligned_position(
+                trigger_rect,
+                value_rect,
+                content_rect,
+                item_rect,
+                text_rect,
+                viewport_rect().await,
+            )));
+        });
+    };
+
+    // Start on the selected item, or the first one
+    let mount_select = select.clone();
+    let on_mount_auto_focus = move |event: Event<()>| {
+        event.prevent_default();
+        if !is_open {
+            return;
+        }
+        match mount_select.current_item() {
+            Some(item) => platform::focus(&item.id),
+            None => platform::focus(&mount_select.content_id),
+        }
+    };
+
+    let trigger_id = select.trigger_id.clone();
+    let on_unmount_auto_focus = move |event: Event<()>| {
+        if let Some(handler) = &on_close_auto_focus {
+            handler.call(event.clone());
+        }
+        if event.default_action_enabled() {
+            event.prevent_default();
+            platform::focus(&trigger_id);
+        }
+    };
+
+    let keyboard_select = select.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let select = &keyboard_select;
+        let items = select.enabled_items();
+        let current = select
+            .highlighted
+            .peek()
+            .as_ref()
+            .and_then(|id| items.iter().position(|item| &item.id == id));
+
+        let target = match event.key() {
+            Key::ArrowDown => match current {
+                None => Some(0),
+                Some(index) => Some((index + 1).min(items.len().saturating_sub(1))),
+            },
+            Key::ArrowUp => match current {
+                None => items.len().checked_sub(1),
+                Some(index) => Some(index.saturating_sub(1)),
+            },
+            Key::Home => Some(0),
+            Key::End => items.len().checked_sub(1),
+            // The list isn't part of the tab order; Tab neither leaves nor moves through it
+            Key::Tab => {
+                event.prevent_default();
+                return;
+            }
+            Key::Character(character) => {
+                let modifiers = event.modifiers();
+                if character.chars().count() != 1
+                    || modifiers.ctrl()
+                    || modifiers.alt()
+                    || modifiers.meta()
+                {
+                    return;
+                }
+                let search = select.typeahead.push(&character);
+                let texts: Vec<&str> = items.iter().map(|item| item.text()).collect();
+                typeahead_match(&texts, current, &search)
+            }
+            _ => return,
+        };
+
+        event.prevent_default();
+        if let Some(item) = target.and_then(|index| items.get(index)) {
+            platform::focus(&item.id);
+        }
+    };
+
+    let class_name = utils::cn(vec![
+        Some("select-content bg-popover text-popover-foreground relative flex max-h-96 min-w-[8rem] flex-col overflow-hidden rounded-md border shadow-md outline-none"),
+        Some(class.as_str()),
+    ]);
+
+    // Caller attributes replace the defaults of the same name; caller listeners run first
+    let mut content_attributes = vec![
+        Attribute::new("role", "listbox", None, false),
+        Attribute::new("id", select.content_id.clone(), None, false),
+        Attribute::new("aria-labelledby", select.trigger_id.clone(), None, false),
+        Attribute::new("data-state", state, None, false),
+        Attribute::new("class", class_name, None, false),
+        Attribute::new("style", max_height, None, false),
+        dioxus::html::events::onkeydown(onkeydown),
+    ];
+    if popper {
+        let placed = floating.position();
+        let placed_side = placed.map(|pos| pos.side).unwrap_or(side);
+        let placed_align = placed.map(|pos| pos.align).unwrap_or(align);
+        content_attributes.extend([
+            Attribute::new("data-side", placed_side.as_str(), None, false),
+            Attribute::new("data-align", placed_align.as_str(), None, false),
+        ]);
+    }
+    let content_attributes = merge_attributes(content_attributes, attributes);
+
+    let mut viewport_node = viewport.viewport;
+
+    rsx! {
+        Portal {
+            container,
+            // The page behind can't be clicked; pressing it closes the list
+            div {
+                "data-select-backdrop": "",
+                "data-state": state,
+                style: "position: fixed; inset: 0; z-index: {z_index}; pointer-events: {pointer_events};",
+            }
+            div {
+                "data-select-content-wrapper": "",
+                style: "{wrapper_style} z-index: {z_index + 1};",
+                onmounted: onmounted_wrapper,
+                FocusScope {
+                    on_mount_auto_focus,
+                    on_unmount_auto_focus,
+                    attributes: content_attributes,
+                    div {
+                        "data-select-viewport": "",
+                        role: "presentation",
+                        class: "p-1",
+                        style: "position: relative; flex: 1; min-height: 0; overflow-y: auto;",
+                        onmounted: move |event: MountedEvent| {
+                            viewport_node.set(Some(event.data()));
+                            viewport.update();
+                        },
+                        onscroll: move |_| viewport.update(),
+                        {children}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The item around a [`SelectItemText`] or [`SelectItemIndicator`].
+#[derive(Clone)]
+struct SelectItemContext {
+    id: String,
+    selected: Memo<bool>,
+}
+
+#[component]
+pub fn SelectItem(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The value the item stands for, which is submitted with the form
+    #[props(into)]
+    value: String,
+    /// Skip the item in keyboard navigation and ignore selecting it
+    #[props(default = false)]
+    disabled: bool,
+    /// The text shown on the trigger and matched by typeahead (default: the item's text)
+    #[props(into)]
+    text_value: Option<String>,
+    children: Element,
+) -> Element {
+    let select = use_context::<SelectContext>();
+    let id = use_id("select-item");
+    let own_text = element_text(&children);
+
+    use_collection_item(
+        &select.items,
+        ItemEntry {
+            id: id.clone(),
+            value: value.clone(),
+            disabled,
+            text_value: text_value.clone(),
+            item_text: String::new(),
+            own_text: own_text.clone(),
+            node: None,
+            text_node: None,
+        },
+    );
+    let changed_items = select.items.clone();
+    use_effect(use_reactive!(|value, disabled, text_value, own_text| {
+        let _ = (value, disabled, text_value, own_text);
+        changed_items.changed();
+    }));
+
+    let selected = use_memo(use_reactive!(|value| select.value.get() == value));
+    let item_id = id.clone();
+    use_context_provider(|| SelectItemContext {
+        id: item_id,
+        selected,
+    });
+
+    let mut highlighted = select.highlighted;
+    let is_highlighted = highlighted.read().as_deref() == Some(id.as_str());
+    let typeahead = select.typeahead;
+
+    let click_select = select.clone();
+    let click_value = value.clone();
+    let onclick = move |_| {
+        if !disabled {
+            click_select.select(click_value.clone());
+        }
+    };
+
+    let key_select = select.clone();
+    let key_value = value.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let key = event.key();
+        // While typing ahead, Space is part of the search
+        let is_space = key == Key::Character(" ".to_string()) && !typeahead.is_searching();
+        if key == Key::Enter || is_space {
+            event.prevent_default();
+            event.stop_propagation();
+            if !disabled {
+                key_select.select(key_value.clone());
+            }
+        }
+    };
+
+    let hovered_id = id.clone();
+    let content_id = select.content_id.clone();
+    let onpointerenter = move |event: PointerEvent| {
+        if event.pointer_type() == "touch" {
+            return;
+        }
+        if disabled {
+            platform::focus(&content_id);
+        } else {
+            platform::focus(&hovered_id);
+        }
+    };
+    let left_content_id = select.content_id.clone();
+    let onpointerleave = move |event: PointerEvent| {
+        if event.pointer_type() != "touch" {
+            platform::focus(&left_content_id);
+        }
+    };
+
+    let focused_id = id.clone();
+    let blurred_id = id.clone();
+    let mounted_select = select.clone();
+    let mounted_id = id.clone();
+
+    let class_name = utils::cn(vec![
+        Some("relative flex w-full cursor-default items-center gap-2 rounded-sm py-1.5 pr-8 pl-2 text-sm outline-none select-none data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            role: "option",
+            id: "{id}",
+            tabindex: if disabled { None } else { Some("-1") },
+            class: "{class_name}",
+            "aria-selected": if selected() { "true" } else { "false" },
+            "aria-disabled": if disabled { Some("true") } else { None },
+            "data-state": if selected() { "checked" } else { "unchecked" },
+            "data-highlighted": if is_highlighted { Some("") } else { None },
+            "data-disabled": if disabled { Some("") } else { None },
+            onmounted: move |event: MountedEvent| {
+                mounted_select.items.update(&mounted_id, |item| item.node = Some(event.data()));
+            },
+            onclick,
+            onkeydown,
+            onpointerenter,
+            onpointerleave,
+            onfocus: move |_| highlighted.set(Some(focused_id.clone())),
+            onblur: move |_| {
+                if highlighted.peek().as_deref() == Some(blurred_id.as_str()) {
+                    highlighted.set(None);
+                }
+            },
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItemText
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The part of an item shown on the trigger once it is selected, and lined up with the
+/// trigger's value when the list opens.
+#[component]
+pub fn SelectItemText(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let select = use_context::<SelectContext>();
+    let item = use_context::<SelectItemContext>();
+    let text = element_text(&children);
+
+    select
+        .items
+        .update(&item.id, |entry| entry.item_text = text.clone());
+    let changed_items = select.items.clone();
+    use_effect(use_reactive!(|text| {
+        let _ = text;
+        changed_items.changed();
+    }));
+
+    let item_id = item.id.clone();
+
+    rsx! {
+        span {
+            onmounted: move |event: MountedEvent| {
+                select.items.update(&item_id, |entry| entry.text_node = Some(event.data()));
+            },
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItemIndicator
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Renders its children only while the surrounding item is selected, e.g. a check mark.
+#[component]
+pub fn SelectItemIndicator(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let item = use_context::<SelectItemContext>();
+    if !(item.selected)() {
+        return rsx! {};
+    }
+
+    let class_name = utils::cn(vec![
+        Some("pointer-events-none absolute right-2 flex size-3.5 items-center justify-center"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        span {
+            class: "{class_name}",
+            "aria-hidden": "true",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The group around a [`SelectLabel`], which the label names.
+#[derive(Clone)]
+struct SelectGroupContext {
+    label_id: String,
+}
+
+#[component]
+pub fn SelectGroup(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    let label_id = use_id("select-label");
+    use_context_provider(|| SelectGroupContext {
+        label_id: label_id.clone(),
+    });
+
+    rsx! {
+        div {
+            role: "group",
+            "aria-labelledby": "{label_id}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectLabel
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A heading for a group of items. It can't be focused or selected.
+#[component]
+pub fn SelectLabel(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let group = try_use_context::<SelectGroupContext>();
+    let class_name = utils::cn(vec![
+        Some("text-muted-foreground px-2 py-1.5 text-xs"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            id: group.map(|group| group.label_id),
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SelectSeparator(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("bg-border pointer-events-none -mx-1 my-1 h-px"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            "aria-hidden": "true",
+            class: "{class_name}",
+            ..attributes,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectScrollUpButton / SelectScrollDownButton
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Shown at the top of a list that is scrolled down; hovering it scrolls up.
+#[component]
+pub fn SelectScrollUpButton(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The icon (default: a chevron pointing up)
+    children: Element,
+) -> Element {
+    rsx! {
+        SelectScrollButton { up: true, class, attributes, {children} }
+    }
+}
+
+/// Shown at the bottom of a list with more items below; hovering it scrolls down.
+#[component]
+pub fn SelectScrollDownButton(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The icon (default: a chevron pointing down)
+    children: Element,
+) -> Element {
+    rsx! {
+        SelectScrollButton { up: false, class, attributes, {children} }
+    }
+}
+
+#[component]
+fn SelectScrollButton(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    class: String,
+    up: bool,
+    children: Element,
+) -> Element {
+    let viewport = use_context::<SelectViewportContext>();
+    let mut scrolling = use_hook(|| CopyValue::new(None::<Task>));
+    let visible = if up {
+        (viewport.can_scroll_up)()
+    } else {
+        (viewport.can_scroll_down)()
+    };
+
+    let mut stop = move || {
+        if let Some(task) = scrolling.take() {
+            task.cancel();
+        }
+    };
+    // A button that hides or unmounts gets no pointerleave, so stop scrolling then too
+    use_effect(use_reactive!(|visible| {
+        if !visible {
+            stop();
+        }
+    }));
+    use_drop(stop);
+
+    if !visible {
+        return rsx! {};
+    }
+    let onpointerenter = move |_| {
+        if scrolling.peek().is_some() {
+            return;
+        }
+        let delta = if up { -SCROLL_STEP_PX } else { SCROLL_STEP_PX };
+        scrolling.set(Some(spawn(async move {
+            loop {
+                viewport.scroll(delta, false).await;
+                platform::sleep(SCROLL_INTERVAL_MS).await;
+            }
+        })));
+    };
+
+    // Sticks to its edge of the scrolling list, over the items rather than pushing them
+    let style = if up {
+        "position: sticky; top: -4px; z-index: 1; margin-bottom: -24px; height: 24px;"
+    } else {
+        "position: sticky; bottom: -4px; z-index: 1; margin-top: -24px; height: 24px;"
+    };
+    let class_name = utils::cn(vec![
+        Some("bg-popover flex cursor-default items-center justify-center"),
+        Some(class.as_str()),
+    ]);
+    let path = if up { "m18 15-6-6-6 6" } else { "m6 9 6 6 6-6" };
+
+    rsx! {
+        div {
+            "aria-hidden": "true",
+            class: "{class_name}",
+            style,
+            onpointerenter,
+            onpointerleave: move |_| stop(),
+            ..attributes,
+            if children.is_ok() && children != VNode::empty() {
+                {children}
+            } else {
+                svg {
+                    class: "size-4",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    width: "24",
+                    height: "24",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    path { d: path }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    #[test]
+    fn lines_the_item_text_up_with_the_value() {
+        let trigger = Rect::new(100.0, 200.0, 180.0, 36.0);
+        let value = Rect::new(116.0, 208.0, 80.0, 20.0);
+        // Measured wherever the content was; only the offsets inside it matter
+        let content = Rect::new(0.0, 0.0, 150.0, 120.0);
+        let item = Rect::new(4.0, 36.0, 142.0, 32.0);
+        let item_text = Rect::new(12.0, 42.0, 60.0, 20.0);
+
+        let pos = item_aligned_position(trigger, value, content, item, item_text, VIEWPORT);
+        assert_eq!((pos.x, pos.y), (104.0, 166.0));
+        // Wide enough to reach the trigger's right edge
+        assert_eq!(pos.min_width, 176.0);
+        assert_eq!(pos.scroll_top, 0.0);
+        assert_eq!(pos.max_height, 580.0);
+    }
+
+    #[test]
+    fn stays_inside_the_viewport() {
+        let content = Rect::new(0.0, 0.0, 150.0, 120.0);
+        let item = Rect::new(4.0, 4.0, 142.0, 32.0);
+
+        // Near the top left corner, the content moves right and down
+        let trigger = Rect::new(0.0, 0.0, 100.0, 36.0);
+        let pos = item_aligned_position(trigger, trigger, content, item, item, VIEWPORT);
+        assert_eq!((pos.x, pos.y), (10.0, 10.0));
+
+        // Near the bottom right corner, it moves left and up
+        let trigger = Rect::new(760.0, 580.0, 40.0, 20.0);
+        let pos = item_aligned_position(trigger, trigger, content, item, item, VIEWPORT);
+        assert_eq!((pos.x, pos.y), (640.0, 470.0));
+    }
+
+    #[test]
+    fn scrolls_a_long_list_to_keep_the_item_lined_up() {
+        let trigger = Rect::new(100.0, 50.0, 180.0, 36.0);
+        // The 40th of 50 items, far below the trigger
+        let content = Rect::new(0.0, 0.0, 200.0, 384.0);
+        let item = Rect::new(4.0, 1252.0, 192.0, 32.0);
+
+        let pos = item_aligned_position(trigger, trigger, content, item, item, VIEWPORT);
+        assert_eq!(pos.y, 10.0);
+        // The item's middle (1268px down the list) ends up on the trigger's middle (68px)
+        assert_eq!(pos.scroll_top, 1210.0);
+    }
+}
@@ -0,0 +1,386 @@
+//! # Sheet Component
+//!
+//! A dialog anchored to one edge of the screen, for navigation drawers and detail side
+//! panels. It shares [`Dialog`]'s state, focus trap, layering and Escape handling, and
+//! slides in and out with CSS animations keyed on `data-state` and `data-side`.
+//!
+//! On touch screens the sheet can be swiped back towards its edge to dismiss it. This is
+//! on by default with the `mobile` feature and can be toggled with `swipe_to_dismiss`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Navigation() -> Element {
+//!     rsx! {
+//!         Sheet {
+//!             SheetTrigger { "Menu" }
+//!             SheetContent {
+//!                 side: SheetSide::Left,
+//!                 size: "18rem",
+//!                 SheetHeader {
+//!                     SheetTitle { "Navigation" }
+//!                     SheetDescription { "Jump to a section" }
+//!                 }
+//!                 nav { a { href: "/", "Home" } }
+//!                 SheetFooter {
+//!                     SheetClose { "Close" }
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::dialog::{
+    Dialog, DialogClose, DialogContent, DialogContext, DialogDescription, DialogTitle,
+    DialogTrigger,
+};
+use crate::components::portal::PortalContainer;
+use crate::platform::ListenerEvent;
+use crate::utils;
+use dioxus::html::events::{onpointercancel, onpointerdown, onpointermove, onpointerup};
+use dioxus::prelude::*;
+
+const SHEET_CSS: &str = include_str!("./sheet.css");
+
+/// How far, in CSS pixels, a touch has to drag the sheet towards its edge to dismiss it.
+const SWIPE_DISMISS_THRESHOLD: f64 = 80.0;
+
+/// The edge of the screen a sheet is attached to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SheetSide {
+    Top,
+    #[default]
+    Right,
+    Bottom,
+    Left,
+}
+
+impl SheetSide {
+    fn as_str(self) -> &'static str {
+        match self {
+            SheetSide::Top => "top",
+            SheetSide::Right => "right",
+            SheetSide::Bottom => "bottom",
+            SheetSide::Left => "left",
+        }
+    }
+
+    fn to_class(self) -> &'static str {
+        match self {
+            SheetSide::Top => "inset-x-0 top-0 h-auto border-b",
+            SheetSide::Right => "inset-y-0 right-0 h-full w-3/4 border-l sm:max-w-sm",
+            SheetSide::Bottom => "inset-x-0 bottom-0 h-auto border-t",
+            SheetSide::Left => "inset-y-0 left-0 h-full w-3/4 border-r sm:max-w-sm",
+        }
+    }
+
+    /// The CSS property `size` applies to: the sheet's extent away from its edge.
+    fn size_property(self) -> &'static str {
+        match self {
+            SheetSide::Top | SheetSide::Bottom => "height",
+            SheetSide::Right | SheetSide::Left => "width",
+        }
+    }
+
+    /// The `touch-action` that leaves drags towards this edge to the sheet, while the
+    /// browser still pans along it.
+    fn touch_action(self) -> &'static str {
+        match self {
+            SheetSide::Top | SheetSide::Bottom => "pan-x",
+            SheetSide::Right | SheetSide::Left => "pan-y",
+        }
+    }
+
+    /// How far a pointer moved from `start` to `end` towards this edge, in CSS pixels.
+    fn distance_towards(self, start: (f64, f64), end: (f64, f64)) -> f64 {
+        match self {
+            SheetSide::Top => start.1 - end.1,
+            SheetSide::Right => end.0 - start.0,
+            SheetSide::Bottom => end.1 - start.1,
+            SheetSide::Left => start.0 - end.0,
+        }
+    }
+
+    /// The transform that moves the sheet `distance` pixels towards this edge.
+    fn translate(self, distance: f64) -> String {
+        match self {
+            SheetSide::Top => format!("translateY(-{distance}px)"),
+            SheetSide::Right => format!("translateX({distance}px)"),
+            SheetSide::Bottom => format!("translateY({distance}px)"),
+            SheetSide::Left => format!("translateX(-{distance}px)"),
+        }
+    }
+}
+
+/// A touch drag in progress.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Swipe {
+    start: (f64, f64),
+    /// How far the sheet has been dragged towards its edge, never negative.
+    distance: f64,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Sheet (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn Sheet(
+    /// Controlled open state
+    open: Option<bool>,
+    /// Whether the sheet is open by default (uncontrolled)
+    #[props(default = false)]
+    default_open: bool,
+    /// Callback when open state changes
+    on_open_change: Option<EventHandler<bool>>,
+    /// Whether the sheet is modal (blocks interaction with content behind it)
+    #[props(default = true)]
+    modal: bool,
+    children: Element,
+) -> Element {
+    rsx! {
+        style { {SHEET_CSS} }
+        Dialog {
+            open,
+            default_open,
+            on_open_change,
+            modal,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    rsx! {
+        DialogTrigger { attributes, {children} }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The edge of the screen the sheet slides in from
+    #[props(default)]
+    side: SheetSide,
+    /// Width of left/right sheets or height of top/bottom sheets, as a CSS length. Defaults
+    /// to three quarters of the screen (capped on wide screens) for left/right sheets and
+    /// to the content's height for top/bottom sheets.
+    #[props(into)]
+    size: Option<String>,
+    /// Where the content is portaled: a CSS selector or element (default: the nearest
+    /// `PortalProvider`'s container, or the app root)
+    #[props(into)]
+    container: Option<PortalContainer>,
+    /// Whether clicking outside should close the sheet
+    #[props(default = true)]
+    close_on_outside_click: bool,
+    /// Whether pressing Escape should close the sheet
+    #[props(default = true)]
+    close_on_escape: bool,
+    /// Called when Escape is pressed while the sheet is open. Call `prevent_default` on the
+    /// event to keep the sheet open.
+    on_escape_key_down: Option<EventHandler<Event<ListenerEvent>>>,
+    /// Whether a touch can swipe the sheet towards its edge to dismiss it (default: on
+    /// with the `mobile` feature)
+    #[props(default = cfg!(feature = "mobile"))]
+    swipe_to_dismiss: bool,
+    children: Element,
+) -> Element {
+    let dialog = use_context::<DialogContext>();
+    let mut swipe = use_signal(|| None::<Swipe>);
+
+    let class_name = utils::cn(vec![
+        Some("sheet-content bg-background flex flex-col gap-4 shadow-lg"),
+        Some(side.to_class()),
+        Some(class.as_str()),
+    ]);
+
+    let mut style = String::new();
+    if let Some(size) = &size {
+        // An explicit width replaces the default cap on wide screens
+        style.push_str(&format!("{}: {size}; max-{0}: none;", side.size_property()));
+    }
+    if swipe_to_dismiss {
+        // Otherwise the browser takes touch drags for scrolling and cancels the swipe
+        style.push_str(&format!(" touch-action: {};", side.touch_action()));
+    }
+    if let Some(Swipe { distance, .. }) = swipe() {
+        style.push_str(&format!(" transform: {};", side.translate(distance)));
+    }
+
+    let mut content_attributes = vec![Attribute::new("data-side", side.as_str(), None, false)];
+    if !style.is_empty() {
+        content_attributes.push(Attribute::new("style", style, None, false));
+    }
+    if swipe().is_some() {
+        content_attributes.push(Attribute::new("data-swiping", "", None, false));
+    }
+    if swipe_to_dismiss {
+        content_attributes.extend([
+            onpointerdown(move |event: PointerEvent| {
+                if event.pointer_type() != "touch" {
+                    return;
+                }
+                let point = event.client_coordinates();
+                swipe.set(Some(Swipe {
+                    start: (point.x, point.y),
+                    distance: 0.0,
+                }));
+            }),
+            onpointermove(move |event: PointerEvent| {
+                let Some(current) = swipe() else {
+                    return;
+                };
+                let point = event.client_coordinates();
+                let distance = side.distance_towards(current.start, (point.x, point.y));
+                swipe.set(Some(Swipe {
+                    distance: distance.max(0.0),
+                    ..current
+                }));
+            }),
+            onpointerup(move |_| {
+                let dismissed = swipe().is_some_and(|s| s.distance >= SWIPE_DISMISS_THRESHOLD);
+                swipe.set(None);
+                if dismissed {
+                    dialog.set_open(false);
+                }
+            }),
+            onpointercancel(move |_| swipe.set(None)),
+        ]);
+    }
+    content_attributes.extend(attributes);
+
+    rsx! {
+        DialogContent {
+            class: class_name,
+            container,
+            close_on_outside_click,
+            close_on_escape,
+            on_escape_key_down,
+            attributes: content_attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetHeader
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetHeader(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("flex flex-col gap-1.5 p-4"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            "data-slot": "sheet-header",
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetFooter
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetFooter(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("mt-auto flex flex-col gap-2 p-4"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            "data-slot": "sheet-footer",
+            class: "{class_name}",
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetTitle
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetTitle(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("text-foreground font-semibold"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        DialogTitle { class: class_name, attributes, {children} }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetDescription
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetDescription(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    children: Element,
+) -> Element {
+    let class_name = utils::cn(vec![
+        Some("text-muted-foreground text-sm"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        DialogDescription { class: class_name, attributes, {children} }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SheetClose
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn SheetClose(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    children: Element,
+) -> Element {
+    rsx! {
+        DialogClose { attributes, {children} }
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use crate::components::spinner::*;
+//! use dioxus_components::*;
 //!
 //! #[component]
 //! fn App() -> Element {
@@ -34,7 +34,7 @@ pub enum SpinnerSize {
 }
 
 impl SpinnerSize {
-    fn to_class(&self) -> &'static str {
+    fn to_class(self) -> &'static str {
         match self {
             SpinnerSize::Small => "size-4",
             SpinnerSize::Medium => "size-6",
@@ -0,0 +1,401 @@
+//! # Tabs Component
+//!
+//! Layered sections of content, known as tab panels, displayed one at a time. It follows
+//! the WAI-ARIA tabs pattern:
+//!
+//! - the triggers form one tab stop: Tab moves into the list on the selected trigger, and
+//!   the arrow keys along the `orientation` (plus Home/End) move between triggers,
+//!   skipping disabled ones;
+//! - with the default `activation_mode`, moving focus to a trigger selects it; with
+//!   [`TabsActivationMode::Manual`] it takes Enter, Space or a click;
+//! - each trigger `aria-controls` its panel, and each panel is `aria-labelledby` its
+//!   trigger.
+//!
+//! Panels mount when their tab is selected and unmount when it isn't, after any exit
+//! animation. Use `force_mount` to keep a panel mounted, hidden, e.g. to preserve its
+//! state.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Settings() -> Element {
+//!     rsx! {
+//!         Tabs {
+//!             default_value: "account",
+//!             TabsList {
+//!                 TabsTrigger { value: "account", "Account" }
+//!                 TabsTrigger { value: "password", "Password" }
+//!                 TabsTrigger { value: "billing", disabled: true, "Billing" }
+//!             }
+//!             TabsContent { value: "account", "Make changes to your account here." }
+//!             TabsContent { value: "password", "Change your password here." }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::components::presence::use_presence;
+use crate::platform;
+use crate::utils::{
+    self, roving_index, use_collection, use_collection_item, use_controllable_state, use_id,
+    Collection, CollectionItem, ControllableState,
+};
+use dioxus::prelude::*;
+
+/// The direction the tab list runs in, which decides the arrow keys that move between
+/// triggers.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TabsOrientation {
+    /// ArrowLeft/ArrowRight move between triggers.
+    #[default]
+    Horizontal,
+    /// ArrowUp/ArrowDown move between triggers.
+    Vertical,
+}
+
+impl TabsOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TabsOrientation::Horizontal => "horizontal",
+            TabsOrientation::Vertical => "vertical",
+        }
+    }
+}
+
+/// Whether moving focus to a trigger selects its tab.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TabsActivationMode {
+    /// Focusing a trigger selects it.
+    #[default]
+    Automatic,
+    /// A trigger is selected by Enter, Space or a click. Use it when showing a panel is
+    /// slow, so arrowing past tabs doesn't load each one.
+    Manual,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Tabs state
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A trigger registered with its tabs, for keyboard navigation and the tab stop.
+#[derive(Clone)]
+struct TriggerEntry {
+    id: String,
+    value: String,
+    disabled: bool,
+}
+
+impl CollectionItem for TriggerEntry {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Clone)]
+struct TabsContext {
+    value: ControllableState<String>,
+    orientation: TabsOrientation,
+    activation_mode: TabsActivationMode,
+    /// The prefix of the trigger and panel ids.
+    base_id: String,
+    /// The triggers in document order.
+    triggers: Collection<TriggerEntry>,
+}
+
+impl TabsContext {
+    fn select(&self, value: &str) {
+        if self.value.peek() != value {
+            let mut state = self.value;
+            state.set(value.to_string());
+        }
+    }
+
+    /// An id fragment for `value`. Characters other than ASCII letters, digits and `-`
+    /// are escaped as `_<hex code point>_`, so different values never share an id.
+    fn id_part(value: &str) -> String {
+        let mut part = String::with_capacity(value.len());
+        for c in value.chars() {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                part.push(c);
+            } else {
+                part.push_str(&format!("_{:x}_", c as u32));
+            }
+        }
+        part
+    }
+
+    fn trigger_id(&self, value: &str) -> String {
+        format!("{}-trigger-{}", self.base_id, Self::id_part(value))
+    }
+
+    fn content_id(&self, value: &str) -> String {
+        format!("{}-content-{}", self.base_id, Self::id_part(value))
+    }
+
+    fn enabled_values(&self) -> Vec<String> {
+        self.triggers
+            .borrow()
+            .iter()
+            .filter(|trigger| !trigger.disabled)
+            .map(|trigger| trigger.value.clone())
+            .collect()
+    }
+
+    /// The trigger Tab moves to: the selected one, or the first enabled one when the
+    /// selected tab is missing or disabled.
+    fn tab_stop(&self) -> Option<String> {
+        let mounted = self.triggers.version() > 0;
+        let selected = self.value.get();
+        let triggers = self.triggers.borrow();
+        match triggers.iter().find(|trigger| trigger.value == selected) {
+            Some(trigger) if !trigger.disabled => return Some(selected),
+            // Until mounted, a selected trigger may simply not have rendered yet
+            None if !mounted && !selected.is_empty() => return Some(selected),
+            _ => {}
+        }
+        triggers
+            .iter()
+            .find(|trigger| !trigger.disabled)
+            .map(|trigger| trigger.value.clone())
+    }
+}
+
+/// Set by `TabsList` for its triggers.
+#[derive(Clone, Copy)]
+struct TabsListContext {
+    r#loop: bool,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Tabs (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn Tabs(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Controlled value: the `value` of the selected tab
+    #[props(into)]
+    value: Option<String>,
+    /// The tab selected by default (uncontrolled)
+    #[props(into, default = String::new())]
+    default_value: String,
+    /// Callback when the selected tab changes
+    on_value_change: Option<EventHandler<String>>,
+    /// The direction the tab list runs in
+    #[props(default)]
+    orientation: TabsOrientation,
+    /// Whether focusing a trigger selects its tab
+    #[props(default)]
+    activation_mode: TabsActivationMode,
+    children: Element,
+) -> Element {
+    let value = use_controllable_state(value, || default_value, on_value_change);
+    let base_id = use_id("tabs");
+    let triggers = use_collection();
+
+    use_context_provider(|| TabsContext {
+        value,
+        orientation,
+        activation_mode,
+        base_id,
+        triggers,
+    });
+
+    let class_name = utils::cn(vec![
+        Some("flex gap-2 data-[orientation=horizontal]:flex-col"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            "data-slot": "tabs",
+            class: "{class_name}",
+            "data-orientation": orientation.as_str(),
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TabsList
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The row (or column) of triggers.
+#[component]
+pub fn TabsList(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// Whether the arrow keys move from the last trigger to the first, and back
+    #[props(default = true)]
+    r#loop: bool,
+    children: Element,
+) -> Element {
+    let tabs = use_context::<TabsContext>();
+    use_context_provider(|| TabsListContext { r#loop });
+
+    let class_name = utils::cn(vec![
+        Some("bg-muted text-muted-foreground inline-flex h-9 w-fit items-center justify-center rounded-lg p-[3px] data-[orientation=vertical]:h-auto data-[orientation=vertical]:flex-col"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        div {
+            role: "tablist",
+            class: "{class_name}",
+            "aria-orientation": tabs.orientation.as_str(),
+            "data-orientation": tabs.orientation.as_str(),
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TabsTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The button that selects a tab. Only the selected trigger is in the tab order.
+#[component]
+pub fn TabsTrigger(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The value of the tab, matching its `TabsContent`
+    #[props(into)]
+    value: String,
+    /// Skip the trigger in keyboard navigation and ignore selecting it
+    #[props(default = false)]
+    disabled: bool,
+    children: Element,
+) -> Element {
+    let tabs = use_context::<TabsContext>();
+    let wrap = try_use_context::<TabsListContext>().is_none_or(|list| list.r#loop);
+
+    use_collection_item(
+        &tabs.triggers,
+        TriggerEntry {
+            id: tabs.trigger_id(&value),
+            value: value.clone(),
+            disabled,
+        },
+    );
+    let changed_triggers = tabs.triggers.clone();
+    use_effect(use_reactive!(|value, disabled| {
+        let _ = (value, disabled);
+        changed_triggers.changed();
+    }));
+
+    let selected = tabs.value.get() == value;
+    let tab_stop = tabs.tab_stop().as_deref() == Some(value.as_str());
+    let orientation = tabs.orientation;
+
+    let click_tabs = tabs.clone();
+    let click_value = value.clone();
+    let onclick = move |_| {
+        if !disabled {
+            click_tabs.select(&click_value);
+        }
+    };
+
+    let focus_tabs = tabs.clone();
+    let focus_value = value.clone();
+    let onfocus = move |_| {
+        if !disabled && focus_tabs.activation_mode == TabsActivationMode::Automatic {
+            focus_tabs.select(&focus_value);
+        }
+    };
+
+    let key_tabs = tabs.clone();
+    let key_value = value.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let enabled = key_tabs.enabled_values();
+        let Some(current) = enabled.iter().position(|value| *value == key_value) else {
+            return;
+        };
+        let horizontal = orientation == TabsOrientation::Horizontal;
+        let key = event.key();
+        if let Some(next) =
+            roving_index(&key, current, enabled.len(), horizontal, !horizontal, wrap)
+        {
+            event.prevent_default();
+            platform::focus(&key_tabs.trigger_id(&enabled[next]));
+        }
+    };
+
+    let class_name = utils::cn(vec![
+        Some("data-[state=active]:bg-background dark:data-[state=active]:text-foreground focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:outline-ring dark:data-[state=active]:border-input dark:data-[state=active]:bg-input/30 text-foreground dark:text-muted-foreground inline-flex h-[calc(100%-1px)] flex-1 items-center justify-center gap-1.5 rounded-md border border-transparent px-2 py-1 text-sm font-medium whitespace-nowrap transition-[color,box-shadow] focus-visible:ring-[3px] focus-visible:outline-1 disabled:pointer-events-none disabled:opacity-50 data-[state=active]:shadow-sm data-[orientation=vertical]:w-full data-[orientation=vertical]:justify-start [&_svg]:pointer-events-none [&_svg]:shrink-0 [&_svg:not([class*='size-'])]:size-4"),
+        Some(class.as_str()),
+    ]);
+
+    rsx! {
+        button {
+            r#type: "button",
+            role: "tab",
+            id: tabs.trigger_id(&value),
+            class: "{class_name}",
+            disabled,
+            tabindex: if tab_stop { "0" } else { "-1" },
+            "aria-selected": if selected { "true" } else { "false" },
+            "aria-controls": tabs.content_id(&value),
+            "data-state": if selected { "active" } else { "inactive" },
+            "data-disabled": if disabled { Some("") } else { None },
+            "data-orientation": orientation.as_str(),
+            onclick,
+            onfocus,
+            onkeydown,
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TabsContent
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The panel shown while its tab is selected.
+#[component]
+pub fn TabsContent(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    #[props(default = String::new())] class: String,
+    /// The value of the tab, matching its `TabsTrigger`
+    #[props(into)]
+    value: String,
+    /// Keep the panel mounted, hidden, while another tab is selected
+    #[props(default = false)]
+    force_mount: bool,
+    children: Element,
+) -> Element {
+    let tabs = use_context::<TabsContext>();
+    let selected = tabs.value.get() == value;
+    let content_id = tabs.content_id(&value);
+
+    // Stays mounted after deselection until its exit animation finishes
+    let presence = use_presence(selected, content_id.clone());
+
+    if !force_mount && !presence.is_present() {
+        return rsx! {};
+    }
+
+    let class_name = utils::cn(vec![Some("flex-1 outline-none"), Some(class.as_str())]);
+
+    rsx! {
+        div {
+            role: "tabpanel",
+            id: content_id,
+            class: "{class_name}",
+            tabindex: "0",
+            hidden: !presence.is_present(),
+            "aria-labelledby": tabs.trigger_id(&value),
+            "data-state": if selected { "active" } else { "inactive" },
+            "data-orientation": tabs.orientation.as_str(),
+            ..attributes,
+            {children}
+        }
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use crate::components::tooltip::*;
+//! use dioxus_components::*;
 //!
 //! #[component]
 //! fn App() -> Element {
@@ -26,7 +26,14 @@
 //! }
 //! ```
 
-use crate::utils;
+use crate::components::portal::{Portal, PortalContainer};
+use crate::components::presence::use_presence;
+use crate::platform::{clear_timeout, set_timeout};
+use crate::utils::floating::{
+    use_auto_update, use_floating, Align, Anchor, FloatingArrow, FloatingOptions, Side,
+};
+use crate::utils::{self, use_id};
+use dioxus::core::Task;
 use dioxus::prelude::*;
 
 const TOOLTIP_CSS: &str = include_str!("./tooltip.css");
@@ -117,7 +124,9 @@ pub fn Tooltip(props: TooltipProps) -> Element {
 
     let open = use_signal(|| props.default_open);
     let was_open_delayed = use_signal(|| false);
-    let open_timer = use_signal(|| None::<i32>);
+    let open_timer = use_signal(|| None::<Task>);
+    let trigger_ref = use_signal(|| None::<Anchor>);
+    let content_id = use_id("tooltip-content");
 
     let delay_duration = props
         .delay_duration
@@ -132,6 +141,8 @@ pub fn Tooltip(props: TooltipProps) -> Element {
         disable_hoverable_content,
         was_open_delayed,
         open_timer,
+        trigger_ref,
+        content_id: CopyValue::new(content_id),
         is_open_delayed: provider_context.is_open_delayed,
         skip_delay_duration: provider_context.skip_delay_duration,
     });
@@ -153,7 +164,9 @@ struct TooltipContext {
     delay_duration: u64,
     disable_hoverable_content: bool,
     was_open_delayed: Signal<bool>,
-    open_timer: Signal<Option<i32>>,
+    open_timer: Signal<Option<Task>>,
+    trigger_ref: Signal<Option<Anchor>>,
+    content_id: CopyValue<String>,
     is_open_delayed: Signal<bool>,
     skip_delay_duration: u64,
 }
@@ -286,7 +299,11 @@ pub fn TooltipTrigger(props: TooltipTriggerProps) -> Element {
         span {
             class: "{class_name}",
             "data-state": "{context.get_state_attribute()}",
-            aria_describedby: if *context.open.read() { "tooltip-content" } else { "" },
+            aria_describedby: if *context.open.read() { Some(context.content_id.cloned()) } else { None },
+
+            onmounted: move |evt| {
+                context.trigger_ref.set(Some(evt.data().into()));
+            },
 
             onmouseenter: move |_| {
                 #[cfg(target_arch = "wasm32")]
@@ -344,26 +361,11 @@ pub fn TooltipTrigger(props: TooltipTriggerProps) -> Element {
  * TooltipContent
  * -----------------------------------------------------------------------------------------------*/
 
-#[derive(Clone, Copy, PartialEq)]
-#[allow(dead_code)]
-pub enum TooltipSide {
-    Top,
-    Right,
-    Bottom,
-    Left,
-}
+/// The preferred side of the trigger to render the content against.
+pub type TooltipSide = Side;
 
-impl TooltipSide {
-    #[allow(dead_code)]
-    fn as_str(&self) -> &'static str {
-        match self {
-            TooltipSide::Top => "top",
-            TooltipSide::Right => "right",
-            TooltipSide::Bottom => "bottom",
-            TooltipSide::Left => "left",
-        }
-    }
-}
+/// The preferred alignment of the content against the trigger.
+pub type TooltipAlign = Align;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct TooltipContentProps {
@@ -381,74 +383,138 @@ pub struct TooltipContentProps {
     #[props(default = 4)]
     pub side_offset: i32,
 
+    /// The alignment against the trigger. Collisions shift the content rather than change it.
+    #[props(default = TooltipAlign::Center)]
+    pub align: TooltipAlign,
+
+    /// An offset in pixels from the "start" or "end" alignment options.
+    #[props(default = 0)]
+    pub align_offset: i32,
+
+    /// When true, flips the side and shifts the content to prevent collisions with the viewport edges.
+    #[props(default = true)]
+    pub avoid_collisions: bool,
+
+    /// The distance in pixels from the viewport edges where collision detection should occur.
+    #[props(default = 0)]
+    pub collision_padding: i32,
+
+    /// The padding between the arrow and the edges of the content.
+    #[props(default = 0)]
+    pub arrow_padding: i32,
+
     /// A more descriptive label for accessibility purpose.
     #[props(optional)]
     pub aria_label: Option<String>,
+
+    /// When true, renders the content into a `Portal` so `overflow: hidden` ancestors can't clip it.
+    #[props(default = false)]
+    pub portal: bool,
+
+    /// Where the content is portaled (only used when `portal` is true). Defaults to the
+    /// nearest `PortalProvider`'s container, or the app root.
+    #[props(optional, into)]
+    pub container: Option<PortalContainer>,
+
+    /// Keep the content mounted while closed, e.g. to drive its animation from outside.
+    #[props(default = false)]
+    pub force_mount: bool,
 }
 
 #[component]
 pub fn TooltipContent(props: TooltipContentProps) -> Element {
     let context = use_context::<TooltipContext>();
 
+    let mut floating = use_floating(
+        context.trigger_ref,
+        FloatingOptions {
+            side: props.side,
+            align: props.align,
+            side_offset: props.side_offset as f64,
+            align_offset: props.align_offset as f64,
+            avoid_collisions: props.avoid_collisions,
+            collision_padding: props.collision_padding as f64,
+            arrow_padding: props.arrow_padding as f64,
+            ..FloatingOptions::default()
+        },
+    );
+    use_context_provider(|| floating);
+
+    // Follow the trigger while the page scrolls or resizes
+    let is_open = *context.open.read();
+    use_auto_update(floating, is_open);
+
+    // Stays mounted after closing until its exit animation finishes
+    let presence = use_presence(is_open, context.content_id.cloned());
+
     // Base styling inspired by Radix UI
-    let base_class = "tooltip-content absolute z-50 rounded px-[15px] py-[10px] text-[15px] leading-none select-none bg-white text-primary shadow-[hsl(206_22%_7%_/_35%)_0px_10px_38px_-10px,_hsl(206_22%_7%_/_20%)_0px_10px_20px_-15px] dark:bg-gray-800 dark:text-white";
+    let base_class = "tooltip-content z-50 rounded px-[15px] py-[10px] text-[15px] leading-none select-none bg-white text-primary shadow-[hsl(206_22%_7%_/_35%)_0px_10px_38px_-10px,_hsl(206_22%_7%_/_20%)_0px_10px_20px_-15px] dark:bg-gray-800 dark:text-white";
 
-    let position_class = match props.side {
-        TooltipSide::Top => "bottom-full left-1/2 -translate-x-1/2 mb-2",
-        TooltipSide::Right => "left-full top-1/2 -translate-y-1/2 ml-2",
-        TooltipSide::Bottom => "top-full left-1/2 -translate-x-1/2 mt-2",
-        TooltipSide::Left => "right-full top-1/2 -translate-y-1/2 mr-2",
-    };
+    let class_name = utils::cn(vec![Some(base_class), props.class.as_deref()]);
 
-    let class_name = utils::cn(vec![
-        Some(base_class),
-        Some(position_class),
-        props.class.as_deref(),
-    ]);
+    let position = floating.position();
+    let placed_side = position.map(|pos| pos.side).unwrap_or(props.side);
+    let placed_align = position.map(|pos| pos.align).unwrap_or(props.align);
 
-    // Close tooltip if trigger is scrolled or if Escape is pressed
-    // Note: This is a simplified version. Full implementation would need proper event listeners
+    if !props.force_mount && !presence.is_present() {
+        return rsx! {};
+    }
 
-    rsx! {
-        if *context.open.read() {
-            div {
-                id: "tooltip-content",
-                role: "tooltip",
-                class: "{class_name}",
-                "data-state": "{context.get_state_attribute()}",
-                "data-side": "{props.side.as_str()}",
-
-                onmouseenter: move |_| {
-                    #[cfg(target_arch = "wasm32")]
-                    {
-                        web_sys::console::log_1(&format!("Mouse entered content - state: {}, side: {}", context.get_state_attribute(), props.side.as_str()).into());
-                    }
-                    // Cancel any pending close timer when entering content
-                    let mut ctx = context;
-                    let timer_val = *ctx.open_timer.read();
-                    if let Some(timer_id) = timer_val {
-                        clear_timeout(timer_id);
-                        *ctx.open_timer.write() = None;
-                    }
-                },
+    let content = rsx! {
+        div {
+            id: context.content_id.cloned(),
+            role: "tooltip",
+            class: "{class_name}",
+            style: "{floating.style()}",
+            "data-state": "{context.get_state_attribute()}",
+            "data-side": "{placed_side.as_str()}",
+            "data-align": "{placed_align.as_str()}",
 
-                onmouseleave: move |_| {
-                    #[cfg(target_arch = "wasm32")]
-                    web_sys::console::log_1(&"Mouse left tooltip content".into());
-                    // Set a delay before closing to allow moving back to trigger
-                    let mut ctx = context;
-                    let close_timer_id = set_timeout(
-                        move || {
-                            ctx.handle_close();
-                        },
-                        300, // 300ms grace period to move back to trigger
-                    );
-                    *ctx.open_timer.write() = Some(close_timer_id);
-                },
+            onmounted: move |evt| {
+                floating.set_content(evt.data());
+            },
+            onmouseenter: move |_| {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    web_sys::console::log_1(&format!("Mouse entered content - state: {}, side: {}", context.get_state_attribute(), placed_side.as_str()).into());
+                }
+                // Cancel any pending close timer when entering content
+                let mut ctx = context;
+                let timer_val = *ctx.open_timer.read();
+                if let Some(timer_id) = timer_val {
+                    clear_timeout(timer_id);
+                    *ctx.open_timer.write() = None;
+                }
+            },
+
+            onmouseleave: move |_| {
+                #[cfg(target_arch = "wasm32")]
+                web_sys::console::log_1(&"Mouse left tooltip content".into());
+                // Set a delay before closing to allow moving back to trigger
+                let mut ctx = context;
+                let close_timer_id = set_timeout(
+                    move || {
+                        ctx.handle_close();
+                    },
+                    300, // 300ms grace period to move back to trigger
+                );
+                *ctx.open_timer.write() = Some(close_timer_id);
+            },
 
-                {props.children}
+            {props.children}
+        }
+    };
+
+    if props.portal {
+        rsx! {
+            Portal {
+                container: props.container.clone(),
+                style: "display: contents;",
+                {content}
             }
         }
+    } else {
+        content
     }
 }
 
@@ -473,56 +539,6 @@ pub fn TooltipArrow(props: TooltipArrowProps) -> Element {
     let class_name = utils::cn(vec![Some("fill-primary"), props.class.as_deref()]);
 
     rsx! {
-        svg {
-            width: "{props.width}",
-            height: "{props.height}",
-            view_box: "0 0 30 10",
-            preserve_aspect_ratio: "none",
-            class: "{class_name}",
-
-            polygon { points: "0,0 30,0 15,10" }
-        }
+        FloatingArrow { class: class_name, width: props.width, height: props.height }
     }
 }
-
-/* -------------------------------------------------------------------------------------------------
- * Helper functions for WASM environment
- * -----------------------------------------------------------------------------------------------*/
-
-#[cfg(target_arch = "wasm32")]
-fn set_timeout<F>(f: F, delay_ms: u64) -> i32
-where
-    F: FnOnce() + 'static,
-{
-    use wasm_bindgen::prelude::*;
-    use wasm_bindgen::JsCast;
-
-    let closure = Closure::once(f);
-    let window = web_sys::window().expect("no global window exists");
-    let id = window
-        .set_timeout_with_callback_and_timeout_and_arguments_0(
-            closure.as_ref().unchecked_ref(),
-            delay_ms as i32,
-        )
-        .expect("should set timeout");
-    closure.forget();
-    id
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn set_timeout<F>(_f: F, _delay_ms: u64) -> i32
-where
-    F: FnOnce() + 'static,
-{
-    0
-}
-
-#[cfg(target_arch = "wasm32")]
-fn clear_timeout(id: i32) {
-    if let Some(window) = web_sys::window() {
-        window.clear_timeout_with_handle(id);
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn clear_timeout(_id: i32) {}
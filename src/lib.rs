@@ -5,18 +5,31 @@
 //! A comprehensive collection of reusable Dioxus 0.7 components built with Tailwind CSS v4
 //! and following Radix UI design patterns.
 //!
-//! ## Components (11 Total)
+//! ## Components (24 Total)
 //!
 //! - **Accordion** - Vertically stacked interactive headings with reveal sections
+//! - **AlertDialog** - Confirmation dialogs that require an answer, with async actions
 //! - **Avatar** - User avatar display with image loading and fallback support
 //! - **Badge** - Flexible badge component with multiple style variants
 //! - **Button** - Versatile button with 6 variants and 6 size options
 //! - **Card** - Flexible card container with header, content, and footer sections
-//! - **Checkbox** - Three-state checkbox (checked/unchecked/indeterminate)
+//! - **Checkbox** - Three-state checkbox (checked/unchecked/indeterminate), with groups and select-all
+//! - **Combobox** - Searchable pickers with filtered or async-loaded options and multi-select badges
+//! - **Command** - Command palette with fuzzy-ranked results and a hotkey-bound dialog
+//! - **ContextMenu** - Right-click and long-press menus opened at the pointer
 //! - **Dialog** - Accessible modal dialogs with overlay, focus trap, and keyboard handling
+//! - **DismissableLayer** - Dismisses overlays on outside pointer presses, focus moves and Escape
+//! - **DropdownMenu** - Menus of actions with checkbox/radio items, submenus and typeahead
 //! - **Empty** - Empty state component for "no content" scenarios
+//! - **FocusScope** - Focus trapping, looping and restoration for overlays
+//! - **Popover** - Click-triggered floating panels for interactive content, modal or non-modal
 //! - **Portal** - Render content outside the parent DOM hierarchy (modals, overlays)
+//! - **Presence** - Keeps content mounted until its exit animation finishes
+//! - **RadioGroup** - Single-choice radios with arrow-key selection and one form input
+//! - **Select** - Styleable selects that submit through a hidden native `<select>`
+//! - **Sheet** - Dialogs that slide in from a screen edge, with swipe-to-dismiss on touch
 //! - **Spinner** - Loading indicator with multiple sizes and colors
+//! - **Tabs** - Tabbed panels with roving focus, automatic or manual activation and orientation
 //! - **Tooltip** - Hover-triggered tooltips with flexible positioning
 //!
 //! ## Quick Start
@@ -59,8 +72,24 @@
 //!
 //!
 //! For a complete example, see the `examples/` directory.
+//!
+//! ## Platforms
+//!
+//! Components render on the web (`web` feature), desktop and mobile (`desktop`/`mobile`),
+//! and under server-side rendering. Browser-only APIs are confined to the [`platform`]
+//! module, which uses `web_sys` on `wasm32` and `dioxus::document::eval` elsewhere.
+//!
+//! ## Testing
+//!
+//! The `testing` feature adds the [`testing`] module: a headless document for clicking,
+//! typing, and querying components by role or label from ordinary `cargo test` runs. On
+//! non-browser targets it also routes the [`platform`] layer to that document, so enable it
+//! only for tests.
 
 pub mod components;
+pub mod platform;
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub mod testing;
 pub mod utils;
 
 // Re-export commonly used items
@@ -69,27 +98,79 @@ pub use components::{
         Accordion, AccordionContent, AccordionItem, AccordionOrientation, AccordionTrigger,
         AccordionType,
     },
+    alert_dialog::{
+        AlertDialog, AlertDialogAction, AlertDialogActionFuture, AlertDialogCancel,
+        AlertDialogContent, AlertDialogDescription, AlertDialogTitle, AlertDialogTrigger,
+    },
     avatar::{Avatar, AvatarFallback, AvatarImage, ImageLoadingStatus},
     badge::{Badge, BadgeVariant},
-    button::{Button, ButtonSize, ButtonVariant},
+    button::{button_variants, Button, ButtonSize, ButtonVariant},
     card::{Card, CardAction, CardContent, CardDescription, CardFooter, CardHeader, CardTitle},
     checkbox::{
-        Checkbox, CheckboxBubbleInput, CheckboxContext, CheckboxIndicator, CheckboxLabel,
-        CheckboxProvider, CheckboxTrigger, CheckedState,
+        Checkbox, CheckboxBubbleInput, CheckboxContext, CheckboxGroup, CheckboxGroupContext,
+        CheckboxGroupSelectAll, CheckboxIndicator, CheckboxLabel, CheckboxProvider,
+        CheckboxTrigger, CheckedState,
+    },
+    combobox::{
+        Combobox, ComboboxContent, ComboboxEmpty, ComboboxInput, ComboboxLoadFuture, ComboboxOption,
+    },
+    command::{
+        Command, CommandDialog, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList,
+        CommandSeparator, CommandShortcut,
+    },
+    context_menu::{
+        ContextMenu, ContextMenuCheckboxItem, ContextMenuContent, ContextMenuGroup,
+        ContextMenuItem, ContextMenuItemIndicator, ContextMenuLabel, ContextMenuRadioGroup,
+        ContextMenuRadioItem, ContextMenuSeparator, ContextMenuShortcut, ContextMenuSub,
+        ContextMenuSubContent, ContextMenuSubTrigger, ContextMenuTrigger,
     },
     dialog::{
         Dialog, DialogClose, DialogContent, DialogDescription, DialogOverlay, DialogTitle,
         DialogTrigger,
     },
+    dismissable_layer::{
+        use_dismissable_layer, DismissReason, DismissableLayer, DismissableLayerOptions,
+    },
+    dropdown_menu::{
+        DropdownMenu, DropdownMenuAlign, DropdownMenuCheckboxItem, DropdownMenuContent,
+        DropdownMenuGroup, DropdownMenuItem, DropdownMenuItemIndicator, DropdownMenuLabel,
+        DropdownMenuRadioGroup, DropdownMenuRadioItem, DropdownMenuSeparator, DropdownMenuShortcut,
+        DropdownMenuSide, DropdownMenuSub, DropdownMenuSubContent, DropdownMenuSubTrigger,
+        DropdownMenuTrigger,
+    },
     empty::{
         Empty, EmptyContent, EmptyDescription, EmptyHeader, EmptyMedia, EmptyMediaVariant,
         EmptyTitle,
     },
-    portal::Portal,
+    focus_scope::FocusScope,
+    popover::{
+        Popover, PopoverAlign, PopoverAnchor, PopoverArrow, PopoverClose, PopoverContent,
+        PopoverSide, PopoverTrigger,
+    },
+    portal::{Portal, PortalContainer, PortalProvider},
+    presence::{use_presence, Presence, UsePresence},
+    radio_group::{
+        RadioGroup, RadioGroupBubbleInput, RadioGroupIndicator, RadioGroupItem,
+        RadioGroupOrientation,
+    },
+    select::{
+        Select, SelectContent, SelectGroup, SelectItem, SelectItemIndicator, SelectItemText,
+        SelectLabel, SelectPosition, SelectScrollDownButton, SelectScrollUpButton, SelectSeparator,
+        SelectTrigger, SelectValue,
+    },
+    sheet::{
+        Sheet, SheetClose, SheetContent, SheetDescription, SheetFooter, SheetHeader, SheetSide,
+        SheetTitle, SheetTrigger,
+    },
     spinner::{Spinner, SpinnerSize},
+    tabs::{Tabs, TabsActivationMode, TabsContent, TabsList, TabsOrientation, TabsTrigger},
     tooltip::{
-        Tooltip, TooltipArrow, TooltipContent, TooltipProvider, TooltipSide, TooltipTrigger,
+        Tooltip, TooltipAlign, TooltipArrow, TooltipContent, TooltipProvider, TooltipSide,
+        TooltipTrigger,
     },
 };
 
-pub use utils::cn;
+pub use utils::{
+    cn, use_controllable_state, use_id, use_layer, use_layer_escape, use_scroll_lock,
+    ControllableState, Layer,
+};
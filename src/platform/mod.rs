@@ -0,0 +1,622 @@
+//! # Platform Layer
+//!
+//! The few things components need from the host environment: timers, viewport queries,
+//! one-off scripts, moving portal content and document/window event listeners.
+//!
+//! In the browser (`wasm32`) these go straight through `web_sys`. Everywhere else
+//! (desktop, mobile, server-side rendering) they go through `dioxus::document::eval`,
+//! which quietly does nothing when there is no document to talk to. Components should
+//! use this module instead of reaching for `js_sys`/`web_sys` directly.
+//!
+//! With the `testing` feature, non-browser targets use the in-memory document of
+//! [`TestDom`](crate::testing::TestDom) instead, so only enable it for tests.
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "testing")))]
+mod native;
+#[cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
+pub(crate) mod test_host;
+#[cfg(not(target_arch = "wasm32"))]
+mod timer;
+#[cfg(target_arch = "wasm32")]
+mod web;
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "testing")))]
+use native as backend;
+#[cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
+use test_host as backend;
+#[cfg(target_arch = "wasm32")]
+use web as backend;
+
+use dioxus::core::{use_drop, SuperFrom, Task};
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/* -------------------------------------------------------------------------------------------------
+ * Timers
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Waits for `ms` milliseconds.
+pub async fn sleep(ms: u64) {
+    backend::sleep(ms).await;
+}
+
+/// Runs `f` after `ms` milliseconds. Cancel it with [`clear_timeout`].
+///
+/// The timer is owned by the calling component, so it is dropped if that component unmounts.
+pub fn set_timeout(f: impl FnOnce() + 'static, ms: u64) -> Task {
+    spawn(async move {
+        sleep(ms).await;
+        f();
+    })
+}
+
+/// Cancels a timer started with [`set_timeout`]. Does nothing if it already fired.
+pub fn clear_timeout(task: Task) {
+    task.cancel();
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DOM queries
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The size of the visible viewport in CSS pixels, if there is one.
+pub async fn viewport_size() -> Option<(f64, f64)> {
+    backend::viewport_size().await
+}
+
+/// The ids among `ids` that belong to elements in the document, in document order, or
+/// `None` when there is no document to ask.
+pub async fn document_order(ids: &[String]) -> Option<Vec<String>> {
+    backend::document_order(ids).await
+}
+
+/// Runs a snippet of JavaScript against the document, ignoring the result.
+pub fn run_script(js: &str) {
+    let _ = document::eval(js);
+}
+
+/// Dispatches bubbling `events` on the first element matching `selector`, so listeners
+/// outside Dioxus (plain form handlers, validation) see changes made by a component.
+pub fn dispatch_events(selector: &str, events: &[&'static str]) {
+    backend::dispatch_events(selector, events);
+}
+
+/// A CSS selector matching the element with `id`, whatever characters the id contains.
+pub fn id_selector(id: &str) -> String {
+    format!("[id=\"{}\"]", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Animations
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The animation and transition timing in an element's computed style, as the CSS strings
+/// the browser reports (comma-separated lists such as `"0.3s, 150ms"`).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct AnimationTiming {
+    pub animation_name: String,
+    pub animation_duration: String,
+    pub animation_delay: String,
+    pub transition_duration: String,
+    pub transition_delay: String,
+}
+
+impl AnimationTiming {
+    /// How long the longest animation or transition runs, delay included, in milliseconds.
+    fn total_ms(&self) -> f64 {
+        let animated = self
+            .animation_name
+            .split(',')
+            .any(|name| !matches!(name.trim(), "" | "none"));
+        let animations = if animated {
+            longest(&self.animation_duration, &self.animation_delay)
+        } else {
+            0.0
+        };
+        animations.max(longest(&self.transition_duration, &self.transition_delay))
+    }
+}
+
+/// Parses a CSS `<time>` list into milliseconds. Unparsable entries count as zero.
+fn css_times(list: &str) -> Vec<f64> {
+    list.split(',')
+        .map(|time| {
+            let time = time.trim();
+            let (value, scale) = match time.strip_suffix("ms") {
+                Some(value) => (value, 1.0),
+                None => (time.strip_suffix('s').unwrap_or(time), 1000.0),
+            };
+            value
+                .trim()
+                .parse::<f64>()
+                .map_or(0.0, |value| value * scale)
+        })
+        .collect()
+}
+
+/// The longest duration plus its delay. Like CSS, the delay list repeats when shorter.
+fn longest(durations: &str, delays: &str) -> f64 {
+    let delays = css_times(delays);
+    css_times(durations)
+        .into_iter()
+        .enumerate()
+        .map(|(index, duration)| {
+            let delay = match delays.len() {
+                0 => 0.0,
+                len => delays[index % len],
+            };
+            duration + delay
+        })
+        .fold(0.0, f64::max)
+}
+
+/// How long the CSS animations and transitions applied to the element with `id` take to
+/// finish, in milliseconds. `0` when it has none or there is no such element.
+///
+/// Call this right after changing the element's state (from an effect), so the computed
+/// style already reflects the new `data-state`.
+pub async fn animation_duration(id: &str) -> u64 {
+    let timing = backend::animation_timing(id).await;
+    timing.map_or(0, |timing| timing.total_ms().ceil() as u64)
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Focus
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Elements that can take keyboard focus; candidates are then filtered by `tabindex`,
+/// `disabled` and hidden ancestors.
+#[cfg(any(target_arch = "wasm32", not(feature = "testing")))]
+const TABBABLE_SELECTOR: &str = "a[href], area[href], button, input, select, textarea, iframe, summary, [tabindex], [contenteditable]";
+
+/// Focuses the element with `id`, if it exists and can take focus.
+pub fn focus(id: &str) {
+    backend::focus(id);
+}
+
+/// Focuses the first tabbable element inside the element with `container_id`, or the
+/// container itself when it has none.
+pub fn focus_first(container_id: &str) {
+    backend::focus_first(container_id);
+}
+
+/// Moves focus to the next (or previous) tabbable element inside the element with
+/// `container_id`, looking the tabbables up again on every call. At either end focus wraps
+/// around when `wrap` is set and stays put otherwise.
+pub fn focus_next(container_id: &str, forward: bool, wrap: bool) {
+    backend::focus_next(container_id, forward, wrap);
+}
+
+/// The element that had focus when it was captured, so focus can go back there later.
+pub struct FocusReturn(backend::FocusReturn);
+
+impl FocusReturn {
+    /// Remembers the currently focused element.
+    pub fn capture() -> Self {
+        Self(backend::FocusReturn::capture())
+    }
+
+    /// Focuses the remembered element again, if it is still in the document.
+    pub fn restore(self) {
+        self.0.restore();
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Portals
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Where a [`PortalMount`] moves its element.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PortalContainer {
+    /// The first element matching a CSS selector, such as `"body"`, `"#overlays"` or
+    /// `".panel"`.
+    Selector(String),
+    /// A specific element.
+    #[cfg(target_arch = "wasm32")]
+    Element(web_sys::Element),
+}
+
+impl Default for PortalContainer {
+    /// The element Dioxus mounts apps into unless configured otherwise, `#main`.
+    fn default() -> Self {
+        PortalContainer::Selector("#main".to_string())
+    }
+}
+
+impl From<&str> for PortalContainer {
+    fn from(selector: &str) -> Self {
+        PortalContainer::Selector(selector.to_string())
+    }
+}
+
+impl From<String> for PortalContainer {
+    fn from(selector: String) -> Self {
+        PortalContainer::Selector(selector)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl From<web_sys::Element> for PortalContainer {
+    fn from(element: web_sys::Element) -> Self {
+        PortalContainer::Element(element)
+    }
+}
+
+/// Lets optional `container` props take a selector or element without `Some(..)`, the way
+/// optional `String` props take a `&str`.
+#[doc(hidden)]
+pub struct OptionPortalContainerMarker;
+
+impl SuperFrom<&str, OptionPortalContainerMarker> for Option<PortalContainer> {
+    fn super_from(selector: &str) -> Self {
+        Some(selector.into())
+    }
+}
+
+impl SuperFrom<String, OptionPortalContainerMarker> for Option<PortalContainer> {
+    fn super_from(selector: String) -> Self {
+        Some(selector.into())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl SuperFrom<web_sys::Element, OptionPortalContainerMarker> for Option<PortalContainer> {
+    fn super_from(element: web_sys::Element) -> Self {
+        Some(element.into())
+    }
+}
+
+/// An element moved into another container. It is removed from the document when dropped,
+/// wherever it ended up.
+pub struct PortalMount {
+    id: String,
+    inner: Option<backend::PortalMount>,
+}
+
+impl PortalMount {
+    /// Appends the element with `id` to `container`. The element stays where it is when
+    /// the container doesn't exist.
+    pub fn new(id: &str, container: &PortalContainer) -> Self {
+        Self {
+            id: id.to_string(),
+            inner: backend::PortalMount::new(id, container),
+        }
+    }
+
+    /// Appends the element to another container, keeping it in the document. Like
+    /// [`PortalMount::new`], it stays where it is when the container doesn't exist.
+    pub fn move_to(&mut self, container: &PortalContainer) {
+        match &self.inner {
+            Some(inner) => inner.move_to(container),
+            None => self.inner = backend::PortalMount::new(&self.id, container),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Scripted side effects
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A document side effect set up by one script and undone by another, such as locking body
+/// scroll. The teardown script runs when the guard is dropped.
+pub struct ScriptGuard {
+    teardown: String,
+}
+
+impl ScriptGuard {
+    /// Runs `setup` now and `teardown` on drop.
+    pub fn new(setup: &str, teardown: impl Into<String>) -> Self {
+        run_script(setup);
+        Self {
+            teardown: teardown.into(),
+        }
+    }
+}
+
+impl Drop for ScriptGuard {
+    fn drop(&mut self) {
+        run_script(&self.teardown);
+    }
+}
+
+/// Holds the guard returned by `setup` while `active` is true.
+///
+/// `setup` runs after the component has rendered, so its scripts can find the component's
+/// elements. The guard is dropped, running its teardown, as soon as `active` turns false or
+/// the component unmounts.
+pub fn use_script_guard(active: bool, mut setup: impl FnMut() -> ScriptGuard + 'static) {
+    let setup = use_callback(move |()| setup());
+    let guard = use_hook(|| Rc::new(RefCell::new(None::<ScriptGuard>)));
+
+    let slot = guard.clone();
+    use_effect(use_reactive((&active,), move |(active,)| {
+        let mut slot = slot.borrow_mut();
+        if !active {
+            slot.take();
+        } else if slot.is_none() {
+            *slot = Some(setup.call(()));
+        }
+    }));
+
+    use_drop(move || {
+        guard.borrow_mut().take();
+    });
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Event listeners
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Where a global listener is attached.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListenerTarget {
+    Window,
+    Document,
+    /// The element with this id.
+    Element(String),
+    /// The `<form>` owning the element with this id: the one named by its `form`
+    /// attribute, otherwise its nearest ancestor form.
+    FormOf(String),
+}
+
+impl ListenerTarget {
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "testing")))]
+    fn as_js(&self) -> String {
+        match self {
+            ListenerTarget::Window => "window".to_string(),
+            ListenerTarget::Document => "document".to_string(),
+            ListenerTarget::Element(id) => format!("document.getElementById({id:?})"),
+            ListenerTarget::FormOf(id) => format!(
+                "((el) => el && (el.form || el.closest(\"form\")))(document.getElementById({id:?}))"
+            ),
+        }
+    }
+}
+
+/// Options for [`use_event_listener`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ListenerOptions {
+    /// Listen during the capture phase.
+    pub capture: bool,
+    /// Only deliver keyboard events whose `key` is listed. Empty delivers every event.
+    pub keys: &'static [&'static str],
+    /// Only deliver keyboard events matching this shortcut, such as `"mod+k"`. See
+    /// [`Hotkey`] for the syntax.
+    pub hotkey: Option<&'static str>,
+    /// Call `preventDefault()` on delivered events.
+    ///
+    /// Events reach Rust asynchronously, so this has to be decided up front.
+    pub prevent_default: bool,
+}
+
+/// The parts of a DOM event that components care about.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListenerEvent {
+    /// `KeyboardEvent.key`, empty for other events.
+    pub key: String,
+    pub shift_key: bool,
+    pub ctrl_key: bool,
+    pub alt_key: bool,
+    pub meta_key: bool,
+    /// The id of the event target itself, empty if it has none.
+    pub target_id: String,
+    /// The ids of the event target and its ancestors, innermost first, so a listener can
+    /// tell whether the event happened inside a given element.
+    pub target_ids: Vec<String>,
+}
+
+impl ListenerEvent {
+    /// Whether the event target is the element with `id` or inside it.
+    pub fn is_within(&self, id: &str) -> bool {
+        self.target_ids.iter().any(|target| target == id)
+    }
+}
+
+/// A keyboard shortcut such as `"mod+k"` or `"ctrl+shift+p"`: modifiers and a key joined
+/// by `+`, ignoring case.
+///
+/// The modifiers are `ctrl`, `alt`, `shift`, `meta` (or `cmd`), and `mod`, which is either
+/// Ctrl or Meta so the same shortcut works as Ctrl+K on Windows and Linux and Cmd+K on
+/// macOS. Modifiers that aren't listed must not be held.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hotkey {
+    /// `KeyboardEvent.key`, lowercased.
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// Either Ctrl or Meta.
+    pub r#mod: bool,
+}
+
+impl Hotkey {
+    /// Parses a shortcut, or returns `None` if it has no key or an unknown modifier.
+    pub fn parse(shortcut: &str) -> Option<Self> {
+        let mut hotkey = Hotkey {
+            key: String::new(),
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            r#mod: false,
+        };
+        let mut parts: Vec<&str> = shortcut.split('+').map(str::trim).collect();
+        // "mod++" binds the plus key itself
+        if shortcut.ends_with("++") {
+            parts.truncate(parts.len() - 2);
+            parts.push("+");
+        }
+        let (key, modifiers) = parts.split_last()?;
+        if key.is_empty() {
+            return None;
+        }
+        for modifier in modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => hotkey.ctrl = true,
+                "alt" | "option" => hotkey.alt = true,
+                "shift" => hotkey.shift = true,
+                "meta" | "cmd" | "command" => hotkey.meta = true,
+                "mod" => hotkey.r#mod = true,
+                _ => return None,
+            }
+        }
+        hotkey.key = key.to_lowercase();
+        Some(hotkey)
+    }
+
+    /// Whether a keyboard event is this shortcut.
+    pub fn matches(&self, event: &ListenerEvent) -> bool {
+        let command_keys = if self.r#mod {
+            event.ctrl_key || event.meta_key
+        } else {
+            event.ctrl_key == self.ctrl && event.meta_key == self.meta
+        };
+        event.key.to_lowercase() == self.key
+            && command_keys
+            && event.alt_key == self.alt
+            && event.shift_key == self.shift
+    }
+}
+
+/// A global event listener. It is removed when dropped.
+pub struct EventListener {
+    _inner: backend::Listener,
+    task: Task,
+}
+
+impl EventListener {
+    /// Attaches `callback` to `event` on `target`. Must be called inside a component.
+    ///
+    /// Element targets are looked up immediately, so only attach them once the element
+    /// has mounted.
+    pub fn new(
+        target: ListenerTarget,
+        event: &'static str,
+        options: ListenerOptions,
+        callback: Callback<ListenerEvent>,
+    ) -> Option<Self> {
+        let (inner, mut events) = backend::Listener::new(target, event, options)?;
+        let task = spawn(async move {
+            while let Some(event) = events.next().await {
+                callback.call(event);
+            }
+        });
+
+        Some(Self {
+            _inner: inner,
+            task,
+        })
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        self.task.cancel();
+    }
+}
+
+/// Listens for `event` on `target` while `active` is true.
+///
+/// The listener is attached once the component has rendered, so element targets may be
+/// rendered by the same component. It is replaced when `target`, `event` or `options`
+/// change, and removed as soon as `active` turns false or the component unmounts.
+pub fn use_event_listener(
+    target: ListenerTarget,
+    event: &'static str,
+    options: ListenerOptions,
+    active: bool,
+    handler: impl FnMut(ListenerEvent) + 'static,
+) {
+    let callback = use_callback(handler);
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<EventListener>)));
+
+    let slot = listener.clone();
+    use_effect(use_reactive(
+        (&target, &event, &options, &active),
+        move |(target, event, options, active)| {
+            let mut slot = slot.borrow_mut();
+            slot.take();
+            if active {
+                *slot = EventListener::new(target, event, options, callback);
+            }
+        },
+    ));
+
+    use_drop(move || {
+        listener.borrow_mut().take();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(name: &str, duration: &str, delay: &str) -> AnimationTiming {
+        AnimationTiming {
+            animation_name: name.to_string(),
+            animation_duration: duration.to_string(),
+            animation_delay: delay.to_string(),
+            transition_duration: "0s".to_string(),
+            transition_delay: "0s".to_string(),
+        }
+    }
+
+    fn key(key: &str, ctrl: bool, meta: bool, shift: bool) -> ListenerEvent {
+        ListenerEvent {
+            key: key.to_string(),
+            ctrl_key: ctrl,
+            meta_key: meta,
+            shift_key: shift,
+            ..ListenerEvent::default()
+        }
+    }
+
+    #[test]
+    fn mod_hotkeys_match_ctrl_or_meta() {
+        let hotkey = Hotkey::parse("mod+k").unwrap();
+        assert!(hotkey.matches(&key("k", true, false, false)));
+        assert!(hotkey.matches(&key("k", false, true, false)));
+        assert!(!hotkey.matches(&key("k", false, false, false)));
+        // Unlisted modifiers must not be held
+        assert!(!hotkey.matches(&key("K", true, false, true)));
+    }
+
+    #[test]
+    fn parses_hotkeys_ignoring_case() {
+        let hotkey = Hotkey::parse("Ctrl+Shift+P").unwrap();
+        assert_eq!(
+            (hotkey.key.as_str(), hotkey.ctrl, hotkey.shift),
+            ("p", true, true)
+        );
+        assert!(hotkey.matches(&key("P", true, false, true)));
+        assert!(!hotkey.matches(&key("P", true, true, true)));
+
+        assert_eq!(Hotkey::parse("mod++").unwrap().key, "+");
+        assert_eq!(Hotkey::parse("/").unwrap().key, "/");
+        assert_eq!(Hotkey::parse("hyper+k"), None);
+        assert_eq!(Hotkey::parse("ctrl+"), None);
+    }
+
+    #[test]
+    fn parses_css_time_lists() {
+        assert_eq!(css_times("0.3s, 150ms, 2s"), vec![300.0, 150.0, 2000.0]);
+        assert_eq!(css_times("bogus"), vec![0.0]);
+    }
+
+    #[test]
+    fn animation_duration_includes_delays_and_ignores_none() {
+        assert_eq!(timing("fadeOut", "200ms", "0.1s").total_ms(), 300.0);
+        assert_eq!(timing("a, b", "1s, 0.5s", "0s, 1s").total_ms(), 1500.0);
+        assert_eq!(timing("none", "1s", "0s").total_ms(), 0.0);
+
+        let transition = AnimationTiming {
+            transition_duration: "150ms".to_string(),
+            ..timing("none", "0s", "0s")
+        };
+        assert_eq!(transition.total_ms(), 150.0);
+    }
+}
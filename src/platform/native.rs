@@ -0,0 +1,266 @@
+//! Desktop, mobile and server backend built on `document::eval`.
+//!
+//! Without a webview (server-side rendering, tests) every eval fails, so timers (which run
+//! on a shared thread) still work while DOM queries return `None` and listeners never fire.
+
+use super::{
+    run_script, AnimationTiming, Hotkey, ListenerEvent, ListenerOptions, ListenerTarget,
+    PortalContainer, TABBABLE_SELECTOR,
+};
+use dioxus::core::Task;
+use dioxus::prelude::*;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_LISTENER_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub(super) use super::timer::sleep;
+
+pub(super) async fn viewport_size() -> Option<(f64, f64)> {
+    document::eval("return [window.innerWidth, window.innerHeight];")
+        .join::<(f64, f64)>()
+        .await
+        .ok()
+}
+
+pub(super) async fn document_order(ids: &[String]) -> Option<Vec<String>> {
+    let script = format!(
+        r#"
+        const ids = new Set({ids:?});
+        return Array.from(document.querySelectorAll("[id]"), (el) => el.id).filter((id) => ids.has(id));
+        "#
+    );
+    document::eval(&script).join::<Vec<String>>().await.ok()
+}
+
+pub(super) async fn animation_timing(id: &str) -> Option<AnimationTiming> {
+    let script = format!(
+        r#"
+        const el = document.getElementById({id:?});
+        if (!el) return null;
+        const style = getComputedStyle(el);
+        return [style.animationName, style.animationDuration, style.animationDelay,
+            style.transitionDuration, style.transitionDelay];
+        "#
+    );
+    let (
+        animation_name,
+        animation_duration,
+        animation_delay,
+        transition_duration,
+        transition_delay,
+    ) = document::eval(&script)
+        .join::<Option<(String, String, String, String, String)>>()
+        .await
+        .ok()??;
+    Some(AnimationTiming {
+        animation_name,
+        animation_duration,
+        animation_delay,
+        transition_duration,
+        transition_delay,
+    })
+}
+
+pub(super) fn dispatch_events(selector: &str, events: &[&'static str]) {
+    run_script(&format!(
+        r#"
+        const el = document.querySelector({selector:?});
+        if (el) for (const name of {events:?}) el.dispatchEvent(new Event(name, {{ bubbles: true }}));
+        "#
+    ));
+}
+
+/// Shared by the focus scripts: the tabbable elements inside `container`, in DOM order.
+const TABBABLES_JS: &str = r#"
+const tabbables = (container) => Array.from(container.querySelectorAll(SELECTOR)).filter(
+    (el) => el.tabIndex >= 0 && !el.disabled && !el.closest("[hidden], [inert]")
+);
+"#;
+
+fn focus_script(body: &str) -> String {
+    format!(
+        "{}{body}",
+        TABBABLES_JS.replace("SELECTOR", &format!("{TABBABLE_SELECTOR:?}"))
+    )
+}
+
+pub(super) fn focus(id: &str) {
+    run_script(&format!("document.getElementById({id:?})?.focus();"));
+}
+
+pub(super) fn focus_first(container_id: &str) {
+    run_script(&focus_script(&format!(
+        r#"
+        const container = document.getElementById({container_id:?});
+        if (container) (tabbables(container)[0] || container).focus();
+        "#
+    )));
+}
+
+pub(super) fn focus_next(container_id: &str, forward: bool, wrap: bool) {
+    run_script(&focus_script(&format!(
+        r#"
+        const container = document.getElementById({container_id:?});
+        if (container) {{
+            const items = tabbables(container);
+            const index = items.indexOf(document.activeElement);
+            let next = index === -1 ? ({forward} ? 0 : items.length - 1) : index + ({forward} ? 1 : -1);
+            if (next < 0 || next >= items.length) next = {wrap} ? (next + items.length) % items.length : index;
+            (items[next] || container).focus();
+        }}
+        "#
+    )));
+}
+
+static NEXT_FOCUS_RETURN_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub(super) struct FocusReturn {
+    id: usize,
+}
+
+impl FocusReturn {
+    pub(super) fn capture() -> Self {
+        let id = NEXT_FOCUS_RETURN_ID.fetch_add(1, Ordering::Relaxed);
+        run_script(&format!(
+            "window.__dxcFocusReturn = window.__dxcFocusReturn || {{}}; window.__dxcFocusReturn[{id}] = document.activeElement;"
+        ));
+        Self { id }
+    }
+
+    pub(super) fn restore(&self) {
+        run_script(&format!(
+            "const el = window.__dxcFocusReturn?.[{id}]; if (el && el.isConnected) el.focus();",
+            id = self.id
+        ));
+    }
+}
+
+impl Drop for FocusReturn {
+    fn drop(&mut self) {
+        run_script(&format!(
+            "delete window.__dxcFocusReturn?.[{id}];",
+            id = self.id
+        ));
+    }
+}
+
+pub(super) struct PortalMount {
+    id: String,
+}
+
+impl PortalMount {
+    pub(super) fn new(id: &str, container: &PortalContainer) -> Option<Self> {
+        let mount = Self { id: id.to_string() };
+        mount.move_to(container);
+        Some(mount)
+    }
+
+    pub(super) fn move_to(&self, container: &PortalContainer) {
+        let PortalContainer::Selector(selector) = container;
+        run_script(&format!(
+            r#"
+            const el = document.getElementById({id:?});
+            let target = null;
+            try {{ target = document.querySelector({selector:?}); }} catch (e) {{}}
+            if (el && target) target.appendChild(el);
+            "#,
+            id = self.id
+        ));
+    }
+}
+
+impl Drop for PortalMount {
+    fn drop(&mut self) {
+        run_script(&format!(
+            "document.getElementById({id:?})?.remove();",
+            id = self.id
+        ));
+    }
+}
+
+pub(super) struct Listener {
+    id: usize,
+    task: Task,
+}
+
+impl Listener {
+    pub(super) fn new(
+        target: ListenerTarget,
+        event: &'static str,
+        options: ListenerOptions,
+    ) -> Option<(Self, UnboundedReceiver<ListenerEvent>)> {
+        let id = NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed);
+        let keys = format!("{:?}", options.keys);
+        let hotkey = match options.hotkey.and_then(Hotkey::parse) {
+            Some(h) => format!(
+                "{{ key: {:?}, ctrl: {}, alt: {}, shift: {}, meta: {}, mod: {} }}",
+                h.key, h.ctrl, h.alt, h.shift, h.meta, h.r#mod
+            ),
+            None => "null".to_string(),
+        };
+        let script = format!(
+            r#"
+            const target = {target};
+            if (!target) return;
+            const keys = {keys};
+            const hotkey = {hotkey};
+            const handler = (e) => {{
+                if (keys.length && !keys.includes(e.key)) return;
+                if (hotkey && !(
+                    (e.key || "").toLowerCase() === hotkey.key
+                    && (hotkey.mod ? e.ctrlKey || e.metaKey : e.ctrlKey === hotkey.ctrl && e.metaKey === hotkey.meta)
+                    && e.altKey === hotkey.alt
+                    && e.shiftKey === hotkey.shift
+                )) return;
+                if ({prevent_default}) e.preventDefault();
+                const ids = [];
+                for (let el = e.target; el; el = el.parentElement) if (el.id) ids.push(el.id);
+                const targetId = (e.target && e.target.id) || "";
+                dioxus.send([e.key || "", !!e.shiftKey, !!e.ctrlKey, !!e.altKey, !!e.metaKey, targetId, ids]);
+            }};
+            target.addEventListener({event:?}, handler, {capture});
+            window.__dxcListeners = window.__dxcListeners || {{}};
+            window.__dxcListeners[{id}] = () => target.removeEventListener({event:?}, handler, {capture});
+            await new Promise(() => {{}});
+            "#,
+            target = target.as_js(),
+            prevent_default = options.prevent_default,
+            capture = options.capture,
+        );
+
+        let mut eval = document::eval(&script);
+        let (tx, rx) = unbounded();
+        let task = spawn(async move {
+            while let Ok((key, shift_key, ctrl_key, alt_key, meta_key, target_id, target_ids)) =
+                eval.recv::<(String, bool, bool, bool, bool, String, Vec<String>)>()
+                    .await
+            {
+                let event = ListenerEvent {
+                    key,
+                    shift_key,
+                    ctrl_key,
+                    alt_key,
+                    meta_key,
+                    target_id,
+                    target_ids,
+                };
+                if tx.unbounded_send(event).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Some((Self { id, task }, rx))
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        self.task.cancel();
+        run_script(&format!(
+            "window.__dxcListeners?.[{id}]?.(); delete window.__dxcListeners?.[{id}];",
+            id = self.id
+        ));
+    }
+}
@@ -0,0 +1,300 @@
+//! Backend for [`TestDom`](crate::testing::TestDom), used in place of the native one when
+//! the `testing` feature is enabled.
+//!
+//! A test DOM keeps its document in memory, so this backend queues what components ask of
+//! the document (focus changes, portal moves, dispatched events) for the test DOM to
+//! apply on its next flush, in the order they were asked for. Global listeners register
+//! here for the test DOM to deliver its synthetic events to, and queries (animation
+//! timing, document order) wait for it to answer. On a thread without a test DOM
+//! (server-side rendering, doctests) it behaves like a host without a document: queries
+//! return `None` and everything else does nothing.
+
+pub(super) use super::timer::sleep;
+
+use super::{AnimationTiming, ListenerEvent, ListenerOptions, ListenerTarget, PortalContainer};
+use dioxus::core::Runtime;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_channel::oneshot;
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+thread_local! {
+    static INSTALLED: Cell<bool> = const { Cell::new(false) };
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+    static LISTENERS: RefCell<Vec<HostListener>> = const { RefCell::new(Vec::new()) };
+    static REQUESTS: RefCell<Vec<HostRequest>> = const { RefCell::new(Vec::new()) };
+    static QUERIES: RefCell<Vec<HostQuery>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Routes the platform layer on this thread to the test DOM from now on.
+pub(crate) fn install() {
+    INSTALLED.with(|installed| installed.set(true));
+}
+
+fn installed() -> bool {
+    INSTALLED.try_with(Cell::get).unwrap_or(false)
+}
+
+fn next_id() -> usize {
+    NEXT_ID.with(|next| next.replace(next.get() + 1))
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Requests
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A document change for the test DOM to apply.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum HostRequest {
+    Focus(FocusRequest),
+    /// Append the element with `id` to the first element matching `container`.
+    MovePortal {
+        id: String,
+        container: String,
+    },
+    /// Remove the element with `id` from the document, wherever it is.
+    RemovePortal {
+        id: String,
+    },
+    /// Dispatch bubbling `events` on the first element matching `selector`.
+    DispatchEvents {
+        selector: String,
+        events: Vec<&'static str>,
+    },
+}
+
+/// A focus change, see [`HostRequest::Focus`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum FocusRequest {
+    Element(String),
+    First {
+        container: String,
+    },
+    Next {
+        container: String,
+        forward: bool,
+        wrap: bool,
+    },
+    Capture(usize),
+    Restore(usize),
+    Forget(usize),
+}
+
+fn request(request: HostRequest) {
+    // The queue may already be gone if the thread is shutting down
+    if installed() {
+        let _ = REQUESTS.try_with(|requests| requests.borrow_mut().push(request));
+    }
+}
+
+/// Takes the requests made since the last call, oldest first.
+pub(crate) fn take_requests() -> Vec<HostRequest> {
+    REQUESTS.with(|requests| std::mem::take(&mut *requests.borrow_mut()))
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DOM queries
+ * -----------------------------------------------------------------------------------------------*/
+
+/// There is no layout, so there is no viewport to measure.
+pub(super) async fn viewport_size() -> Option<(f64, f64)> {
+    None
+}
+
+/// A question for the test DOM, answered on its next flush.
+pub(crate) enum HostQuery {
+    /// The animation timing of the element with `id`.
+    AnimationTiming {
+        id: String,
+        reply: oneshot::Sender<Option<AnimationTiming>>,
+    },
+    /// The ids among `ids` of elements in the document, in document order.
+    DocumentOrder {
+        ids: Vec<String>,
+        reply: oneshot::Sender<Vec<String>>,
+    },
+}
+
+/// Queues the query built around `reply` and waits for the answer, or returns `None` on a
+/// thread without a test DOM.
+async fn ask<T>(query: impl FnOnce(oneshot::Sender<T>) -> HostQuery) -> Option<T> {
+    if !installed() {
+        return None;
+    }
+    let (reply, answer) = oneshot::channel();
+    QUERIES.with(|queries| queries.borrow_mut().push(query(reply)));
+    answer.await.ok()
+}
+
+/// Takes the queries made since the last call.
+pub(crate) fn take_queries() -> Vec<HostQuery> {
+    QUERIES.with(|queries| std::mem::take(&mut *queries.borrow_mut()))
+}
+
+pub(super) async fn document_order(ids: &[String]) -> Option<Vec<String>> {
+    let ids = ids.to_vec();
+    ask(|reply| HostQuery::DocumentOrder { ids, reply }).await
+}
+
+pub(super) async fn animation_timing(id: &str) -> Option<AnimationTiming> {
+    let id = id.to_string();
+    ask(|reply| HostQuery::AnimationTiming { id, reply })
+        .await
+        .flatten()
+}
+
+pub(super) fn dispatch_events(selector: &str, events: &[&'static str]) {
+    request(HostRequest::DispatchEvents {
+        selector: selector.to_string(),
+        events: events.to_vec(),
+    });
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Focus
+ * -----------------------------------------------------------------------------------------------*/
+
+pub(super) fn focus(id: &str) {
+    request(HostRequest::Focus(FocusRequest::Element(id.to_string())));
+}
+
+pub(super) fn focus_first(container_id: &str) {
+    request(HostRequest::Focus(FocusRequest::First {
+        container: container_id.to_string(),
+    }));
+}
+
+pub(super) fn focus_next(container_id: &str, forward: bool, wrap: bool) {
+    request(HostRequest::Focus(FocusRequest::Next {
+        container: container_id.to_string(),
+        forward,
+        wrap,
+    }));
+}
+
+/// A remembered focus target, identified by key in the test DOM.
+pub(super) struct FocusReturn(usize);
+
+impl FocusReturn {
+    pub(super) fn capture() -> Self {
+        let key = next_id();
+        request(HostRequest::Focus(FocusRequest::Capture(key)));
+        Self(key)
+    }
+
+    pub(super) fn restore(&self) {
+        request(HostRequest::Focus(FocusRequest::Restore(self.0)));
+    }
+}
+
+impl Drop for FocusReturn {
+    fn drop(&mut self) {
+        request(HostRequest::Focus(FocusRequest::Forget(self.0)));
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Portals
+ * -----------------------------------------------------------------------------------------------*/
+
+pub(super) struct PortalMount {
+    id: String,
+}
+
+impl PortalMount {
+    pub(super) fn new(id: &str, container: &PortalContainer) -> Option<Self> {
+        if !installed() {
+            return None;
+        }
+        let mount = Self { id: id.to_string() };
+        mount.move_to(container);
+        Some(mount)
+    }
+
+    pub(super) fn move_to(&self, container: &PortalContainer) {
+        let PortalContainer::Selector(selector) = container;
+        request(HostRequest::MovePortal {
+            id: self.id.clone(),
+            container: selector.clone(),
+        });
+    }
+}
+
+impl Drop for PortalMount {
+    fn drop(&mut self) {
+        request(HostRequest::RemovePortal {
+            id: std::mem::take(&mut self.id),
+        });
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Event listeners
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A listener registered by a component running inside a test DOM.
+#[derive(Clone)]
+pub(crate) struct HostListener {
+    id: usize,
+    runtime: Weak<Runtime>,
+    pub target: ListenerTarget,
+    pub event: &'static str,
+    pub options: ListenerOptions,
+    pub sender: UnboundedSender<ListenerEvent>,
+}
+
+/// Removes its listener from the registry when dropped.
+pub(super) struct Listener(usize);
+
+impl Listener {
+    pub(super) fn new(
+        target: ListenerTarget,
+        event: &'static str,
+        options: ListenerOptions,
+    ) -> Option<(Self, UnboundedReceiver<ListenerEvent>)> {
+        if !installed() {
+            return None;
+        }
+        let runtime = Runtime::try_current()?;
+
+        let id = next_id();
+        let (sender, receiver) = unbounded();
+        LISTENERS.with(|listeners| {
+            listeners.borrow_mut().push(HostListener {
+                id,
+                runtime: Rc::downgrade(&runtime),
+                target,
+                event,
+                options,
+                sender,
+            });
+        });
+
+        Some((Self(id), receiver))
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        let id = self.0;
+        // The registry may already be gone if the thread is shutting down
+        let _ = LISTENERS.try_with(|listeners| {
+            listeners.borrow_mut().retain(|listener| listener.id != id);
+        });
+    }
+}
+
+/// The listeners for `event` registered by components of `runtime`, in registration order.
+pub(crate) fn listeners(runtime: &Rc<Runtime>, event: &str) -> Vec<HostListener> {
+    LISTENERS.with(|listeners| {
+        listeners
+            .borrow()
+            .iter()
+            .filter(|listener| {
+                listener.event == event
+                    && std::ptr::eq(listener.runtime.as_ptr(), Rc::as_ptr(runtime))
+            })
+            .cloned()
+            .collect()
+    })
+}
@@ -0,0 +1,151 @@
+//! One shared timer thread for hosts without an async timer driver.
+//!
+//! Every [`sleep`] registers a deadline with the same background thread, which sleeps until
+//! the soonest one and then wakes the futures that are due. Dropping a [`Sleep`] before it
+//! fires (for example by cancelling the task awaiting it) removes its deadline.
+
+use futures_channel::oneshot;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex, MutexGuard, Once, PoisonError};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// A deadline and the id that keeps it unique among sleeps due at the same instant.
+type Key = (Instant, u64);
+
+struct Timers {
+    /// The pending wakeups, soonest first.
+    queue: BTreeMap<Key, oneshot::Sender<()>>,
+    next_id: u64,
+}
+
+static TIMERS: Mutex<Timers> = Mutex::new(Timers {
+    queue: BTreeMap::new(),
+    next_id: 0,
+});
+/// Signalled when a new deadline becomes the soonest one.
+static CHANGED: Condvar = Condvar::new();
+static STARTED: Once = Once::new();
+
+fn timers() -> MutexGuard<'static, Timers> {
+    TIMERS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn run() {
+    let mut timers = timers();
+    loop {
+        let now = Instant::now();
+        while let Some(entry) = timers.queue.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            let _ = entry.remove().send(());
+        }
+        timers = match timers.queue.keys().next() {
+            Some(&(deadline, _)) => {
+                CHANGED
+                    .wait_timeout(timers, deadline - now)
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0
+            }
+            None => CHANGED.wait(timers).unwrap_or_else(PoisonError::into_inner),
+        };
+    }
+}
+
+/// Resolves once its deadline has passed. See [`sleep`].
+pub(super) struct Sleep {
+    key: Key,
+    fired: oneshot::Receiver<()>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.fired).poll(cx).map(|_| ())
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        timers().queue.remove(&self.key);
+    }
+}
+
+/// Waits for `ms` milliseconds, capped like browser timers at `u32::MAX`.
+pub(super) fn sleep(ms: u64) -> Sleep {
+    STARTED.call_once(|| {
+        std::thread::Builder::new()
+            .name("dioxus-components-timer".to_string())
+            .spawn(run)
+            .expect("failed to start the timer thread");
+    });
+
+    let deadline = Instant::now() + Duration::from_millis(ms.min(u32::MAX as u64));
+    let (sender, fired) = oneshot::channel();
+    let mut timers = timers();
+    let key = (deadline, timers.next_id);
+    timers.next_id += 1;
+    let soonest = timers.queue.keys().next().is_none_or(|&first| key < first);
+    timers.queue.insert(key, sender);
+    drop(timers);
+
+    if soonest {
+        CHANGED.notify_one();
+    }
+    Sleep { key, fired }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+    use std::thread::{self, Thread};
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    fn is_pending(key: &Key) -> bool {
+        timers().queue.contains_key(key)
+    }
+
+    #[test]
+    fn sleeps_wake_after_their_deadline_even_behind_later_ones() {
+        let later = sleep(60_000);
+        let start = Instant::now();
+        block_on(sleep(30));
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert!(is_pending(&later.key));
+    }
+
+    #[test]
+    fn dropping_a_sleep_drops_its_wakeup() {
+        let pending = sleep(60_000);
+        let key = pending.key;
+        assert!(is_pending(&key));
+        drop(pending);
+        assert!(!is_pending(&key));
+    }
+}
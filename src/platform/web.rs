@@ -0,0 +1,308 @@
+//! Browser backend built on `web_sys`.
+
+use super::{
+    AnimationTiming, Hotkey, ListenerEvent, ListenerOptions, ListenerTarget, PortalContainer,
+    TABBABLE_SELECTOR,
+};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use std::collections::HashSet;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+pub(super) async fn sleep(ms: u64) {
+    gloo_timers::future::TimeoutFuture::new(ms.min(u32::MAX as u64) as u32).await;
+}
+
+pub(super) async fn viewport_size() -> Option<(f64, f64)> {
+    let window = web_sys::window()?;
+    let width = window.inner_width().ok()?.as_f64()?;
+    let height = window.inner_height().ok()?.as_f64()?;
+    Some((width, height))
+}
+
+pub(super) async fn document_order(ids: &[String]) -> Option<Vec<String>> {
+    let elements = document()?.query_selector_all("[id]").ok()?;
+    let ids: HashSet<&str> = ids.iter().map(String::as_str).collect();
+    Some(
+        (0..elements.length())
+            .filter_map(|index| elements.item(index)?.dyn_into::<web_sys::Element>().ok())
+            .map(|element| element.id())
+            .filter(|id| ids.contains(id.as_str()))
+            .collect(),
+    )
+}
+
+pub(super) async fn animation_timing(id: &str) -> Option<AnimationTiming> {
+    let window = web_sys::window()?;
+    let element = window.document()?.get_element_by_id(id)?;
+    let style = window.get_computed_style(&element).ok()??;
+    let property = |name: &str| style.get_property_value(name).unwrap_or_default();
+    Some(AnimationTiming {
+        animation_name: property("animation-name"),
+        animation_duration: property("animation-duration"),
+        animation_delay: property("animation-delay"),
+        transition_duration: property("transition-duration"),
+        transition_delay: property("transition-delay"),
+    })
+}
+
+pub(super) fn dispatch_events(selector: &str, events: &[&'static str]) {
+    let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.query_selector(selector).ok().flatten())
+    else {
+        return;
+    };
+    let init = web_sys::EventInit::new();
+    init.set_bubbles(true);
+    for name in events {
+        if let Ok(event) = web_sys::Event::new_with_event_init_dict(name, &init) {
+            let _ = element.dispatch_event(&event);
+        }
+    }
+}
+
+/// The form owning `element`, as `element.form` reports it for form controls.
+fn owner_form(element: web_sys::Element) -> Option<web_sys::Element> {
+    let form = if let Some(button) = element.dyn_ref::<web_sys::HtmlButtonElement>() {
+        button.form()
+    } else if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+        input.form()
+    } else {
+        None
+    };
+    match form {
+        Some(form) => Some(form.into()),
+        None => element.closest("form").ok().flatten(),
+    }
+}
+
+fn document() -> Option<web_sys::Document> {
+    web_sys::window()?.document()
+}
+
+/// The tabbable elements inside `container`, in DOM order.
+fn tabbables(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(TABBABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index)?.dyn_into::<web_sys::HtmlElement>().ok())
+        .filter(|el| {
+            el.tab_index() >= 0
+                && !el.has_attribute("disabled")
+                && el.closest("[hidden], [inert]").ok().flatten().is_none()
+        })
+        .collect()
+}
+
+pub(super) fn focus(id: &str) {
+    let element = document()
+        .and_then(|doc| doc.get_element_by_id(id))
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+    if let Some(element) = element {
+        let _ = element.focus();
+    }
+}
+
+pub(super) fn focus_first(container_id: &str) {
+    let Some(container) = document().and_then(|doc| doc.get_element_by_id(container_id)) else {
+        return;
+    };
+    let target = tabbables(&container)
+        .into_iter()
+        .next()
+        .or_else(|| container.dyn_into().ok());
+    if let Some(target) = target {
+        let _ = target.focus();
+    }
+}
+
+pub(super) fn focus_next(container_id: &str, forward: bool, wrap: bool) {
+    let Some(document) = document() else {
+        return;
+    };
+    let Some(container) = document.get_element_by_id(container_id) else {
+        return;
+    };
+    let items = tabbables(&container);
+    if items.is_empty() {
+        if let Ok(container) = container.dyn_into::<web_sys::HtmlElement>() {
+            let _ = container.focus();
+        }
+        return;
+    }
+
+    let active = document.active_element();
+    let current = items
+        .iter()
+        .position(|item| Some(item.unchecked_ref::<web_sys::Element>()) == active.as_ref());
+    let len = items.len() as isize;
+    let next = match current {
+        None if forward => 0,
+        None => len - 1,
+        Some(index) => {
+            let next = index as isize + if forward { 1 } else { -1 };
+            match (next < 0 || next >= len, wrap) {
+                (false, _) => next,
+                (true, true) => (next + len) % len,
+                (true, false) => index as isize,
+            }
+        }
+    };
+    let _ = items[next as usize].focus();
+}
+
+pub(super) struct FocusReturn {
+    element: Option<web_sys::HtmlElement>,
+}
+
+impl FocusReturn {
+    pub(super) fn capture() -> Self {
+        let element = document()
+            .and_then(|doc| doc.active_element())
+            .and_then(|el| el.dyn_into().ok());
+        Self { element }
+    }
+
+    pub(super) fn restore(&self) {
+        if let Some(element) = self.element.as_ref().filter(|el| el.is_connected()) {
+            let _ = element.focus();
+        }
+    }
+}
+
+pub(super) struct PortalMount {
+    element: web_sys::Element,
+}
+
+impl PortalMount {
+    pub(super) fn new(id: &str, container: &PortalContainer) -> Option<Self> {
+        let element = document()?.get_element_by_id(id)?;
+        let mount = Self { element };
+        mount.try_move_to(container)?;
+        Some(mount)
+    }
+
+    pub(super) fn move_to(&self, container: &PortalContainer) {
+        let _ = self.try_move_to(container);
+    }
+
+    fn try_move_to(&self, container: &PortalContainer) -> Option<()> {
+        let target = match container {
+            // Invalid selectors are an error rather than a miss
+            PortalContainer::Selector(selector) => document()?.query_selector(selector).ok()??,
+            PortalContainer::Element(target) => target.clone(),
+        };
+        target.append_child(&self.element).ok()?;
+        Some(())
+    }
+}
+
+impl Drop for PortalMount {
+    fn drop(&mut self) {
+        self.element.remove();
+    }
+}
+
+/// The ids of the event target and its ancestors, innermost first.
+fn target_ids(event: &web_sys::Event) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut element = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+    while let Some(current) = element {
+        let id = current.id();
+        if !id.is_empty() {
+            ids.push(id);
+        }
+        element = current.parent_element();
+    }
+    ids
+}
+
+pub(super) struct Listener {
+    target: web_sys::EventTarget,
+    event: &'static str,
+    capture: bool,
+    callback: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl Listener {
+    pub(super) fn new(
+        target: ListenerTarget,
+        event: &'static str,
+        options: ListenerOptions,
+    ) -> Option<(Self, UnboundedReceiver<ListenerEvent>)> {
+        let window = web_sys::window()?;
+        let target: web_sys::EventTarget = match target {
+            ListenerTarget::Window => window.into(),
+            ListenerTarget::Document => window.document()?.into(),
+            ListenerTarget::Element(id) => window.document()?.get_element_by_id(&id)?.into(),
+            ListenerTarget::FormOf(id) => {
+                owner_form(window.document()?.get_element_by_id(&id)?)?.into()
+            }
+        };
+
+        let hotkey = options.hotkey.and_then(Hotkey::parse);
+        let (tx, rx) = unbounded();
+        let callback = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+            let keyboard = event.dyn_ref::<web_sys::KeyboardEvent>();
+            let key = keyboard.map(|e| e.key()).unwrap_or_default();
+            if !options.keys.is_empty() && !options.keys.contains(&key.as_str()) {
+                return;
+            }
+            let listener_event = ListenerEvent {
+                key,
+                shift_key: keyboard.map(|e| e.shift_key()).unwrap_or(false),
+                ctrl_key: keyboard.map(|e| e.ctrl_key()).unwrap_or(false),
+                alt_key: keyboard.map(|e| e.alt_key()).unwrap_or(false),
+                meta_key: keyboard.map(|e| e.meta_key()).unwrap_or(false),
+                target_id: event
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    .map(|element| element.id())
+                    .unwrap_or_default(),
+                target_ids: target_ids(&event),
+            };
+            if hotkey
+                .as_ref()
+                .is_some_and(|hotkey| !hotkey.matches(&listener_event))
+            {
+                return;
+            }
+            if options.prevent_default {
+                event.prevent_default();
+            }
+            let _ = tx.unbounded_send(listener_event);
+        });
+
+        target
+            .add_event_listener_with_callback_and_bool(
+                event,
+                callback.as_ref().unchecked_ref(),
+                options.capture,
+            )
+            .ok()?;
+
+        Some((
+            Self {
+                target,
+                event,
+                capture: options.capture,
+                callback,
+            },
+            rx,
+        ))
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        let _ = self.target.remove_event_listener_with_callback_and_bool(
+            self.event,
+            self.callback.as_ref().unchecked_ref(),
+            self.capture,
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! A minimal document tree that applies VirtualDom mutations.
+
+use std::collections::HashMap;
+
+use dioxus::core::{
+    AttributeValue, ElementId, Template, TemplateAttribute, TemplateNode, WriteMutations,
+};
+
+#[derive(Debug)]
+pub(crate) enum NodeKind {
+    Element {
+        tag: String,
+        attributes: Vec<(String, String)>,
+        styles: Vec<(String, String)>,
+        listeners: Vec<String>,
+    },
+    Text(String),
+    Placeholder,
+}
+
+#[derive(Debug)]
+pub(crate) struct Node {
+    pub kind: NodeKind,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+    pub element_id: Option<ElementId>,
+}
+
+/// Arena of nodes. Index `0` is the document body that the VirtualDom mounts into.
+pub(crate) struct Tree {
+    pub nodes: Vec<Node>,
+    ids: HashMap<ElementId, usize>,
+    stack: Vec<usize>,
+    /// Nodes that gained an `onmounted` listener since the last drain.
+    pub mounted: Vec<usize>,
+}
+
+pub(crate) const ROOT: usize = 0;
+
+impl Tree {
+    pub fn new() -> Self {
+        let mut tree = Self {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            stack: Vec::new(),
+            mounted: Vec::new(),
+        };
+        let root = tree.push_node(NodeKind::Element {
+            tag: "body".to_string(),
+            attributes: Vec::new(),
+            styles: Vec::new(),
+            listeners: Vec::new(),
+        });
+        tree.assign(root, ElementId(0));
+        tree
+    }
+
+    pub fn element_id(&self, node: usize) -> Option<ElementId> {
+        self.nodes[node].element_id
+    }
+
+    /// Whether the VirtualDom has attached any event listener to the node.
+    pub fn has_listeners(&self, node: usize) -> bool {
+        matches!(&self.nodes[node].kind, NodeKind::Element { listeners, .. } if !listeners.is_empty())
+    }
+
+    /// Whether the node is still attached to the body.
+    pub fn is_connected(&self, mut node: usize) -> bool {
+        loop {
+            if node == ROOT {
+                return true;
+            }
+            match self.nodes[node].parent {
+                Some(parent) => node = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// All connected nodes below `node`, in document order.
+    pub fn descendants(&self, node: usize) -> Vec<usize> {
+        let mut out = Vec::new();
+        let mut pending: Vec<usize> = self.nodes[node].children.iter().rev().copied().collect();
+        while let Some(next) = pending.pop() {
+            out.push(next);
+            pending.extend(self.nodes[next].children.iter().rev());
+        }
+        out
+    }
+
+    fn push_node(&mut self, kind: NodeKind) -> usize {
+        self.nodes.push(Node {
+            kind,
+            parent: None,
+            children: Vec::new(),
+            element_id: None,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn assign(&mut self, node: usize, id: ElementId) {
+        if let Some(previous) = self.ids.insert(id, node) {
+            if previous != node {
+                self.nodes[previous].element_id = None;
+            }
+        }
+        self.nodes[node].element_id = Some(id);
+    }
+
+    fn node(&self, id: ElementId) -> usize {
+        self.ids[&id]
+    }
+
+    fn build(&mut self, template: &TemplateNode) -> usize {
+        match template {
+            TemplateNode::Element {
+                tag,
+                attrs,
+                children,
+                ..
+            } => {
+                let attributes = attrs
+                    .iter()
+                    .filter_map(|attr| match attr {
+                        TemplateAttribute::Static { name, value, .. } => {
+                            Some((name.to_string(), value.to_string()))
+                        }
+                        TemplateAttribute::Dynamic { .. } => None,
+                    })
+                    .collect();
+                let node = self.push_node(NodeKind::Element {
+                    tag: tag.to_string(),
+                    attributes,
+                    styles: Vec::new(),
+                    listeners: Vec::new(),
+                });
+                for child in children.iter() {
+                    let child = self.build(child);
+                    self.append(node, child);
+                }
+                node
+            }
+            TemplateNode::Text { text } => self.push_node(NodeKind::Text(text.to_string())),
+            TemplateNode::Dynamic { .. } => self.push_node(NodeKind::Placeholder),
+        }
+    }
+
+    pub fn detach(&mut self, node: usize) {
+        if let Some(parent) = self.nodes[node].parent.take() {
+            self.nodes[parent].children.retain(|&child| child != node);
+        }
+    }
+
+    pub fn append(&mut self, parent: usize, child: usize) {
+        self.detach(child);
+        self.nodes[child].parent = Some(parent);
+        self.nodes[parent].children.push(child);
+    }
+
+    fn insert_at(&mut self, anchor: usize, nodes: Vec<usize>, after: bool) {
+        let Some(parent) = self.nodes[anchor].parent else {
+            return;
+        };
+        for &node in &nodes {
+            self.detach(node);
+            self.nodes[node].parent = Some(parent);
+        }
+        let position = self.nodes[parent]
+            .children
+            .iter()
+            .position(|&child| child == anchor)
+            .expect("anchor is a child of its parent");
+        let position = if after { position + 1 } else { position };
+        self.nodes[parent]
+            .children
+            .splice(position..position, nodes);
+    }
+
+    fn replace(&mut self, old: usize, nodes: Vec<usize>) {
+        self.insert_at(old, nodes, false);
+        self.detach(old);
+    }
+
+    fn pop(&mut self, m: usize) -> Vec<usize> {
+        let at = self.stack.len() - m;
+        self.stack.split_off(at)
+    }
+
+    fn at_path(&self, path: &[u8]) -> usize {
+        let mut node = *self.stack.last().expect("template root on the stack");
+        for &index in path {
+            node = self.nodes[node].children[index as usize];
+        }
+        node
+    }
+}
+
+/// Mirrors the web interpreter: `false` removes boolean attributes and is kept as text
+/// everywhere else (`aria-expanded="false"`).
+fn attribute_text(name: &str, value: &AttributeValue) -> Option<String> {
+    let text = match value {
+        AttributeValue::Text(text) => text.clone(),
+        AttributeValue::Float(value) => value.to_string(),
+        AttributeValue::Int(value) => value.to_string(),
+        AttributeValue::Bool(value) => value.to_string(),
+        AttributeValue::Listener(_) | AttributeValue::Any(_) | AttributeValue::None => return None,
+    };
+    if text != "true" && BOOLEAN_ATTRIBUTES.contains(&name) {
+        return None;
+    }
+    Some(text)
+}
+
+const BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "ismap",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
+impl WriteMutations for Tree {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        let parent = self.node(id);
+        for child in self.pop(m) {
+            self.append(parent, child);
+        }
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        let node = self.at_path(path);
+        self.assign(node, id);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        let node = self.push_node(NodeKind::Placeholder);
+        self.assign(node, id);
+        self.stack.push(node);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        let node = self.push_node(NodeKind::Text(value.to_string()));
+        self.assign(node, id);
+        self.stack.push(node);
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        let node = self.build(&template.roots[index]);
+        self.assign(node, id);
+        self.stack.push(node);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        let old = self.node(id);
+        let nodes = self.pop(m);
+        self.replace(old, nodes);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        let nodes = self.pop(m);
+        let old = self.at_path(path);
+        self.replace(old, nodes);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        let anchor = self.node(id);
+        let nodes = self.pop(m);
+        self.insert_at(anchor, nodes, true);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        let anchor = self.node(id);
+        let nodes = self.pop(m);
+        self.insert_at(anchor, nodes, false);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        let node = self.node(id);
+        let NodeKind::Element {
+            attributes, styles, ..
+        } = &mut self.nodes[node].kind
+        else {
+            return;
+        };
+        let list = if ns == Some("style") {
+            styles
+        } else {
+            attributes
+        };
+        list.retain(|(existing, _)| existing != name);
+        if let Some(value) = attribute_text(name, value) {
+            list.push((name.to_string(), value));
+        }
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        let node = self.node(id);
+        self.nodes[node].kind = NodeKind::Text(value.to_string());
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.node(id);
+        if let NodeKind::Element { listeners, .. } = &mut self.nodes[node].kind {
+            listeners.push(name.to_string());
+        }
+        if name == "mounted" {
+            self.mounted.push(node);
+        }
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.node(id);
+        if let NodeKind::Element { listeners, .. } = &mut self.nodes[node].kind {
+            if let Some(index) = listeners.iter().position(|listener| listener == name) {
+                listeners.remove(index);
+            }
+        }
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        let node = self.node(id);
+        self.detach(node);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        let node = self.node(id);
+        self.stack.push(node);
+    }
+}
@@ -0,0 +1,372 @@
+//! Synthetic event payloads for [`TestDom`](super::TestDom).
+//!
+//! Dioxus hands every listener a [`PlatformEventData`] and relies on a global
+//! [`HtmlEventConverter`] to turn it into `MouseData`, `KeyboardData`, and so on. Renderers
+//! install their own converter; the test DOM installs [`SyntheticConverter`], which only
+//! understands the payloads defined here.
+
+use std::any::Any;
+use std::rc::Rc;
+
+use dioxus::html::geometry::{ClientPoint, ElementPoint, PagePoint, ScreenPoint};
+use dioxus::html::input_data::{MouseButton, MouseButtonSet};
+use dioxus::html::point_interaction::{
+    InteractionElementOffset, InteractionLocation, ModifiersInteraction, PointerInteraction,
+};
+use dioxus::html::{
+    AnimationData, CancelData, ClipboardData, CompositionData, DragData, FileData, FocusData,
+    FormData, FormValue, HasAnimationData, HasFileData, HasFocusData, HasFormData, HasKeyboardData,
+    HasMouseData, HasPointerData, HasTransitionData, HtmlEventConverter, ImageData, KeyboardData,
+    MediaData, MountedData, MouseData, PlatformEventData, PointerData, ResizeData, ScrollData,
+    SelectionData, ToggleData, TouchData, TransitionData, VisibleData, WheelData,
+};
+use dioxus::prelude::{Code, Key, Location, Modifiers};
+
+/// Pointer and mouse payload. Coordinates are reported identically in every space.
+#[derive(Clone, Debug)]
+pub(crate) struct SyntheticPointer {
+    pub pointer_type: &'static str,
+    pub button: Option<MouseButton>,
+    pub x: f64,
+    pub y: f64,
+    pub modifiers: Modifiers,
+}
+
+impl SyntheticPointer {
+    pub fn mouse() -> Self {
+        Self {
+            pointer_type: "mouse",
+            button: Some(MouseButton::Primary),
+            x: 0.0,
+            y: 0.0,
+            modifiers: Modifiers::empty(),
+        }
+    }
+}
+
+impl InteractionLocation for SyntheticPointer {
+    fn client_coordinates(&self) -> ClientPoint {
+        ClientPoint::new(self.x, self.y)
+    }
+
+    fn screen_coordinates(&self) -> ScreenPoint {
+        ScreenPoint::new(self.x, self.y)
+    }
+
+    fn page_coordinates(&self) -> PagePoint {
+        PagePoint::new(self.x, self.y)
+    }
+}
+
+impl InteractionElementOffset for SyntheticPointer {
+    fn element_coordinates(&self) -> ElementPoint {
+        ElementPoint::new(self.x, self.y)
+    }
+}
+
+impl ModifiersInteraction for SyntheticPointer {
+    fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+}
+
+impl PointerInteraction for SyntheticPointer {
+    fn trigger_button(&self) -> Option<MouseButton> {
+        self.button
+    }
+
+    fn held_buttons(&self) -> MouseButtonSet {
+        match self.button {
+            Some(button) => MouseButtonSet::only(button),
+            None => MouseButtonSet::empty(),
+        }
+    }
+}
+
+impl HasMouseData for SyntheticPointer {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl HasPointerData for SyntheticPointer {
+    fn pointer_id(&self) -> i32 {
+        1
+    }
+
+    fn width(&self) -> f64 {
+        1.0
+    }
+
+    fn height(&self) -> f64 {
+        1.0
+    }
+
+    fn pressure(&self) -> f32 {
+        if self.button.is_some() {
+            0.5
+        } else {
+            0.0
+        }
+    }
+
+    fn tangential_pressure(&self) -> f32 {
+        0.0
+    }
+
+    fn tilt_x(&self) -> i32 {
+        0
+    }
+
+    fn tilt_y(&self) -> i32 {
+        0
+    }
+
+    fn twist(&self) -> i32 {
+        0
+    }
+
+    fn pointer_type(&self) -> String {
+        self.pointer_type.to_string()
+    }
+
+    fn is_primary(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Keyboard payload.
+#[derive(Clone, Debug)]
+pub(crate) struct SyntheticKeyboard {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl ModifiersInteraction for SyntheticKeyboard {
+    fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+}
+
+impl HasKeyboardData for SyntheticKeyboard {
+    fn key(&self) -> Key {
+        self.key.clone()
+    }
+
+    fn code(&self) -> Code {
+        Code::Unidentified
+    }
+
+    fn location(&self) -> Location {
+        Location::Standard
+    }
+
+    fn is_auto_repeating(&self) -> bool {
+        false
+    }
+
+    fn is_composing(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Focus and blur payload.
+#[derive(Clone, Debug)]
+pub(crate) struct SyntheticFocus;
+
+impl HasFocusData for SyntheticFocus {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Input, change, submit and reset payload.
+#[derive(Clone, Debug)]
+pub(crate) struct SyntheticForm {
+    pub value: String,
+    pub values: Vec<(String, String)>,
+}
+
+impl HasFileData for SyntheticForm {
+    fn files(&self) -> Vec<FileData> {
+        Vec::new()
+    }
+}
+
+impl HasFormData for SyntheticForm {
+    fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    fn valid(&self) -> bool {
+        true
+    }
+
+    fn values(&self) -> Vec<(String, FormValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.clone(), FormValue::Text(value.clone())))
+            .collect()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Animation and transition end payload.
+#[derive(Clone, Debug)]
+pub(crate) struct SyntheticAnimation {
+    pub name: String,
+}
+
+impl HasAnimationData for SyntheticAnimation {
+    fn animation_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn pseudo_element(&self) -> String {
+        String::new()
+    }
+
+    fn elapsed_time(&self) -> f32 {
+        0.0
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl HasTransitionData for SyntheticAnimation {
+    fn property_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn pseudo_element(&self) -> String {
+        String::new()
+    }
+
+    fn elapsed_time(&self) -> f32 {
+        0.0
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Wraps a synthetic payload the way a renderer would before handing it to the runtime.
+pub(crate) fn platform_event(data: impl Any) -> Rc<dyn Any> {
+    Rc::new(PlatformEventData::new(Box::new(data)))
+}
+
+fn payload<T: Clone + 'static>(event: &PlatformEventData, kind: &str) -> T {
+    event
+        .downcast::<T>()
+        .cloned()
+        .unwrap_or_else(|| panic!("TestDom dispatched an event without {kind} data"))
+}
+
+fn unsupported(kind: &str) -> ! {
+    panic!("TestDom does not synthesize {kind} events")
+}
+
+/// Converts the payloads above into the event types listeners expect.
+pub(crate) struct SyntheticConverter;
+
+impl HtmlEventConverter for SyntheticConverter {
+    fn convert_animation_data(&self, event: &PlatformEventData) -> AnimationData {
+        AnimationData::new(payload::<SyntheticAnimation>(event, "animation"))
+    }
+
+    fn convert_cancel_data(&self, _: &PlatformEventData) -> CancelData {
+        unsupported("cancel")
+    }
+
+    fn convert_clipboard_data(&self, _: &PlatformEventData) -> ClipboardData {
+        unsupported("clipboard")
+    }
+
+    fn convert_composition_data(&self, _: &PlatformEventData) -> CompositionData {
+        unsupported("composition")
+    }
+
+    fn convert_drag_data(&self, _: &PlatformEventData) -> DragData {
+        unsupported("drag")
+    }
+
+    fn convert_focus_data(&self, event: &PlatformEventData) -> FocusData {
+        FocusData::new(payload::<SyntheticFocus>(event, "focus"))
+    }
+
+    fn convert_form_data(&self, event: &PlatformEventData) -> FormData {
+        FormData::new(payload::<SyntheticForm>(event, "form"))
+    }
+
+    fn convert_image_data(&self, _: &PlatformEventData) -> ImageData {
+        unsupported("image")
+    }
+
+    fn convert_keyboard_data(&self, event: &PlatformEventData) -> KeyboardData {
+        KeyboardData::new(payload::<SyntheticKeyboard>(event, "keyboard"))
+    }
+
+    fn convert_media_data(&self, _: &PlatformEventData) -> MediaData {
+        unsupported("media")
+    }
+
+    fn convert_mounted_data(&self, _: &PlatformEventData) -> MountedData {
+        // There is no layout engine behind the test DOM, so every measurement reports
+        // `Unsupported` and components fall back to their unmeasured state.
+        MountedData::new(())
+    }
+
+    fn convert_mouse_data(&self, event: &PlatformEventData) -> MouseData {
+        MouseData::new(payload::<SyntheticPointer>(event, "mouse"))
+    }
+
+    fn convert_pointer_data(&self, event: &PlatformEventData) -> PointerData {
+        PointerData::new(payload::<SyntheticPointer>(event, "pointer"))
+    }
+
+    fn convert_resize_data(&self, _: &PlatformEventData) -> ResizeData {
+        unsupported("resize")
+    }
+
+    fn convert_scroll_data(&self, _: &PlatformEventData) -> ScrollData {
+        unsupported("scroll")
+    }
+
+    fn convert_selection_data(&self, _: &PlatformEventData) -> SelectionData {
+        unsupported("selection")
+    }
+
+    fn convert_toggle_data(&self, _: &PlatformEventData) -> ToggleData {
+        unsupported("toggle")
+    }
+
+    fn convert_touch_data(&self, _: &PlatformEventData) -> TouchData {
+        unsupported("touch")
+    }
+
+    fn convert_transition_data(&self, event: &PlatformEventData) -> TransitionData {
+        TransitionData::new(payload::<SyntheticAnimation>(event, "transition"))
+    }
+
+    fn convert_visible_data(&self, _: &PlatformEventData) -> VisibleData {
+        unsupported("visible")
+    }
+
+    fn convert_wheel_data(&self, _: &PlatformEventData) -> WheelData {
+        unsupported("wheel")
+    }
+}
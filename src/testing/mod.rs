@@ -0,0 +1,1270 @@
+//! # Testing Utilities
+//!
+//! A headless document for driving components in plain `cargo test`, modelled on
+//! [Testing Library](https://testing-library.com/): find elements the way a user or
+//! assistive technology would (by role, label, or text), interact with them, and assert on
+//! the attributes that result.
+//!
+//! [`TestDom`] runs a `VirtualDom`, applies its mutations to an in-memory tree, and
+//! dispatches synthetic events to it. With the `testing` feature the crate's platform
+//! layer works against that tree: global listeners (document `keydown`, form `reset`, ...)
+//! receive the synthetic events too, focus moves, and portals move into their container
+//! when it matches an element. There is no layout or JavaScript behind it, so
+//! measurements report `Unsupported` and scripts run through `document::eval` do nothing.
+//! There is no stylesheet either: an element only animates if its inline `style` sets
+//! `animation-name`/`animation-duration` or `transition-duration`, and the animation ends
+//! when the test calls [`TestDom::animation_end`] or the duration passes.
+//!
+//! Enable the `testing` feature to use it from your own tests. It replaces the platform
+//! backend on non-browser targets, so enable it only as a dev-dependency:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! dioxus_components = { version = "0.1", features = ["testing"] }
+//! ```
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_components::testing::TestDom;
+//! use dioxus_components::*;
+//!
+//! #[component]
+//! fn Terms() -> Element {
+//!     rsx! {
+//!         CheckboxProvider {
+//!             CheckboxTrigger { CheckboxIndicator {} }
+//!             CheckboxLabel { "Accept terms" }
+//!         }
+//!     }
+//! }
+//!
+//! let mut dom = TestDom::new(Terms);
+//! let checkbox = dom.get_by_label("Accept terms");
+//! assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+//!
+//! dom.click(checkbox);
+//! assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("true"));
+//! ```
+
+mod dom;
+mod events;
+mod selector;
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use dioxus::core::{ComponentFunction, Event};
+use dioxus::html::input_data::MouseButton;
+use dioxus::html::{set_event_converter, PlatformEventData};
+use dioxus::prelude::*;
+use futures_util::FutureExt;
+
+use crate::platform::test_host::{self, FocusRequest, HostQuery, HostRequest};
+use crate::platform::{AnimationTiming, Hotkey, ListenerEvent, ListenerTarget};
+
+use dom::{NodeKind, Tree, ROOT};
+use events::{
+    platform_event, SyntheticAnimation, SyntheticConverter, SyntheticFocus, SyntheticForm,
+    SyntheticKeyboard, SyntheticPointer,
+};
+use selector::Selector;
+
+/// Upper bound on render passes per [`TestDom::flush`], to catch components that never settle.
+const MAX_RENDER_PASSES: usize = 100;
+
+/// Handle to an element (or text node) in a [`TestDom`].
+///
+/// Handles stay valid across re-renders; an element removed from the tree reports
+/// `false` from [`TestDom::is_connected`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TestNode(usize);
+
+/// A rendered `VirtualDom` that can be queried and interacted with.
+pub struct TestDom {
+    vdom: VirtualDom,
+    tree: Tree,
+    focused: Option<usize>,
+    /// Focus remembered through `platform::FocusReturn`, by key.
+    saved_focus: HashMap<usize, Option<usize>>,
+}
+
+impl TestDom {
+    /// Renders a root component without props.
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_vdom(VirtualDom::new(app))
+    }
+
+    /// Renders a root component with props.
+    pub fn with_props<P: Clone + 'static, M: 'static>(
+        root: impl ComponentFunction<P, M>,
+        props: P,
+    ) -> Self {
+        Self::from_vdom(VirtualDom::new_with_props(root, props))
+    }
+
+    /// Renders a prebuilt element, e.g. `TestDom::from_element(rsx! { Accordion { .. } })`.
+    ///
+    /// Event handlers written directly in the element are created outside the
+    /// `VirtualDom`; use [`TestDom::new`] with a component when the test needs its own state.
+    pub fn from_element(element: Element) -> Self {
+        fn root(element: Element) -> Element {
+            element
+        }
+        Self::with_props(root, element)
+    }
+
+    fn from_vdom(mut vdom: VirtualDom) -> Self {
+        set_event_converter(Box::new(SyntheticConverter));
+        test_host::install();
+
+        let mut tree = Tree::new();
+        vdom.rebuild(&mut tree);
+
+        let mut dom = Self {
+            vdom,
+            tree,
+            focused: None,
+            saved_focus: HashMap::new(),
+        };
+        dom.flush();
+        dom
+    }
+
+    /// Runs effects, ready tasks and re-renders until nothing is left to do.
+    ///
+    /// Every interaction method flushes on its own; call this after changing state from
+    /// outside, e.g. through [`TestDom::in_runtime`].
+    pub fn flush(&mut self) {
+        for _ in 0..MAX_RENDER_PASSES {
+            self.vdom.process_events();
+            self.vdom.render_immediate(&mut self.tree);
+
+            let mounted = std::mem::take(&mut self.tree.mounted);
+            for &node in &mounted {
+                if self.tree.is_connected(node) {
+                    self.dispatch(node, "mounted", platform_event(()), false);
+                }
+            }
+
+            let requests = test_host::take_requests();
+            for request in &requests {
+                self.apply_request(request);
+            }
+
+            let queries = test_host::take_queries();
+            let answered = !queries.is_empty();
+            for query in queries {
+                self.answer(query);
+            }
+
+            if mounted.is_empty()
+                && requests.is_empty()
+                && !answered
+                && self.vdom.wait_for_work().now_or_never().is_none()
+            {
+                return;
+            }
+        }
+        panic!("TestDom did not settle after {MAX_RENDER_PASSES} render passes");
+    }
+
+    /// Lets `duration` of real time pass while running tasks as they become ready.
+    ///
+    /// Use this for timer-driven behaviour such as tooltip delays; keep the durations in
+    /// tests short.
+    pub fn wait(&mut self, duration: Duration) {
+        let deadline = Instant::now() + duration;
+        loop {
+            self.flush();
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            std::thread::sleep((deadline - now).min(Duration::from_millis(5)));
+        }
+    }
+
+    /// Runs `f` inside the `VirtualDom` runtime, e.g. to read or write a signal.
+    pub fn in_runtime<O>(&self, f: impl FnOnce() -> O) -> O {
+        self.vdom.in_runtime(f)
+    }
+
+    /* ---------------------------------------------------------------------------------------------
+     * Queries
+     * -------------------------------------------------------------------------------------------*/
+
+    /// The single accessible element with `role`, explicit or implicit.
+    ///
+    /// Elements hidden with `aria-hidden="true"`, `hidden` or `display: none` are skipped,
+    /// which is how the hidden form inputs of `Checkbox` stay out of the way.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such element or more than one.
+    #[track_caller]
+    pub fn get_by_role(&self, role: &str) -> TestNode {
+        self.expect_one(self.get_all_by_role(role), &format!("role \"{role}\""))
+    }
+
+    /// Like [`TestDom::get_by_role`], but returns `None` when there is no match.
+    #[track_caller]
+    pub fn query_by_role(&self, role: &str) -> Option<TestNode> {
+        self.at_most_one(self.get_all_by_role(role), &format!("role \"{role}\""))
+    }
+
+    /// Every accessible element with `role`, in document order.
+    pub fn get_all_by_role(&self, role: &str) -> Vec<TestNode> {
+        self.find_all(|dom, node| {
+            dom.role(node).as_deref() == Some(role) && !dom.is_inaccessible(node)
+        })
+    }
+
+    /// The single accessible element with `role` whose accessible name is `name`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such element or more than one.
+    #[track_caller]
+    pub fn get_by_role_named(&self, role: &str, name: &str) -> TestNode {
+        self.expect_one(
+            self.get_all_by_role_named(role, name),
+            &format!("role \"{role}\" and name \"{name}\""),
+        )
+    }
+
+    /// Like [`TestDom::get_by_role_named`], but returns `None` when there is no match.
+    #[track_caller]
+    pub fn query_by_role_named(&self, role: &str, name: &str) -> Option<TestNode> {
+        self.at_most_one(
+            self.get_all_by_role_named(role, name),
+            &format!("role \"{role}\" and name \"{name}\""),
+        )
+    }
+
+    /// Every accessible element with `role` whose accessible name is `name`.
+    pub fn get_all_by_role_named(&self, role: &str, name: &str) -> Vec<TestNode> {
+        self.get_all_by_role(role)
+            .into_iter()
+            .filter(|&node| self.accessible_name(node) == name)
+            .collect()
+    }
+
+    /// The single element labelled `text` through `aria-labelledby`, `aria-label`, or a
+    /// `<label>` (by `for` or by nesting).
+    ///
+    /// # Panics
+    ///
+    /// If there is no such element or more than one.
+    #[track_caller]
+    pub fn get_by_label(&self, text: &str) -> TestNode {
+        self.expect_one(self.get_all_by_label(text), &format!("label \"{text}\""))
+    }
+
+    /// Like [`TestDom::get_by_label`], but returns `None` when there is no match.
+    #[track_caller]
+    pub fn query_by_label(&self, text: &str) -> Option<TestNode> {
+        self.at_most_one(self.get_all_by_label(text), &format!("label \"{text}\""))
+    }
+
+    /// Every element labelled `text`, in document order.
+    pub fn get_all_by_label(&self, text: &str) -> Vec<TestNode> {
+        self.find_all(|dom, node| {
+            dom.tag_of(node) != Some("label")
+                && dom.label_text(node).as_deref() == Some(text)
+                && !dom.is_inaccessible(node)
+        })
+    }
+
+    /// The single element whose own text is `text`, ignoring surrounding whitespace.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such element or more than one.
+    #[track_caller]
+    pub fn get_by_text(&self, text: &str) -> TestNode {
+        self.expect_one(self.get_all_by_text(text), &format!("text \"{text}\""))
+    }
+
+    /// Like [`TestDom::get_by_text`], but returns `None` when there is no match.
+    #[track_caller]
+    pub fn query_by_text(&self, text: &str) -> Option<TestNode> {
+        self.at_most_one(self.get_all_by_text(text), &format!("text \"{text}\""))
+    }
+
+    /// Every element whose own text is `text`, in document order.
+    pub fn get_all_by_text(&self, text: &str) -> Vec<TestNode> {
+        self.find_all(|dom, node| {
+            !matches!(dom.tag_of(node), None | Some("style" | "script"))
+                && normalize(&dom.own_text(node)) == text
+        })
+    }
+
+    /// The single element with `data-testid="{id}"`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such element or more than one.
+    #[track_caller]
+    pub fn get_by_test_id(&self, id: &str) -> TestNode {
+        let matches = self.find_all(|dom, node| dom.attr(node, "data-testid") == Some(id));
+        self.expect_one(matches, &format!("data-testid \"{id}\""))
+    }
+
+    /* ---------------------------------------------------------------------------------------------
+     * Inspection
+     * -------------------------------------------------------------------------------------------*/
+
+    /// The value of an attribute, or `None` when it is absent.
+    pub fn attribute(&self, node: TestNode, name: &str) -> Option<&str> {
+        self.attr(node.0, name)
+    }
+
+    /// Whether an attribute is present, whatever its value.
+    pub fn has_attribute(&self, node: TestNode, name: &str) -> bool {
+        self.attr(node.0, name).is_some()
+    }
+
+    /// The tag name, or `None` for text nodes.
+    pub fn tag(&self, node: TestNode) -> Option<&str> {
+        self.tag_of(node.0)
+    }
+
+    /// The concatenated text of the node and its descendants.
+    pub fn text(&self, node: TestNode) -> String {
+        self.text_content(node.0)
+    }
+
+    /// The accessible name: `aria-labelledby`, `aria-label`, an associated `<label>`, then
+    /// the text content.
+    pub fn accessible_name(&self, node: TestNode) -> String {
+        self.label_text(node.0)
+            .unwrap_or_else(|| normalize(&self.text_content(node.0)))
+    }
+
+    /// Whether the node is still part of the document.
+    pub fn is_connected(&self, node: TestNode) -> bool {
+        self.tree.is_connected(node.0)
+    }
+
+    /// The entries the form would submit, in document order, like `new FormData(form)`.
+    ///
+    /// Covers named `<input>`, `<select>` and `<textarea>` elements inside the form or
+    /// pointing at it with a `form` attribute. Checkboxes and radios count only when
+    /// checked, and disabled controls are skipped.
+    pub fn form_data(&self, form: TestNode) -> Vec<(String, String)> {
+        let mut entries = Vec::new();
+        for node in self.tree.descendants(ROOT) {
+            let Some(tag @ ("input" | "select" | "textarea")) = self.tag_of(node) else {
+                continue;
+            };
+            let Some(name) = self.attr(node, "name") else {
+                continue;
+            };
+            if self.is_disabled(node) || self.owner_form(node) != Some(form.0) {
+                continue;
+            }
+
+            let values = match tag {
+                "input" => match self.attr(node, "type") {
+                    Some("checkbox" | "radio") if self.attr(node, "checked").is_none() => vec![],
+                    Some("checkbox" | "radio") => {
+                        vec![self.attr(node, "value").unwrap_or("on").to_string()]
+                    }
+                    _ => vec![self.attr(node, "value").unwrap_or_default().to_string()],
+                },
+                "select" => {
+                    let options: Vec<usize> = self
+                        .tree
+                        .descendants(node)
+                        .into_iter()
+                        .filter(|&option| self.tag_of(option) == Some("option"))
+                        .collect();
+                    let mut selected: Vec<usize> = options
+                        .iter()
+                        .copied()
+                        .filter(|&option| self.attr(option, "selected").is_some())
+                        .collect();
+                    if selected.is_empty() && self.attr(node, "multiple").is_none() {
+                        selected.extend(options.first());
+                    }
+                    selected
+                        .into_iter()
+                        .map(|option| match self.attr(option, "value") {
+                            Some(value) => value.to_string(),
+                            None => normalize(&self.text_content(option)),
+                        })
+                        .collect()
+                }
+                _ => vec![self.text_content(node)],
+            };
+            entries.extend(values.into_iter().map(|value| (name.to_string(), value)));
+        }
+        entries
+    }
+
+    /// The element that currently has focus, if any.
+    pub fn focused(&self) -> Option<TestNode> {
+        self.focused
+            .filter(|&node| self.tree.is_connected(node))
+            .map(TestNode)
+    }
+
+    /// The whole document serialized as HTML, without `<style>` contents.
+    pub fn html(&self) -> String {
+        let mut out = String::new();
+        for &child in &self.tree.nodes[ROOT].children {
+            self.write_html(child, &mut out);
+        }
+        out
+    }
+
+    /// One node serialized as HTML.
+    pub fn node_html(&self, node: TestNode) -> String {
+        let mut out = String::new();
+        self.write_html(node.0, &mut out);
+        out
+    }
+
+    /* ---------------------------------------------------------------------------------------------
+     * Interaction
+     * -------------------------------------------------------------------------------------------*/
+
+    /// Clicks like a mouse would: pointer and mouse down, focus, pointer and mouse up, then
+    /// `click`. Disabled form controls receive nothing, and clicking a `<label>` also
+    /// clicks its control. As in browsers, preventing `pointerdown` skips the mouse events
+    /// and the focus change, but not `click`.
+    pub fn click(&mut self, node: TestNode) {
+        let node = node.0;
+        if self.is_disabled(node) {
+            return;
+        }
+
+        let pointer = SyntheticPointer::mouse();
+        let mouse_events =
+            self.dispatch(node, "pointerdown", platform_event(pointer.clone()), true);
+        if mouse_events && self.dispatch(node, "mousedown", platform_event(pointer.clone()), true) {
+            match self.focusable_ancestor(node) {
+                Some(target) => self.focus(TestNode(target)),
+                None => self.blur(),
+            }
+        }
+        self.dispatch(node, "pointerup", platform_event(pointer.clone()), true);
+        if mouse_events {
+            self.dispatch(node, "mouseup", platform_event(pointer.clone()), true);
+        }
+
+        if self.dispatch(node, "click", platform_event(pointer), true) {
+            if let Some(control) = self.label_control(node) {
+                if !self.ancestors(node).any(|ancestor| ancestor == control) {
+                    self.click(TestNode(control));
+                }
+            }
+        }
+    }
+
+    /// Moves the mouse onto the node.
+    pub fn hover(&mut self, node: TestNode) {
+        let pointer = SyntheticPointer {
+            button: None,
+            ..SyntheticPointer::mouse()
+        };
+        self.dispatch(node.0, "pointerover", platform_event(pointer.clone()), true);
+        self.dispatch(
+            node.0,
+            "pointerenter",
+            platform_event(pointer.clone()),
+            false,
+        );
+        self.dispatch(node.0, "mouseover", platform_event(pointer.clone()), true);
+        self.dispatch(node.0, "mouseenter", platform_event(pointer), false);
+    }
+
+    /// Moves the mouse off the node.
+    pub fn unhover(&mut self, node: TestNode) {
+        let pointer = SyntheticPointer {
+            button: None,
+            ..SyntheticPointer::mouse()
+        };
+        self.dispatch(node.0, "pointerout", platform_event(pointer.clone()), true);
+        self.dispatch(
+            node.0,
+            "pointerleave",
+            platform_event(pointer.clone()),
+            false,
+        );
+        self.dispatch(node.0, "mouseout", platform_event(pointer.clone()), true);
+        self.dispatch(node.0, "mouseleave", platform_event(pointer), false);
+    }
+
+    /// Drags a finger across the node: a touch `pointerdown`, `pointermove`s and a
+    /// `pointerup` `dx`/`dy` CSS pixels away from where it started.
+    pub fn swipe(&mut self, node: TestNode, dx: f64, dy: f64) {
+        let touch = SyntheticPointer {
+            pointer_type: "touch",
+            ..SyntheticPointer::mouse()
+        };
+        self.dispatch(node.0, "pointerdown", platform_event(touch.clone()), true);
+        for step in [0.5, 1.0] {
+            let moved = SyntheticPointer {
+                x: dx * step,
+                y: dy * step,
+                ..touch.clone()
+            };
+            self.dispatch(node.0, "pointermove", platform_event(moved), true);
+        }
+        let lifted = SyntheticPointer {
+            button: None,
+            x: dx,
+            y: dy,
+            ..touch
+        };
+        self.dispatch(node.0, "pointerup", platform_event(lifted), true);
+    }
+
+    /// Right-clicks the node at `x`/`y` (client coordinates): pointer and mouse down with
+    /// the secondary button, focus, pointer and mouse up, then `contextmenu`.
+    pub fn context_menu(&mut self, node: TestNode, x: f64, y: f64) {
+        let node = node.0;
+        let pointer = SyntheticPointer {
+            button: Some(MouseButton::Secondary),
+            x,
+            y,
+            ..SyntheticPointer::mouse()
+        };
+        let mouse_events =
+            self.dispatch(node, "pointerdown", platform_event(pointer.clone()), true);
+        if mouse_events && self.dispatch(node, "mousedown", platform_event(pointer.clone()), true) {
+            match self.focusable_ancestor(node) {
+                Some(target) => self.focus(TestNode(target)),
+                None => self.blur(),
+            }
+        }
+        self.dispatch(node, "pointerup", platform_event(pointer.clone()), true);
+        if mouse_events {
+            self.dispatch(node, "mouseup", platform_event(pointer.clone()), true);
+        }
+        self.dispatch(node, "contextmenu", platform_event(pointer), true);
+    }
+
+    /// Presses a finger on the node at `x`/`y` (client coordinates), holds it still for
+    /// `duration` while timers run, then lifts it.
+    pub fn long_press(&mut self, node: TestNode, x: f64, y: f64, duration: Duration) {
+        let touch = SyntheticPointer {
+            pointer_type: "touch",
+            x,
+            y,
+            ..SyntheticPointer::mouse()
+        };
+        self.dispatch(node.0, "pointerdown", platform_event(touch.clone()), true);
+        self.wait(duration);
+        let lifted = SyntheticPointer {
+            button: None,
+            ..touch
+        };
+        self.dispatch(node.0, "pointerup", platform_event(lifted), true);
+    }
+
+    /// Focuses the node if it is focusable, blurring the previously focused element.
+    pub fn focus(&mut self, node: TestNode) {
+        if !self.is_focusable(node.0) || self.focused() == Some(node) {
+            return;
+        }
+        self.blur();
+        self.focused = Some(node.0);
+        self.dispatch(node.0, "focus", platform_event(SyntheticFocus), false);
+        self.dispatch(node.0, "focusin", platform_event(SyntheticFocus), true);
+    }
+
+    /// Moves focus back to the document body.
+    pub fn blur(&mut self) {
+        if let Some(TestNode(node)) = self.focused() {
+            self.focused = None;
+            self.dispatch(node, "blur", platform_event(SyntheticFocus), false);
+            self.dispatch(node, "focusout", platform_event(SyntheticFocus), true);
+        }
+        self.focused = None;
+    }
+
+    /// Presses and releases a key on the focused element.
+    ///
+    /// `key` is a [`KeyboardEvent.key`](https://developer.mozilla.org/docs/Web/API/KeyboardEvent/key)
+    /// value, optionally prefixed with modifiers: `"Escape"`, `"a"`, `"Shift+Tab"`,
+    /// `"Control+k"`. Unless a handler prevents the default, `Tab` moves focus and
+    /// `Enter` or `" "` clicks a focused button.
+    pub fn press(&mut self, key: &str) {
+        let keyboard = parse_key(key);
+        let target = self.focused().map(|node| node.0);
+
+        let default = self.key_event(target.unwrap_or(ROOT), "keydown", &keyboard);
+
+        if default {
+            if keyboard.key == Key::Tab {
+                self.move_focus(!keyboard.modifiers.contains(Modifiers::SHIFT));
+            } else if let Some(node) = target.filter(|&node| self.activates_on(node, &keyboard.key))
+            {
+                self.click(TestNode(node));
+            }
+        }
+
+        let target = self.focused().map(|node| node.0);
+        self.key_event(target.unwrap_or(ROOT), "keyup", &keyboard);
+    }
+
+    /// Dispatches a single `keydown` to the node without any default action. Returns
+    /// `false` if a handler called `prevent_default`.
+    pub fn key_down(&mut self, node: TestNode, key: &str) -> bool {
+        self.key_event(node.0, "keydown", &parse_key(key))
+    }
+
+    /// Sets the value of a form control and fires `input` and `change`.
+    pub fn input(&mut self, node: TestNode, value: &str) {
+        if let NodeKind::Element { attributes, .. } = &mut self.tree.nodes[node.0].kind {
+            attributes.retain(|(name, _)| name != "value");
+            attributes.push(("value".to_string(), value.to_string()));
+        }
+        let form = SyntheticForm {
+            value: value.to_string(),
+            values: Vec::new(),
+        };
+        self.dispatch(node.0, "input", platform_event(form.clone()), true);
+        self.dispatch(node.0, "change", platform_event(form), true);
+    }
+
+    /// Fires `reset` on a form, as a reset button or `form.reset()` does.
+    pub fn reset(&mut self, form: TestNode) {
+        let form_data = SyntheticForm {
+            value: String::new(),
+            values: Vec::new(),
+        };
+        self.dispatch(form.0, "reset", platform_event(form_data), true);
+    }
+
+    /// Fires `animationend`, as the browser does when a CSS animation finishes.
+    pub fn animation_end(&mut self, node: TestNode) {
+        let animation = SyntheticAnimation {
+            name: String::new(),
+        };
+        self.dispatch(node.0, "animationend", platform_event(animation), true);
+    }
+
+    /// Fires `transitionend`, as the browser does when a CSS transition finishes.
+    pub fn transition_end(&mut self, node: TestNode) {
+        let transition = SyntheticAnimation {
+            name: String::new(),
+        };
+        self.dispatch(node.0, "transitionend", platform_event(transition), true);
+    }
+
+    /* ---------------------------------------------------------------------------------------------
+     * Internals
+     * -------------------------------------------------------------------------------------------*/
+
+    /// Dispatches to capturing global listeners, the nearest node the VirtualDom listens
+    /// on, then bubbling global listeners, flushing after each. Returns whether the
+    /// default action is still enabled.
+    fn dispatch(&mut self, node: usize, name: &str, data: Rc<dyn Any>, bubbles: bool) -> bool {
+        let mut default = self.dispatch_global(node, name, &data, true, bubbles);
+
+        // Like the web renderer, start from the nearest listening element: the VirtualDom
+        // can't bubble from a static node, such as a template root passed as children
+        let target = std::iter::once(node)
+            .chain(self.ancestors(node))
+            .filter(|&node| self.tree.has_listeners(node))
+            .find_map(|node| self.tree.element_id(node));
+        if let Some(target) = target {
+            let event = Event::new(data.clone(), bubbles);
+            self.vdom
+                .runtime()
+                .handle_event(name, event.clone(), target);
+            default &= event.default_action_enabled();
+            self.flush();
+        }
+
+        default &= self.dispatch_global(node, name, &data, false, bubbles);
+        default
+    }
+
+    /// Delivers an event to the platform-layer listeners on its path.
+    fn dispatch_global(
+        &mut self,
+        node: usize,
+        name: &str,
+        data: &Rc<dyn Any>,
+        capture: bool,
+        bubbles: bool,
+    ) -> bool {
+        let listeners = test_host::listeners(&self.vdom.runtime(), name);
+        if listeners.is_empty() {
+            return true;
+        }
+
+        // Non-bubbling events only reach the target itself outside the capture phase
+        let propagates = capture || bubbles;
+        let path: Vec<usize> = if propagates {
+            std::iter::once(node).chain(self.ancestors(node)).collect()
+        } else {
+            vec![node]
+        };
+        let event = ListenerEvent {
+            target_id: self.attr(node, "id").unwrap_or_default().to_string(),
+            target_ids: std::iter::once(node)
+                .chain(self.ancestors(node))
+                .filter_map(|node| self.attr(node, "id"))
+                .map(str::to_string)
+                .collect(),
+            ..listener_event(data)
+        };
+
+        let mut default = true;
+        let mut delivered = false;
+        for listener in listeners {
+            if listener.options.capture != capture {
+                continue;
+            }
+            let on_path = match &listener.target {
+                ListenerTarget::Window | ListenerTarget::Document => propagates,
+                ListenerTarget::Element(id) => self.by_id(id).is_some_and(|n| path.contains(&n)),
+                ListenerTarget::FormOf(id) => self
+                    .by_id(id)
+                    .and_then(|n| self.owner_form(n))
+                    .is_some_and(|n| path.contains(&n)),
+            };
+            let keys = listener.options.keys;
+            if !on_path || (!keys.is_empty() && !keys.contains(&event.key.as_str())) {
+                continue;
+            }
+            let hotkey = listener.options.hotkey.and_then(Hotkey::parse);
+            if hotkey.is_some_and(|hotkey| !hotkey.matches(&event)) {
+                continue;
+            }
+            if listener.options.prevent_default {
+                default = false;
+            }
+            delivered |= listener.sender.unbounded_send(event.clone()).is_ok();
+        }
+
+        if delivered {
+            self.flush();
+        }
+        default
+    }
+
+    fn key_event(&mut self, node: usize, name: &str, keyboard: &SyntheticKeyboard) -> bool {
+        self.dispatch(node, name, platform_event(keyboard.clone()), true)
+    }
+
+    /// The animation timing declared in the node's inline `style`. There is no stylesheet,
+    /// so this is the only place an animation can come from.
+    fn animation_timing(&self, node: usize) -> AnimationTiming {
+        let mut timing = AnimationTiming::default();
+        for declaration in self.attr(node, "style").unwrap_or_default().split(';') {
+            let Some((property, value)) = declaration.split_once(':') else {
+                continue;
+            };
+            let value = value.trim().to_string();
+            match property.trim() {
+                "animation-name" => timing.animation_name = value,
+                "animation-duration" => timing.animation_duration = value,
+                "animation-delay" => timing.animation_delay = value,
+                "transition-duration" => timing.transition_duration = value,
+                "transition-delay" => timing.transition_delay = value,
+                _ => {}
+            }
+        }
+        timing
+    }
+
+    /// Answers a question the platform layer asked about the document.
+    fn answer(&self, query: HostQuery) {
+        match query {
+            HostQuery::AnimationTiming { id, reply } => {
+                let timing = self.by_id(&id).map(|node| self.animation_timing(node));
+                let _ = reply.send(timing);
+            }
+            HostQuery::DocumentOrder { ids, reply } => {
+                let order = self
+                    .tree
+                    .descendants(ROOT)
+                    .into_iter()
+                    .filter_map(|node| self.attr(node, "id"))
+                    .filter(|id| ids.iter().any(|wanted| wanted == id))
+                    .map(str::to_string)
+                    .collect();
+                let _ = reply.send(order);
+            }
+        }
+    }
+
+    /// Applies a document change requested through the platform layer.
+    fn apply_request(&mut self, request: &HostRequest) {
+        match request {
+            HostRequest::Focus(request) => self.apply_focus_request(request),
+            HostRequest::MovePortal { id, container } => {
+                let (Some(node), Some(container)) =
+                    (self.by_id(id), self.query_selector(container))
+                else {
+                    return;
+                };
+                // Like `appendChild`, an element can't move into itself
+                if container != node && !self.ancestors(container).any(|ancestor| ancestor == node)
+                {
+                    self.tree.append(container, node);
+                }
+            }
+            HostRequest::RemovePortal { id } => {
+                if let Some(node) = self.by_id(id) {
+                    self.tree.detach(node);
+                }
+            }
+            HostRequest::DispatchEvents { selector, events } => {
+                let Some(node) = self.query_selector(selector) else {
+                    return;
+                };
+                // Only form controls are dispatched to, for `input` and `change`
+                let form = SyntheticForm {
+                    value: self.attr(node, "value").unwrap_or_default().to_string(),
+                    values: Vec::new(),
+                };
+                for &event in events {
+                    self.dispatch(node, event, platform_event(form.clone()), true);
+                }
+            }
+        }
+    }
+
+    /// Applies a focus change requested through the platform layer.
+    fn apply_focus_request(&mut self, request: &FocusRequest) {
+        match request {
+            FocusRequest::Element(id) => {
+                if let Some(node) = self.by_id(id) {
+                    self.focus(TestNode(node));
+                }
+            }
+            FocusRequest::First { container } => {
+                let Some(container) = self.by_id(container) else {
+                    return;
+                };
+                let target = self.tabbables_in(container).first().copied();
+                self.focus(TestNode(target.unwrap_or(container)));
+            }
+            FocusRequest::Next {
+                container,
+                forward,
+                wrap,
+            } => {
+                let Some(container) = self.by_id(container) else {
+                    return;
+                };
+                let items = self.tabbables_in(container);
+                if items.is_empty() {
+                    self.focus(TestNode(container));
+                    return;
+                }
+                let len = items.len() as isize;
+                let current = self
+                    .focused()
+                    .and_then(|focused| items.iter().position(|&node| node == focused.0));
+                let next = match current {
+                    None if *forward => 0,
+                    None => len - 1,
+                    Some(index) => {
+                        let next = index as isize + if *forward { 1 } else { -1 };
+                        match (next < 0 || next >= len, wrap) {
+                            (false, _) => next,
+                            (true, true) => (next + len) % len,
+                            (true, false) => index as isize,
+                        }
+                    }
+                };
+                self.focus(TestNode(items[next as usize]));
+            }
+            FocusRequest::Capture(key) => {
+                self.saved_focus
+                    .insert(*key, self.focused().map(|node| node.0));
+            }
+            FocusRequest::Restore(key) => {
+                if let Some(&Some(node)) = self.saved_focus.get(key) {
+                    if self.tree.is_connected(node) {
+                        self.focus(TestNode(node));
+                    }
+                }
+            }
+            FocusRequest::Forget(key) => {
+                self.saved_focus.remove(key);
+            }
+        }
+    }
+
+    /// The first element matching a CSS selector, like `document.querySelector`. Only the
+    /// selectors the platform layer uses are supported; anything else matches nothing.
+    fn query_selector(&self, selector: &str) -> Option<usize> {
+        let selector = Selector::parse(selector)?;
+        std::iter::once(ROOT)
+            .chain(self.tree.descendants(ROOT))
+            .find(|&node| selector.matches(self, node))
+    }
+
+    fn tabbables_in(&self, container: usize) -> Vec<usize> {
+        self.tree
+            .descendants(container)
+            .into_iter()
+            .filter(|&node| self.is_tabbable(node))
+            .collect()
+    }
+
+    fn move_focus(&mut self, forward: bool) {
+        let tabbable: Vec<usize> = self
+            .tree
+            .descendants(ROOT)
+            .into_iter()
+            .filter(|&node| self.is_tabbable(node))
+            .collect();
+        if tabbable.is_empty() {
+            return;
+        }
+
+        let current = self
+            .focused()
+            .and_then(|focused| tabbable.iter().position(|&node| node == focused.0));
+        let next = match (current, forward) {
+            (None, true) => 0,
+            (None, false) => tabbable.len() - 1,
+            (Some(index), true) => (index + 1) % tabbable.len(),
+            (Some(index), false) => (index + tabbable.len() - 1) % tabbable.len(),
+        };
+        self.focus(TestNode(tabbable[next]));
+    }
+
+    fn find_all(&self, matches: impl Fn(&Self, usize) -> bool) -> Vec<TestNode> {
+        self.tree
+            .descendants(ROOT)
+            .into_iter()
+            .filter(|&node| matches(self, node))
+            .map(TestNode)
+            .collect()
+    }
+
+    #[track_caller]
+    fn expect_one(&self, matches: Vec<TestNode>, description: &str) -> TestNode {
+        match self.at_most_one(matches, description) {
+            Some(node) => node,
+            None => panic!("no element with {description}\n\n{}", self.html()),
+        }
+    }
+
+    #[track_caller]
+    fn at_most_one(&self, matches: Vec<TestNode>, description: &str) -> Option<TestNode> {
+        if matches.len() > 1 {
+            panic!(
+                "found {} elements with {description}\n\n{}",
+                matches.len(),
+                self.html()
+            );
+        }
+        matches.into_iter().next()
+    }
+
+    fn ancestors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.tree.nodes[node].parent, |&node| {
+            self.tree.nodes[node].parent
+        })
+    }
+
+    fn tag_of(&self, node: usize) -> Option<&str> {
+        match &self.tree.nodes[node].kind {
+            NodeKind::Element { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    fn attr(&self, node: usize, name: &str) -> Option<&str> {
+        match &self.tree.nodes[node].kind {
+            NodeKind::Element { attributes, .. } => attributes
+                .iter()
+                .rev()
+                .find(|(attribute, _)| attribute == name)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+
+    fn style(&self, node: usize, property: &str) -> Option<String> {
+        let NodeKind::Element { styles, .. } = &self.tree.nodes[node].kind else {
+            return None;
+        };
+        if let Some((_, value)) = styles.iter().find(|(name, _)| name == property) {
+            return Some(value.clone());
+        }
+        self.attr(node, "style")?
+            .split(';')
+            .find_map(|declaration| {
+                let (name, value) = declaration.split_once(':')?;
+                (name.trim() == property).then(|| value.trim().to_string())
+            })
+    }
+
+    fn own_text(&self, node: usize) -> String {
+        self.tree.nodes[node]
+            .children
+            .iter()
+            .filter_map(|&child| match &self.tree.nodes[child].kind {
+                NodeKind::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn text_content(&self, node: usize) -> String {
+        match &self.tree.nodes[node].kind {
+            NodeKind::Text(text) => text.clone(),
+            NodeKind::Placeholder => String::new(),
+            NodeKind::Element { tag, .. } if tag == "style" || tag == "script" => String::new(),
+            NodeKind::Element { .. } => self.tree.nodes[node]
+                .children
+                .iter()
+                .map(|&child| self.text_content(child))
+                .collect(),
+        }
+    }
+
+    fn role(&self, node: usize) -> Option<String> {
+        if let Some(role) = self.attr(node, "role") {
+            return role.split_whitespace().next().map(str::to_string);
+        }
+
+        let role = match self.tag_of(node)? {
+            "button" | "summary" => "button",
+            "a" if self.attr(node, "href").is_some() => "link",
+            "input" => match self.attr(node, "type").unwrap_or("text") {
+                "checkbox" => "checkbox",
+                "radio" => "radio",
+                "range" => "slider",
+                "number" => "spinbutton",
+                "search" => "searchbox",
+                "button" | "submit" | "reset" | "image" => "button",
+                "hidden" | "file" | "color" | "date" | "time" => return None,
+                _ => "textbox",
+            },
+            "select" if self.attr(node, "multiple").is_some() => "listbox",
+            "select" => "combobox",
+            "option" => "option",
+            "textarea" => "textbox",
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+            "ul" | "ol" => "list",
+            "li" => "listitem",
+            "nav" => "navigation",
+            "main" => "main",
+            "dialog" => "dialog",
+            "form" => "form",
+            "hr" => "separator",
+            "img" if self.attr(node, "alt").is_some_and(|alt| !alt.is_empty()) => "img",
+            "progress" => "progressbar",
+            _ => return None,
+        };
+        Some(role.to_string())
+    }
+
+    fn by_id(&self, id: &str) -> Option<usize> {
+        self.tree
+            .descendants(ROOT)
+            .into_iter()
+            .find(|&node| self.attr(node, "id") == Some(id))
+    }
+
+    /// The explicit label of a node, if it has one.
+    /// The form named by the node's `form` attribute, otherwise its nearest ancestor form.
+    fn owner_form(&self, node: usize) -> Option<usize> {
+        match self.attr(node, "form") {
+            Some(id) => self.by_id(id),
+            None => self
+                .ancestors(node)
+                .find(|&ancestor| self.tag_of(ancestor) == Some("form")),
+        }
+    }
+
+    fn label_text(&self, node: usize) -> Option<String> {
+        if let Some(ids) = self.attr(node, "aria-labelledby") {
+            let text: Vec<String> = ids
+                .split_whitespace()
+                .filter_map(|id| self.by_id(id))
+                .map(|label| normalize(&self.text_content(label)))
+                .collect();
+            return Some(text.join(" "));
+        }
+        if let Some(label) = self.attr(node, "aria-label") {
+            return Some(normalize(label));
+        }
+
+        let id = self.attr(node, "id");
+        self.tree
+            .descendants(ROOT)
+            .into_iter()
+            .filter(|&label| self.tag_of(label) == Some("label"))
+            .find(|&label| {
+                let target = self.attr(label, "for");
+                (target.is_some() && target == id) || self.label_control(label) == Some(node)
+            })
+            .map(|label| normalize(&self.text_content(label)))
+    }
+
+    /// The control a `<label>` at or above `node` points at.
+    fn label_control(&self, node: usize) -> Option<usize> {
+        let label = std::iter::once(node)
+            .chain(self.ancestors(node))
+            .find(|&node| self.tag_of(node) == Some("label"))?;
+        match self.attr(label, "for") {
+            Some(id) => self.by_id(id),
+            None => self.tree.descendants(label).into_iter().find(|&node| {
+                matches!(
+                    self.tag_of(node),
+                    Some("button" | "input" | "select" | "textarea")
+                )
+            }),
+        }
+    }
+
+    fn is_inaccessible(&self, node: usize) -> bool {
+        std::iter::once(node)
+            .chain(self.ancestors(node))
+            .any(|node| {
+                self.attr(node, "aria-hidden") == Some("true")
+                    || self.attr(node, "hidden").is_some()
+                    || self.style(node, "display").as_deref() == Some("none")
+            })
+    }
+
+    fn is_disabled(&self, node: usize) -> bool {
+        std::iter::once(node)
+            .chain(self.ancestors(node))
+            .any(|node| {
+                matches!(
+                    self.tag_of(node),
+                    Some("button" | "input" | "select" | "textarea" | "fieldset")
+                ) && self.attr(node, "disabled").is_some()
+            })
+    }
+
+    fn is_focusable(&self, node: usize) -> bool {
+        if !self.tree.is_connected(node) || self.is_disabled(node) || self.is_inaccessible(node) {
+            return false;
+        }
+        if self.attr(node, "tabindex").is_some() {
+            return true;
+        }
+        match self.tag_of(node) {
+            Some("button" | "select" | "textarea") => true,
+            Some("input") => self.attr(node, "type") != Some("hidden"),
+            Some("a") => self.attr(node, "href").is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_tabbable(&self, node: usize) -> bool {
+        self.is_focusable(node)
+            && self
+                .attr(node, "tabindex")
+                .and_then(|index| index.parse::<i32>().ok())
+                .is_none_or(|index| index >= 0)
+    }
+
+    fn focusable_ancestor(&self, node: usize) -> Option<usize> {
+        std::iter::once(node)
+            .chain(self.ancestors(node))
+            .find(|&node| self.is_focusable(node))
+    }
+
+    fn activates_on(&self, node: usize, key: &Key) -> bool {
+        let is_button =
+            self.tag_of(node) == Some("button") || self.role(node).as_deref() == Some("button");
+        match key {
+            Key::Enter => is_button || self.tag_of(node) == Some("a"),
+            Key::Character(character) => character == " " && is_button,
+            _ => false,
+        }
+    }
+
+    fn write_html(&self, node: usize, out: &mut String) {
+        match &self.tree.nodes[node].kind {
+            NodeKind::Text(text) => out.push_str(text),
+            NodeKind::Placeholder => {}
+            NodeKind::Element {
+                tag,
+                attributes,
+                styles,
+                ..
+            } => {
+                out.push('<');
+                out.push_str(tag);
+                for (name, value) in attributes {
+                    out.push_str(&format!(" {name}=\"{value}\""));
+                }
+                if !styles.is_empty() {
+                    let styles: String = styles
+                        .iter()
+                        .map(|(name, value)| format!("{name}: {value};"))
+                        .collect();
+                    out.push_str(&format!(" style=\"{styles}\""));
+                }
+                out.push('>');
+                if tag != "style" && tag != "script" {
+                    for &child in &self.tree.nodes[node].children {
+                        self.write_html(child, out);
+                    }
+                }
+                out.push_str(&format!("</{tag}>"));
+            }
+        }
+    }
+}
+
+/// What a platform-layer listener sees of a synthetic event.
+fn listener_event(data: &Rc<dyn Any>) -> ListenerEvent {
+    let keyboard = data
+        .downcast_ref::<PlatformEventData>()
+        .and_then(|event| event.downcast::<SyntheticKeyboard>());
+    match keyboard {
+        Some(keyboard) => ListenerEvent {
+            key: keyboard.key.to_string(),
+            shift_key: keyboard.modifiers.contains(Modifiers::SHIFT),
+            ctrl_key: keyboard.modifiers.contains(Modifiers::CONTROL),
+            alt_key: keyboard.modifiers.contains(Modifiers::ALT),
+            meta_key: keyboard.modifiers.contains(Modifiers::META),
+            ..ListenerEvent::default()
+        },
+        None => ListenerEvent::default(),
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_key(input: &str) -> SyntheticKeyboard {
+    let mut modifiers = Modifiers::empty();
+    let mut key = input;
+    while let Some((modifier, rest)) = key.split_once('+') {
+        let flag = match modifier {
+            "Shift" => Modifiers::SHIFT,
+            "Control" | "Ctrl" => Modifiers::CONTROL,
+            "Alt" => Modifiers::ALT,
+            "Meta" => Modifiers::META,
+            _ => break,
+        };
+        if rest.is_empty() {
+            break;
+        }
+        modifiers |= flag;
+        key = rest;
+    }
+
+    SyntheticKeyboard {
+        key: key
+            .parse()
+            .unwrap_or_else(|_| Key::Character(key.to_string())),
+        modifiers,
+    }
+}
@@ -0,0 +1,261 @@
+//! Just enough CSS selectors for the platform layer's `querySelector` calls: compound
+//! selectors made of `tag`, `*`, `#id`, `.class`, `[attr]` and `[attr="value"]`, joined by
+//! descendant, child (`>`) and next-sibling (`+`) combinators, in comma-separated lists.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::dom::NodeKind;
+use super::TestDom;
+
+#[derive(Debug, PartialEq)]
+enum Simple {
+    Universal,
+    Tag(String),
+    Id(String),
+    Class(String),
+    Attribute(String, Option<String>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+}
+
+/// Compound selectors from left to right, each but the first with the combinator that
+/// links it to the one before.
+#[derive(Debug, PartialEq)]
+struct Complex(Vec<(Option<Combinator>, Vec<Simple>)>);
+
+/// A parsed selector list.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Selector(Vec<Complex>);
+
+impl Selector {
+    /// Parses `input`, or returns `None` for anything unsupported or invalid, which
+    /// `querySelector` would reject or this module doesn't understand.
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut chars = input.chars().peekable();
+        let mut list = Vec::new();
+        loop {
+            list.push(parse_complex(&mut chars)?);
+            match chars.next() {
+                None => return Some(Self(list)),
+                Some(',') => continue,
+                Some(_) => return None,
+            }
+        }
+    }
+
+    /// Whether the element matches any selector in the list.
+    pub fn matches(&self, dom: &TestDom, node: usize) -> bool {
+        self.0
+            .iter()
+            .any(|complex| complex.matches(dom, complex.0.len() - 1, node))
+    }
+}
+
+impl Complex {
+    fn matches(&self, dom: &TestDom, index: usize, node: usize) -> bool {
+        let (combinator, compound) = &self.0[index];
+        if !compound.iter().all(|simple| simple.matches(dom, node)) {
+            return false;
+        }
+        match combinator {
+            None => true,
+            Some(Combinator::Child) => dom.tree.nodes[node]
+                .parent
+                .is_some_and(|parent| self.matches(dom, index - 1, parent)),
+            Some(Combinator::Descendant) => dom
+                .ancestors(node)
+                .any(|ancestor| self.matches(dom, index - 1, ancestor)),
+            Some(Combinator::NextSibling) => previous_element(dom, node)
+                .is_some_and(|sibling| self.matches(dom, index - 1, sibling)),
+        }
+    }
+}
+
+impl Simple {
+    fn matches(&self, dom: &TestDom, node: usize) -> bool {
+        let Some(tag) = dom.tag_of(node) else {
+            return false;
+        };
+        match self {
+            Simple::Universal => true,
+            Simple::Tag(name) => tag.eq_ignore_ascii_case(name),
+            Simple::Id(id) => dom.attr(node, "id") == Some(id.as_str()),
+            Simple::Class(class) => dom
+                .attr(node, "class")
+                .is_some_and(|classes| classes.split_whitespace().any(|c| c == class)),
+            Simple::Attribute(name, None) => dom.attr(node, name).is_some(),
+            Simple::Attribute(name, Some(value)) => dom.attr(node, name) == Some(value.as_str()),
+        }
+    }
+}
+
+/// The element right before `node` among its parent's children.
+fn previous_element(dom: &TestDom, node: usize) -> Option<usize> {
+    let parent = dom.tree.nodes[node].parent?;
+    let siblings = &dom.tree.nodes[parent].children;
+    let position = siblings.iter().position(|&sibling| sibling == node)?;
+    siblings[..position]
+        .iter()
+        .rev()
+        .copied()
+        .find(|&sibling| matches!(dom.tree.nodes[sibling].kind, NodeKind::Element { .. }))
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) -> bool {
+    let mut skipped = false;
+    while chars.next_if(|c| c.is_whitespace()).is_some() {
+        skipped = true;
+    }
+    skipped
+}
+
+fn parse_complex(chars: &mut Peekable<Chars>) -> Option<Complex> {
+    skip_whitespace(chars);
+    let mut compounds = vec![(None, parse_compound(chars)?)];
+    loop {
+        let spaced = skip_whitespace(chars);
+        let combinator = match chars.peek() {
+            None | Some(',') => return Some(Complex(compounds)),
+            Some('>') => Combinator::Child,
+            Some('+') => Combinator::NextSibling,
+            Some(_) if spaced => Combinator::Descendant,
+            Some(_) => return None,
+        };
+        if combinator != Combinator::Descendant {
+            chars.next();
+            skip_whitespace(chars);
+        }
+        compounds.push((Some(combinator), parse_compound(chars)?));
+    }
+}
+
+fn parse_compound(chars: &mut Peekable<Chars>) -> Option<Vec<Simple>> {
+    let mut compound = Vec::new();
+    while let Some(&c) = chars.peek() {
+        let simple = match c {
+            '*' => {
+                chars.next();
+                Simple::Universal
+            }
+            '#' => {
+                chars.next();
+                Simple::Id(parse_ident(chars)?)
+            }
+            '.' => {
+                chars.next();
+                Simple::Class(parse_ident(chars)?)
+            }
+            '[' => {
+                chars.next();
+                parse_attribute(chars)?
+            }
+            c if is_ident_char(c) || c == '\\' => Simple::Tag(parse_ident(chars)?),
+            _ => break,
+        };
+        compound.push(simple);
+    }
+    (!compound.is_empty()).then_some(compound)
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
+
+fn parse_ident(chars: &mut Peekable<Chars>) -> Option<String> {
+    let mut ident = String::new();
+    loop {
+        match chars.peek() {
+            Some('\\') => {
+                chars.next();
+                ident.push(chars.next()?);
+            }
+            Some(&c) if is_ident_char(c) => {
+                chars.next();
+                ident.push(c);
+            }
+            _ => break,
+        }
+    }
+    (!ident.is_empty()).then_some(ident)
+}
+
+fn parse_attribute(chars: &mut Peekable<Chars>) -> Option<Simple> {
+    skip_whitespace(chars);
+    let name = parse_ident(chars)?;
+    skip_whitespace(chars);
+    let value = match chars.next()? {
+        ']' => return Some(Simple::Attribute(name, None)),
+        '=' => {
+            skip_whitespace(chars);
+            match chars.peek()? {
+                &quote @ ('"' | '\'') => {
+                    chars.next();
+                    let mut value = String::new();
+                    loop {
+                        match chars.next()? {
+                            '\\' => value.push(chars.next()?),
+                            c if c == quote => break,
+                            c => value.push(c),
+                        }
+                    }
+                    value
+                }
+                _ => parse_ident(chars)?,
+            }
+        }
+        _ => return None,
+    };
+    skip_whitespace(chars);
+    (chars.next()? == ']').then_some(Simple::Attribute(name, Some(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_compounds_and_combinators() {
+        let selector = Selector::parse(r#"form > [id="a \"b\""] + input.hidden, #main"#).unwrap();
+        assert_eq!(
+            selector,
+            Selector(vec![
+                Complex(vec![
+                    (None, vec![Simple::Tag("form".to_string())]),
+                    (
+                        Some(Combinator::Child),
+                        vec![Simple::Attribute(
+                            "id".to_string(),
+                            Some(r#"a "b""#.to_string())
+                        )]
+                    ),
+                    (
+                        Some(Combinator::NextSibling),
+                        vec![
+                            Simple::Tag("input".to_string()),
+                            Simple::Class("hidden".to_string())
+                        ]
+                    ),
+                ]),
+                Complex(vec![(None, vec![Simple::Id("main".to_string())])]),
+            ])
+        );
+        assert_eq!(
+            Selector::parse("body .panel").unwrap().0[0].0[1].0,
+            Some(Combinator::Descendant)
+        );
+    }
+
+    #[test]
+    fn rejects_what_it_does_not_support() {
+        assert_eq!(Selector::parse(""), None);
+        assert_eq!(Selector::parse("div:hover"), None);
+        assert_eq!(Selector::parse("a ~ b"), None);
+        assert_eq!(Selector::parse(r#"[id="open"#), None);
+    }
+}
@@ -0,0 +1,62 @@
+//! Helpers for components that spread caller attributes onto an element of their own.
+
+use dioxus::core::AttributeValue;
+use dioxus::html::PlatformEventData;
+use dioxus::prelude::*;
+
+/// Removes the caller's `id` from `attributes` and returns it, or `fallback` without one.
+///
+/// Components that need their element's id should use the caller's, since other components
+/// may point at it (`aria-controls`, `<label for>`).
+pub(crate) fn take_id_attribute(attributes: &mut Vec<Attribute>, fallback: String) -> String {
+    let id = attributes
+        .iter()
+        .find(|attribute| attribute.name == "id")
+        .and_then(|attribute| match &attribute.value {
+            AttributeValue::Text(id) => Some(id.clone()),
+            _ => None,
+        })
+        .unwrap_or(fallback);
+    attributes.retain(|attribute| attribute.name != "id");
+    id
+}
+
+/// Appends the caller's `attributes` to a component's `defaults`, replacing the defaults
+/// of the same name.
+///
+/// A caller listener runs before the component's listener of the same name instead of
+/// replacing it, since only one listener per event fires on an element. A caller `id`
+/// never replaces a default `id`: other parts find the element by it. Parts that can go
+/// by the caller's id take it out first with [`take_id_attribute`].
+pub(crate) fn merge_attributes(
+    mut defaults: Vec<Attribute>,
+    attributes: Vec<Attribute>,
+) -> Vec<Attribute> {
+    for attribute in attributes {
+        let Some(index) = defaults
+            .iter()
+            .position(|default| default.name == attribute.name)
+        else {
+            defaults.push(attribute);
+            continue;
+        };
+        if attribute.name == "id" {
+            continue;
+        }
+        if let (AttributeValue::Listener(default), AttributeValue::Listener(caller)) =
+            (&defaults[index].value, &attribute.value)
+        {
+            let (default, caller) = (default.clone(), caller.clone());
+            defaults[index].value =
+                AttributeValue::listener(move |event: Event<PlatformEventData>| {
+                    let event = event.into_any();
+                    caller.call(event.clone());
+                    default.call(event);
+                });
+            continue;
+        }
+        defaults.remove(index);
+        defaults.push(attribute);
+    }
+    defaults
+}
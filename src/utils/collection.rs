@@ -0,0 +1,214 @@
+//! # Collections
+//!
+//! The items of a composite widget (menu items, select options, commands, tab triggers,
+//! radio buttons) register with their root through a [`Collection`], which keeps them in
+//! document order for keyboard navigation, typeahead and form mirroring.
+//!
+//! Items register while they render, so a new collection starts out in render order, which
+//! is document order. An item rendered later, such as one inserted between two others, is
+//! appended at first; once it has mounted, the collection reads the order back from the
+//! document.
+
+use crate::platform;
+use dioxus::core::{use_drop, Task};
+use dioxus::prelude::*;
+use std::cell::{Ref, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// An entry in a [`Collection`].
+pub(crate) trait CollectionItem: 'static {
+    /// The id of the item's element, which places the entry in document order.
+    fn id(&self) -> &str;
+
+    /// Carries over what the item set on its entry outside of rendering (say, from
+    /// `onmounted`) when a render replaces the entry.
+    fn carry_over(&mut self, _previous: &Self) {}
+}
+
+/// The registered items of a widget, in document order. Create it with
+/// [`use_collection`] in the root and register items with [`use_collection_item`].
+pub(crate) struct Collection<T: 'static> {
+    entries: Rc<RefCell<Vec<T>>>,
+    /// Bumped by [`Collection::changed`], and when entries move or unmount.
+    version: Signal<u64>,
+    /// Bumped when an item mounts, to read the order back from the document.
+    mounted: Signal<u64>,
+}
+
+impl<T> Clone for Collection<T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            version: self.version,
+            mounted: self.mounted,
+        }
+    }
+}
+
+impl<T: CollectionItem> Collection<T> {
+    /// The entries in document order. Doesn't subscribe the caller; read
+    /// [`Collection::version`] for that.
+    pub fn borrow(&self) -> Ref<'_, Vec<T>> {
+        self.entries.borrow()
+    }
+
+    /// Updates the entry with `id` in place.
+    pub fn update(&self, id: &str, update: impl FnOnce(&mut T)) {
+        if let Some(entry) = self
+            .entries
+            .borrow_mut()
+            .iter_mut()
+            .find(|entry| entry.id() == id)
+        {
+            update(entry);
+        }
+    }
+
+    /// How often the entries changed, subscribing the caller to further changes.
+    pub fn version(&self) -> u64 {
+        (self.version)()
+    }
+
+    /// Lets the readers of [`Collection::version`] know the entries changed. Doesn't
+    /// subscribe the caller.
+    pub fn changed(&self) {
+        let mut version = self.version;
+        *version.write() += 1;
+    }
+
+    fn register(&self, mut entry: T) {
+        let mut entries = self.entries.borrow_mut();
+        match entries
+            .iter_mut()
+            .find(|registered| registered.id() == entry.id())
+        {
+            Some(registered) => {
+                entry.carry_over(registered);
+                *registered = entry;
+            }
+            None => entries.push(entry),
+        }
+    }
+
+    fn unregister(&self, id: &str) {
+        self.entries.borrow_mut().retain(|entry| entry.id() != id);
+    }
+
+    /// Reads the order of the entries back from the document.
+    async fn sort(&self) {
+        let ids: Vec<String> = self
+            .borrow()
+            .iter()
+            .map(|entry| entry.id().to_string())
+            .collect();
+        let Some(order) = platform::document_order(&ids).await else {
+            return;
+        };
+        if sort_by_order(&mut self.entries.borrow_mut(), &order) {
+            self.changed();
+        }
+    }
+}
+
+/// Sorts `entries` by the position of their ids in `order`, keeping entries missing from
+/// it at the end. Returns whether anything moved.
+fn sort_by_order<T: CollectionItem>(entries: &mut [T], order: &[String]) -> bool {
+    let positions: HashMap<&str, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(position, id)| (id.as_str(), position))
+        .collect();
+    let position = |entry: &T| positions.get(entry.id()).copied().unwrap_or(usize::MAX);
+    if entries.is_sorted_by_key(position) {
+        return false;
+    }
+    entries.sort_by_key(position);
+    true
+}
+
+/// Creates the collection for the items below the calling component.
+pub(crate) fn use_collection<T: CollectionItem>() -> Collection<T> {
+    let entries = use_hook(|| Rc::new(RefCell::new(Vec::new())));
+    let version = use_signal(|| 0u64);
+    let mounted = use_signal(|| 0u64);
+    let collection = Collection {
+        entries,
+        version,
+        mounted,
+    };
+
+    // Once per batch of mounted items, and only the latest order counts
+    let mut sorting = use_hook(|| CopyValue::new(None::<Task>));
+    let sorted = collection.clone();
+    use_effect(move || {
+        if mounted() == 0 {
+            return;
+        }
+        if let Some(task) = sorting.take() {
+            task.cancel();
+        }
+        let collection = sorted.clone();
+        sorting.set(Some(spawn(async move { collection.sort().await })));
+    });
+
+    collection
+}
+
+/// Registers the calling item with `collection` as `entry`, replacing its entry on every
+/// render and removing it on unmount. The entry's id may change between renders.
+pub(crate) fn use_collection_item<T: CollectionItem>(collection: &Collection<T>, entry: T) {
+    let id = entry.id().to_string();
+    let registered_id = use_hook(|| Rc::new(RefCell::new(id.clone())));
+    let previous_id = registered_id.replace(id.clone());
+    if previous_id != id {
+        collection.unregister(&previous_id);
+    }
+    collection.register(entry);
+
+    let mut mounted = collection.mounted;
+    use_effect(use_reactive!(|id| {
+        let _ = id;
+        *mounted.write() += 1;
+    }));
+
+    let dropped = collection.clone();
+    use_drop(move || {
+        dropped.unregister(&registered_id.borrow());
+        dropped.changed();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Entry(&'static str);
+
+    impl CollectionItem for Entry {
+        fn id(&self) -> &str {
+            self.0
+        }
+    }
+
+    fn ids(entries: &[Entry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.0).collect()
+    }
+
+    #[test]
+    fn sorts_entries_into_document_order() {
+        let mut entries = vec![Entry("a"), Entry("c"), Entry("b")];
+        let order = ["a", "b", "c"].map(String::from);
+        assert!(sort_by_order(&mut entries, &order));
+        assert_eq!(ids(&entries), ["a", "b", "c"]);
+        assert!(!sort_by_order(&mut entries, &order));
+    }
+
+    #[test]
+    fn keeps_entries_missing_from_the_document_last() {
+        let mut entries = vec![Entry("gone"), Entry("b"), Entry("a")];
+        let order = ["a", "b"].map(String::from);
+        assert!(sort_by_order(&mut entries, &order));
+        assert_eq!(ids(&entries), ["a", "b", "gone"]);
+    }
+}
@@ -0,0 +1,106 @@
+use dioxus::prelude::*;
+
+/// State that is either owned by the component or controlled by its parent.
+///
+/// Returned by [`use_controllable_state`]. It is `Copy`, so it can be stored in a context and
+/// moved into event handlers.
+pub struct ControllableState<T: 'static> {
+    prop: Signal<Option<T>>,
+    internal: Signal<T>,
+    on_change: CopyValue<Option<EventHandler<T>>>,
+}
+
+impl<T: 'static> Clone for ControllableState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for ControllableState<T> {}
+
+impl<T: Clone + PartialEq + 'static> ControllableState<T> {
+    /// The current value. Subscribes the calling component to changes.
+    pub fn get(&self) -> T {
+        match self.prop.read().clone() {
+            Some(value) => value,
+            None => self.internal.read().clone(),
+        }
+    }
+
+    /// The current value without subscribing.
+    pub fn peek(&self) -> T {
+        match self.prop.peek().clone() {
+            Some(value) => value,
+            None => self.internal.peek().clone(),
+        }
+    }
+
+    /// Whether the parent passed a value.
+    pub fn is_controlled(&self) -> bool {
+        self.prop.peek().is_some()
+    }
+
+    /// Requests a new value and reports it through `on_change`.
+    ///
+    /// Uncontrolled state updates immediately. Controlled state only changes once the parent
+    /// passes the new value back down, so a parent that ignores the change keeps its value.
+    pub fn set(&mut self, value: T) {
+        if self.peek() == value {
+            return;
+        }
+        if !self.is_controlled() {
+            self.internal.set(value.clone());
+        }
+        if let Some(handler) = *self.on_change.peek() {
+            handler.call(value);
+        }
+    }
+}
+
+/// Manages a value that can be controlled (`prop` is `Some`) or uncontrolled (`prop` is
+/// `None`, starting from `default`), in the style of Radix UI's `useControllableState`.
+///
+/// Components call this with their `checked`/`default_checked`/`onchange` style props and
+/// never write the controlled value themselves; they call [`ControllableState::set`] and let
+/// the parent decide.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_components::use_controllable_state;
+///
+/// #[component]
+/// fn Toggle(pressed: Option<bool>, onchange: Option<EventHandler<bool>>) -> Element {
+///     let mut state = use_controllable_state(pressed, || false, onchange);
+///
+///     rsx! {
+///         button {
+///             aria_pressed: state.get(),
+///             onclick: move |_| state.set(!state.peek()),
+///             "Bold"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_controllable_state<T: Clone + PartialEq + 'static>(
+    prop: Option<T>,
+    default: impl FnOnce() -> T,
+    on_change: Option<EventHandler<T>>,
+) -> ControllableState<T> {
+    let internal = use_signal(default);
+    let mut prop_signal = use_signal(|| prop.clone());
+    let mut handler = use_hook(|| CopyValue::new(on_change));
+
+    // Only notify readers when the parent actually passed something new, so re-rendering
+    // with the same value doesn't re-render every consumer.
+    if *prop_signal.peek() != prop {
+        prop_signal.set(prop);
+    }
+    handler.set(on_change);
+
+    ControllableState {
+        prop: prop_signal,
+        internal,
+        on_change: handler,
+    }
+}
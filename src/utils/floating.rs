@@ -0,0 +1,749 @@
+//! # Floating Positioning
+//!
+//! Collision-aware placement for content that floats next to an anchor: an element
+//! (tooltips, popovers, dropdown menus) or a virtual point such as where a context menu
+//! was requested.
+//!
+//! The geometry lives in pure functions such as [`compute_position`], so it can be
+//! unit tested without a browser. [`use_floating`] wires it up to mounted elements.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use dioxus_components::utils::floating::*;
+//!
+//! let anchor = Rect::new(10.0, 100.0, 80.0, 30.0);
+//! let content = Rect::new(0.0, 0.0, 120.0, 40.0);
+//! let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+//!
+//! let position = compute_position(anchor, content, viewport, &FloatingOptions::default());
+//!
+//! // Centered content would overflow the left edge, so it is shifted back in.
+//! assert_eq!(position.side, Side::Top);
+//! assert_eq!(position.x, 0.0);
+//! ```
+
+use crate::platform::{self, use_event_listener, ListenerOptions, ListenerTarget};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/* -------------------------------------------------------------------------------------------------
+ * Types
+ * -----------------------------------------------------------------------------------------------*/
+
+/// The side of the anchor the floating content is placed against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Side {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        }
+    }
+
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+
+    /// Whether the content sits above or below the anchor (main axis is vertical).
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// How the floating content is aligned against the anchor on the cross axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+}
+
+impl Align {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Align::Start => "start",
+            Align::Center => "center",
+            Align::End => "end",
+        }
+    }
+}
+
+/// A rectangle in viewport coordinates (CSS pixels).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+impl From<dioxus::html::geometry::PixelsRect> for Rect {
+    fn from(rect: dioxus::html::geometry::PixelsRect) -> Self {
+        Self::new(
+            rect.origin.x,
+            rect.origin.y,
+            rect.size.width,
+            rect.size.height,
+        )
+    }
+}
+
+/// What floating content is positioned against.
+#[derive(Clone)]
+pub enum Anchor {
+    /// A mounted element, measured again on every update.
+    Element(Rc<MountedData>),
+    /// A fixed rectangle in viewport coordinates, e.g. a zero-sized one at the pointer.
+    Virtual(Rect),
+}
+
+impl Anchor {
+    /// A zero-sized anchor at `x`/`y` in viewport coordinates.
+    pub fn point(x: f64, y: f64) -> Self {
+        Anchor::Virtual(Rect::new(x, y, 0.0, 0.0))
+    }
+
+    pub(crate) async fn rect(&self) -> Option<Rect> {
+        match self {
+            Anchor::Element(element) => element.get_client_rect().await.ok().map(Rect::from),
+            Anchor::Virtual(rect) => Some(*rect),
+        }
+    }
+}
+
+impl From<Rc<MountedData>> for Anchor {
+    fn from(element: Rc<MountedData>) -> Self {
+        Anchor::Element(element)
+    }
+}
+
+/// Placement options for [`compute_position`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingOptions {
+    /// The preferred side of the anchor.
+    pub side: Side,
+    /// The preferred alignment against the anchor.
+    pub align: Align,
+    /// The distance in pixels from the anchor.
+    pub side_offset: f64,
+    /// An offset in pixels from the `Start` or `End` alignment.
+    pub align_offset: f64,
+    /// When true, flips to the opposite side and shifts along the anchor to stay in view.
+    pub avoid_collisions: bool,
+    /// The distance in pixels from the viewport edges where collision detection kicks in.
+    pub collision_padding: f64,
+    /// The size of the arrow, if one is rendered. [`use_floating`] fills it in from a
+    /// [`FloatingArrow`].
+    pub arrow_width: f64,
+    pub arrow_height: f64,
+    /// The padding between the arrow and the edges of the content.
+    pub arrow_padding: f64,
+}
+
+impl Default for FloatingOptions {
+    fn default() -> Self {
+        Self {
+            side: Side::Top,
+            align: Align::Center,
+            side_offset: 0.0,
+            align_offset: 0.0,
+            avoid_collisions: true,
+            collision_padding: 0.0,
+            arrow_width: 0.0,
+            arrow_height: 0.0,
+            arrow_padding: 0.0,
+        }
+    }
+}
+
+/// The computed placement of the floating content.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingPosition {
+    /// Viewport x coordinate of the content's top-left corner.
+    pub x: f64,
+    /// Viewport y coordinate of the content's top-left corner.
+    pub y: f64,
+    /// The side actually used, after flipping.
+    pub side: Side,
+    /// The requested alignment. Collisions shift the content instead of changing it.
+    pub align: Align,
+    /// Offset of the arrow from the content's left edge (top and bottom sides).
+    pub arrow_x: Option<f64>,
+    /// Offset of the arrow from the content's top edge (left and right sides).
+    pub arrow_y: Option<f64>,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Geometry
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Computes where to place `floating` next to `anchor` so that it stays inside `viewport`.
+///
+/// Only the size of `floating` is used. The result is in the same coordinate space as
+/// `anchor` and `viewport`, which is the viewport when rects come from `getBoundingClientRect`.
+pub fn compute_position(
+    anchor: Rect,
+    floating: Rect,
+    viewport: Rect,
+    options: &FloatingOptions,
+) -> FloatingPosition {
+    let mut side = options.side;
+
+    if options.avoid_collisions {
+        let overflow = main_axis_overflow(anchor, floating, viewport, side, options);
+        if overflow > 0.0 {
+            let flipped = side.opposite();
+            let flipped_overflow = main_axis_overflow(anchor, floating, viewport, flipped, options);
+            if flipped_overflow < overflow {
+                side = flipped;
+            }
+        }
+    }
+
+    let (mut x, mut y) = place(anchor, floating, side, options);
+
+    if options.avoid_collisions {
+        let padding = options.collision_padding;
+        if side.is_vertical() {
+            x = shift(
+                x,
+                floating.width,
+                viewport.x + padding,
+                viewport.right() - padding,
+            );
+        } else {
+            y = shift(
+                y,
+                floating.height,
+                viewport.y + padding,
+                viewport.bottom() - padding,
+            );
+        }
+    }
+
+    let (arrow_x, arrow_y) = if options.arrow_width > 0.0 {
+        if side.is_vertical() {
+            let center = anchor.x + anchor.width / 2.0 - x - options.arrow_width / 2.0;
+            let max = floating.width - options.arrow_width - options.arrow_padding;
+            (Some(clamp(center, options.arrow_padding, max)), None)
+        } else {
+            let center = anchor.y + anchor.height / 2.0 - y - options.arrow_width / 2.0;
+            let max = floating.height - options.arrow_width - options.arrow_padding;
+            (None, Some(clamp(center, options.arrow_padding, max)))
+        }
+    } else {
+        (None, None)
+    };
+
+    FloatingPosition {
+        x,
+        y,
+        side,
+        align: options.align,
+        arrow_x,
+        arrow_y,
+    }
+}
+
+/// Top-left corner of the content for a given side, before any collision handling.
+fn place(anchor: Rect, floating: Rect, side: Side, options: &FloatingOptions) -> (f64, f64) {
+    let distance = options.side_offset + options.arrow_height;
+
+    if side.is_vertical() {
+        let y = match side {
+            Side::Top => anchor.y - floating.height - distance,
+            _ => anchor.bottom() + distance,
+        };
+        let x = match options.align {
+            Align::Start => anchor.x + options.align_offset,
+            Align::Center => anchor.x + (anchor.width - floating.width) / 2.0,
+            Align::End => anchor.right() - floating.width - options.align_offset,
+        };
+        (x, y)
+    } else {
+        let x = match side {
+            Side::Left => anchor.x - floating.width - distance,
+            _ => anchor.right() + distance,
+        };
+        let y = match options.align {
+            Align::Start => anchor.y + options.align_offset,
+            Align::Center => anchor.y + (anchor.height - floating.height) / 2.0,
+            Align::End => anchor.bottom() - floating.height - options.align_offset,
+        };
+        (x, y)
+    }
+}
+
+/// How many pixels the content sticks out of the viewport on the main axis.
+fn main_axis_overflow(
+    anchor: Rect,
+    floating: Rect,
+    viewport: Rect,
+    side: Side,
+    options: &FloatingOptions,
+) -> f64 {
+    let (x, y) = place(anchor, floating, side, options);
+    let padding = options.collision_padding;
+
+    match side {
+        Side::Top => viewport.y + padding - y,
+        Side::Bottom => y + floating.height - (viewport.bottom() - padding),
+        Side::Left => viewport.x + padding - x,
+        Side::Right => x + floating.width - (viewport.right() - padding),
+    }
+}
+
+/// Moves a span of `size` starting at `start` so it fits between `min` and `max`.
+/// When it cannot fit, the start edge wins.
+fn shift(start: f64, size: f64, min: f64, max: f64) -> f64 {
+    if start + size > max {
+        (max - size).max(min)
+    } else if start < min {
+        min
+    } else {
+        start
+    }
+}
+
+fn clamp(value: f64, min: f64, max: f64) -> f64 {
+    value.min(max).max(min)
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_floating
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Handle returned by [`use_floating`].
+#[derive(Clone, Copy)]
+pub struct Floating {
+    anchor: Signal<Option<Anchor>>,
+    content: Signal<Option<Rc<MountedData>>>,
+    options: CopyValue<FloatingOptions>,
+    position: Signal<Option<FloatingPosition>>,
+    /// The size of the [`FloatingArrow`] inside the content, if there is one.
+    arrow: Signal<Option<(f64, f64)>>,
+}
+
+/// Positions floating content against `anchor`.
+///
+/// Pass the mounted floating element to [`Floating::set_content`] from its `onmounted`
+/// handler, then render it with [`Floating::style`]. The position is recomputed whenever
+/// the anchor changes or [`Floating::update`] is called.
+///
+/// To point a [`FloatingArrow`] at the anchor, provide the returned [`Floating`] as context
+/// to the content's children.
+pub fn use_floating(anchor: Signal<Option<Anchor>>, options: FloatingOptions) -> Floating {
+    let content = use_signal(|| None::<Rc<MountedData>>);
+    let position = use_signal(|| None::<FloatingPosition>);
+    let arrow = use_signal(|| None::<(f64, f64)>);
+    let mut stored_options = use_hook(|| CopyValue::new(options));
+
+    if *stored_options.peek() != options {
+        stored_options.set(options);
+    }
+
+    let floating = Floating {
+        anchor,
+        content,
+        options: stored_options,
+        position,
+        arrow,
+    };
+
+    // Follow an anchor that moves, e.g. a context menu requested somewhere else
+    use_effect(move || {
+        if anchor.read().is_some() {
+            floating.update();
+        }
+    });
+
+    floating
+}
+
+impl Floating {
+    /// The last computed position, `None` until both elements have been measured.
+    pub fn position(&self) -> Option<FloatingPosition> {
+        (self.position)()
+    }
+
+    pub fn set_content(&mut self, element: Rc<MountedData>) {
+        self.content.set(Some(element));
+        self.update();
+    }
+
+    /// Sets the size of the arrow rendered inside the content and recomputes the position
+    /// around it.
+    pub fn set_arrow_size(&mut self, width: f64, height: f64) {
+        if *self.arrow.peek() != Some((width, height)) {
+            self.arrow.set(Some((width, height)));
+            self.update();
+        }
+    }
+
+    /// Measures the anchor, the content and the viewport, then recomputes the position.
+    pub fn update(&self) {
+        let anchor = self.anchor;
+        let content = self.content;
+        let options = self.options;
+        let arrow = self.arrow;
+        let mut position = self.position;
+
+        spawn(async move {
+            let (Some(anchor), Some(content)) = (anchor.peek().clone(), content.peek().clone())
+            else {
+                return;
+            };
+            let (Some(anchor_rect), Ok(content_rect)) =
+                (anchor.rect().await, content.get_client_rect().await)
+            else {
+                return;
+            };
+
+            let mut options = options.cloned();
+            if let Some((width, height)) = *arrow.peek() {
+                options.arrow_width = width;
+                options.arrow_height = height;
+            }
+            let next = compute_position(
+                anchor_rect,
+                content_rect.into(),
+                viewport_rect().await,
+                &options,
+            );
+
+            if *position.peek() != Some(next) {
+                position.set(Some(next));
+            }
+        });
+    }
+
+    /// Inline style placing the content. It stays hidden until the first measurement.
+    pub fn style(&self) -> String {
+        match self.position() {
+            Some(pos) => format!("position: fixed; left: {}px; top: {}px;", pos.x, pos.y),
+            None => "position: fixed; left: 0; top: 0; visibility: hidden;".to_string(),
+        }
+    }
+
+    /// Inline style for an arrow rendered inside the content, pointing at the anchor from
+    /// whichever side the content was placed on. The arrow element should be drawn pointing
+    /// down; it is rotated for the other sides. It stays hidden until the first measurement.
+    pub fn arrow_style(&self) -> String {
+        let Some(pos) = self.position() else {
+            return "position: absolute; visibility: hidden;".to_string();
+        };
+
+        // Offsets and rotations match Radix so the arrow always points at the anchor
+        let (edge, transform_origin, transform) = match pos.side {
+            Side::Top => ("bottom: 0;", "", "translateY(100%)"),
+            Side::Right => (
+                "left: 0;",
+                "0 0",
+                "translateY(50%) rotate(90deg) translateX(-50%)",
+            ),
+            Side::Bottom => ("top: 0;", "center 0", "rotate(180deg)"),
+            Side::Left => (
+                "right: 0;",
+                "100% 0",
+                "translateY(50%) rotate(-90deg) translateX(50%)",
+            ),
+        };
+        let offset = match (pos.arrow_x, pos.arrow_y) {
+            (Some(x), _) => format!("left: {x}px;"),
+            (_, Some(y)) => format!("top: {y}px;"),
+            _ => String::new(),
+        };
+        format!(
+            "position: absolute; {edge} {offset} transform-origin: {transform_origin}; transform: {transform};"
+        )
+    }
+}
+
+/// An arrow inside floating content, pointing at the anchor from whichever side the
+/// content was placed on. Render it inside content whose [`Floating`] is provided as
+/// context; the content is positioned to leave room for it.
+#[component]
+pub fn FloatingArrow(
+    /// Classes for the arrow's `svg`, typically a `fill-*` matching the content.
+    #[props(default = String::new())]
+    class: String,
+    #[props(default = 10)] width: u32,
+    #[props(default = 5)] height: u32,
+) -> Element {
+    let mut floating = use_context::<Floating>();
+
+    let size = (width as f64, height as f64);
+    use_effect(use_reactive!(|size| {
+        floating.set_arrow_size(size.0, size.1);
+    }));
+
+    rsx! {
+        span {
+            style: "{floating.arrow_style()}",
+            svg {
+                width: "{width}",
+                height: "{height}",
+                view_box: "0 0 30 10",
+                preserve_aspect_ratio: "none",
+                class: "{class}",
+                style: "display: block;",
+
+                polygon { points: "0,0 30,0 15,10" }
+            }
+        }
+    }
+}
+
+/// Keeps `floating` in sync with its anchor while the page scrolls or resizes.
+///
+/// Listeners are attached while `active` is true and removed when it turns false
+/// or the component unmounts.
+pub fn use_auto_update(floating: Floating, active: bool) {
+    // Capture scroll events so nested scroll containers are tracked too
+    let scroll = ListenerOptions {
+        capture: true,
+        ..ListenerOptions::default()
+    };
+    use_event_listener(
+        ListenerTarget::Window,
+        "scroll",
+        scroll,
+        active,
+        move |_| floating.update(),
+    );
+    use_event_listener(
+        ListenerTarget::Window,
+        "resize",
+        ListenerOptions::default(),
+        active,
+        move |_| floating.update(),
+    );
+}
+
+/// The visible viewport. Without a browser it is unbounded, which disables collisions.
+pub(crate) async fn viewport_rect() -> Rect {
+    match platform::viewport_size().await {
+        Some((width, height)) => Rect::new(0.0, 0.0, width, height),
+        None => Rect::new(0.0, 0.0, f64::INFINITY, f64::INFINITY),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    fn options(side: Side, align: Align) -> FloatingOptions {
+        FloatingOptions {
+            side,
+            align,
+            ..FloatingOptions::default()
+        }
+    }
+
+    #[test]
+    fn places_on_each_side_centered() {
+        let anchor = Rect::new(300.0, 300.0, 100.0, 40.0);
+        let content = Rect::new(0.0, 0.0, 60.0, 20.0);
+
+        let top = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &options(Side::Top, Align::Center),
+        );
+        assert_eq!((top.x, top.y, top.side), (320.0, 280.0, Side::Top));
+
+        let bottom = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &options(Side::Bottom, Align::Center),
+        );
+        assert_eq!((bottom.x, bottom.y), (320.0, 340.0));
+
+        let left = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &options(Side::Left, Align::Center),
+        );
+        assert_eq!((left.x, left.y), (240.0, 310.0));
+
+        let right = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &options(Side::Right, Align::Center),
+        );
+        assert_eq!((right.x, right.y), (400.0, 310.0));
+    }
+
+    #[test]
+    fn applies_alignment_and_offsets() {
+        let anchor = Rect::new(300.0, 300.0, 100.0, 40.0);
+        let content = Rect::new(0.0, 0.0, 60.0, 20.0);
+        let opts = FloatingOptions {
+            side_offset: 4.0,
+            align_offset: 2.0,
+            ..options(Side::Bottom, Align::Start)
+        };
+
+        let start = compute_position(anchor, content, VIEWPORT, &opts);
+        assert_eq!((start.x, start.y), (302.0, 344.0));
+
+        let end = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &FloatingOptions {
+                align: Align::End,
+                ..opts
+            },
+        );
+        assert_eq!(end.x, 338.0);
+    }
+
+    #[test]
+    fn flips_when_preferred_side_overflows() {
+        let anchor = Rect::new(300.0, 10.0, 100.0, 40.0);
+        let content = Rect::new(0.0, 0.0, 60.0, 30.0);
+
+        let pos = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &options(Side::Top, Align::Center),
+        );
+        assert_eq!(pos.side, Side::Bottom);
+        assert_eq!(pos.y, 50.0);
+    }
+
+    #[test]
+    fn keeps_side_when_opposite_is_worse() {
+        let anchor = Rect::new(300.0, 10.0, 100.0, 580.0);
+        let content = Rect::new(0.0, 0.0, 60.0, 30.0);
+
+        let pos = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &options(Side::Top, Align::Center),
+        );
+        assert_eq!(pos.side, Side::Top);
+    }
+
+    #[test]
+    fn shifts_along_cross_axis_with_padding() {
+        let anchor = Rect::new(780.0, 300.0, 20.0, 20.0);
+        let content = Rect::new(0.0, 0.0, 100.0, 20.0);
+        let opts = FloatingOptions {
+            collision_padding: 8.0,
+            ..options(Side::Top, Align::Center)
+        };
+
+        let pos = compute_position(anchor, content, VIEWPORT, &opts);
+        assert_eq!(pos.x, 692.0);
+    }
+
+    #[test]
+    fn does_not_move_when_collisions_are_ignored() {
+        let anchor = Rect::new(0.0, 0.0, 20.0, 20.0);
+        let content = Rect::new(0.0, 0.0, 100.0, 20.0);
+        let opts = FloatingOptions {
+            avoid_collisions: false,
+            ..options(Side::Top, Align::Center)
+        };
+
+        let pos = compute_position(anchor, content, VIEWPORT, &opts);
+        assert_eq!((pos.x, pos.y, pos.side), (-40.0, -20.0, Side::Top));
+    }
+
+    #[test]
+    fn arrow_points_at_anchor_center_and_stays_inside() {
+        let anchor = Rect::new(780.0, 300.0, 20.0, 20.0);
+        let content = Rect::new(0.0, 0.0, 100.0, 20.0);
+        let opts = FloatingOptions {
+            arrow_width: 10.0,
+            arrow_height: 5.0,
+            arrow_padding: 4.0,
+            ..options(Side::Top, Align::Center)
+        };
+
+        let pos = compute_position(anchor, content, VIEWPORT, &opts);
+        assert_eq!(pos.y, 275.0);
+        assert_eq!(pos.arrow_x, Some(85.0));
+        assert_eq!(pos.arrow_y, None);
+
+        let side = compute_position(
+            anchor,
+            content,
+            VIEWPORT,
+            &FloatingOptions {
+                side: Side::Left,
+                ..opts
+            },
+        );
+        assert_eq!(side.arrow_x, None);
+        assert_eq!(side.arrow_y, Some(5.0));
+    }
+
+    #[test]
+    fn point_anchor_opens_beside_the_pointer_and_flips_at_the_edge() {
+        let Anchor::Virtual(point) = Anchor::point(100.0, 100.0) else {
+            unreachable!()
+        };
+        let content = Rect::new(0.0, 0.0, 160.0, 120.0);
+        let opts = options(Side::Right, Align::Start);
+
+        let pos = compute_position(point, content, VIEWPORT, &opts);
+        assert_eq!((pos.x, pos.y, pos.side), (100.0, 100.0, Side::Right));
+
+        // Near the bottom right corner it flips left and shifts up
+        let Anchor::Virtual(corner) = Anchor::point(750.0, 550.0) else {
+            unreachable!()
+        };
+        let pos = compute_position(corner, content, VIEWPORT, &opts);
+        assert_eq!((pos.x, pos.y, pos.side), (590.0, 480.0, Side::Left));
+    }
+}
@@ -0,0 +1,132 @@
+/// How much a match continuing right after the previous one is worth.
+const SCORE_CONTINUE: f64 = 1.0;
+/// A match at the start of a word, after a space, `-`, `_` or `/`.
+const SCORE_WORD_JUMP: f64 = 0.9;
+/// A match at an uppercase letter following a lowercase one, as in `camelCase`.
+const SCORE_CASE_JUMP: f64 = 0.8;
+/// A match in the middle of a word.
+const SCORE_CHARACTER_JUMP: f64 = 0.3;
+/// Applied per skipped character, so nearer matches rank higher.
+const PENALTY_DISTANCE: f64 = 0.999;
+/// Applied when the text goes on after the last match, so exact matches rank first.
+const PENALTY_INCOMPLETE: f64 = 0.99;
+
+/// Scores how well `text` matches a fuzzy `query`, from `0.0` (no match) to `1.0`.
+///
+/// Every query character has to appear in the text in order, ignoring case. Matches that
+/// continue a run or start a word score higher than ones in the middle of a word, so
+/// `"gc"` ranks *Git Commit* above *Logic*. An empty query matches everything with `1.0`.
+///
+/// # Example
+/// ```rust
+/// use dioxus_components::utils::fuzzy_score;
+///
+/// assert_eq!(fuzzy_score("Settings", ""), 1.0);
+/// assert_eq!(fuzzy_score("Settings", "xyz"), 0.0);
+/// assert!(fuzzy_score("Git Commit", "gc") > fuzzy_score("Logic", "gc"));
+/// ```
+pub fn fuzzy_score(text: &str, query: &str) -> f64 {
+    let query: Vec<char> = query.trim().chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return 1.0;
+    }
+    let text: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = text
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut memo = vec![None; (text.len() + 1) * (query.len() + 1)];
+    score(&text, &lower, &query, 0, 0, &mut memo)
+}
+
+/// The best score for matching `query[query_index..]` in `text[text_index..]`.
+fn score(
+    text: &[char],
+    lower: &[char],
+    query: &[char],
+    text_index: usize,
+    query_index: usize,
+    memo: &mut [Option<f64>],
+) -> f64 {
+    if query_index == query.len() {
+        return if text_index == text.len() {
+            SCORE_CONTINUE
+        } else {
+            PENALTY_INCOMPLETE
+        };
+    }
+    let key = text_index * (query.len() + 1) + query_index;
+    if let Some(score) = memo[key] {
+        return score;
+    }
+
+    let mut best = 0.0;
+    for index in text_index..text.len() {
+        if lower[index] != query[query_index] {
+            continue;
+        }
+        let rest = score(text, lower, query, index + 1, query_index + 1, memo);
+        if rest == 0.0 {
+            continue;
+        }
+        let previous = index.checked_sub(1).map(|i| text[i]);
+        let jump = if index == text_index {
+            SCORE_CONTINUE
+        } else if previous.is_some_and(|c| matches!(c, ' ' | '-' | '_' | '/')) {
+            SCORE_WORD_JUMP
+        } else if previous.is_some_and(char::is_lowercase) && text[index].is_uppercase() {
+            SCORE_CASE_JUMP
+        } else {
+            SCORE_CHARACTER_JUMP * PENALTY_DISTANCE.powi((index - text_index) as i32)
+        };
+        best = f64::max(best, jump * rest);
+    }
+
+    memo[key] = Some(best);
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_every_character_in_order() {
+        assert_eq!(fuzzy_score("Calendar", "cal"), 1.0 * PENALTY_INCOMPLETE);
+        assert!(fuzzy_score("Calendar", "cdr") > 0.0);
+        assert_eq!(fuzzy_score("Calendar", "rdc"), 0.0);
+        assert_eq!(fuzzy_score("Calendar", "calendars"), 0.0);
+    }
+
+    #[test]
+    fn ignores_case_and_surrounding_whitespace() {
+        assert_eq!(fuzzy_score("Calendar", "  CALENDAR "), 1.0);
+        assert_eq!(fuzzy_score("", ""), 1.0);
+        assert_eq!(fuzzy_score("", "a"), 0.0);
+    }
+
+    #[test]
+    fn ranks_exact_then_prefix_then_word_starts_then_scattered() {
+        let exact = fuzzy_score("set", "set");
+        let prefix = fuzzy_score("Settings", "set");
+        let words = fuzzy_score("Search Emoji Tab", "set");
+        let scattered = fuzzy_score("Toggle Sidebar Layout", "set");
+        assert!(exact > prefix, "{exact} > {prefix}");
+        assert!(prefix > words, "{prefix} > {words}");
+        assert!(words > scattered, "{words} > {scattered}");
+    }
+
+    #[test]
+    fn prefers_word_starts_and_nearer_matches() {
+        assert!(fuzzy_score("git-commit", "gc") > fuzzy_score("logic", "gc"));
+        assert!(fuzzy_score("openFile", "of") > fuzzy_score("profile", "of"));
+        assert!(fuzzy_score("axb", "ab") > fuzzy_score("axxxxb", "ab"));
+    }
+
+    #[test]
+    fn picks_the_best_of_several_alignments() {
+        // The first `s` is mid-word, the second starts a word
+        assert!(fuzzy_score("ls settings", "se") > fuzzy_score("lsxsettings", "se"));
+    }
+}
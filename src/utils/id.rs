@@ -0,0 +1,46 @@
+use dioxus::core::{current_scope_id, has_context, provide_context};
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Counts the `use_id` calls made by a single component.
+#[derive(Clone, Default)]
+struct IdCounter(Rc<Cell<usize>>);
+
+/// Generates an element ID that is unique on the page and stable across re-renders.
+///
+/// The ID is derived from the component's scope and the order of `use_id` calls inside it,
+/// so server-side rendering and the hydrating client produce the same value. Use it for
+/// `id`/`aria-*` wiring instead of hard-coded or random IDs.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_components::use_id;
+///
+/// #[component]
+/// fn Field() -> Element {
+///     let input_id = use_id("field");
+///
+///     rsx! {
+///         label { r#for: "{input_id}", "Name" }
+///         input { id: "{input_id}" }
+///     }
+/// }
+/// ```
+pub fn use_id(prefix: &str) -> String {
+    use_hook(|| {
+        // has_context only looks at the current scope, so each component counts on its own
+        let counter =
+            has_context::<IdCounter>().unwrap_or_else(|| provide_context(IdCounter::default()));
+        let index = counter.0.get();
+        counter.0.set(index + 1);
+
+        let scope = current_scope_id().0;
+        if index == 0 {
+            format!("{prefix}-{scope}")
+        } else {
+            format!("{prefix}-{scope}-{index}")
+        }
+    })
+}
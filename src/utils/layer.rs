@@ -0,0 +1,305 @@
+//! # Layer Stack
+//!
+//! Overlays (dialogs today, popovers and menus later) register themselves in one stack
+//! shared by the whole app, so nested overlays behave:
+//!
+//! - Escape goes to the topmost layer only, through a single document listener owned by
+//!   the stack instead of one listener per overlay.
+//! - z-indices follow stack order instead of being hard-coded.
+//! - Body scroll lock is reference-counted, so closing an inner dialog doesn't unlock the
+//!   page while an outer one is still open.
+//!
+//! The stack lives in the root scope and is created the first time a layer is used.
+
+use crate::platform::{EventListener, ListenerEvent, ListenerOptions, ListenerTarget, ScriptGuard};
+use dioxus::core::{consume_context_from_scope, use_drop, Runtime};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// z-index of the bottom layer.
+const BASE_Z_INDEX: i32 = 50;
+
+/// z-index distance between consecutive layers, leaving room for each layer's own parts
+/// (a dialog's backdrop sits at its layer's z-index, its content one above).
+const Z_INDEX_STEP: i32 = 10;
+
+/* -------------------------------------------------------------------------------------------------
+ * Stack
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+struct LayerStack {
+    /// Open layers, bottom to top.
+    order: Signal<Vec<usize>>,
+    state: Rc<RefCell<StackState>>,
+}
+
+#[derive(Default)]
+struct StackState {
+    next_id: usize,
+    escape_handlers: HashMap<usize, Callback<ListenerEvent>>,
+    escape_listener: Option<EventListener>,
+    scroll_locks: usize,
+    scroll_guard: Option<ScriptGuard>,
+}
+
+/// The app's layer stack, created in the root scope on first use.
+fn layer_stack() -> LayerStack {
+    consume_context_from_scope::<LayerStack>(ScopeId::ROOT).unwrap_or_else(|| {
+        Runtime::current().in_scope(ScopeId::ROOT, || {
+            provide_context(LayerStack {
+                order: Signal::new(Vec::new()),
+                state: Rc::default(),
+            })
+        })
+    })
+}
+
+impl LayerStack {
+    fn push(&self, id: usize) {
+        let mut order = self.order;
+        if order.peek().contains(&id) {
+            return;
+        }
+        order.write().push(id);
+
+        // The first open layer attaches the shared Escape listener
+        if self.state.borrow().escape_listener.is_none() {
+            let stack = self.clone();
+            let listener = Runtime::current().in_scope(ScopeId::ROOT, || {
+                EventListener::new(
+                    ListenerTarget::Document,
+                    "keydown",
+                    ListenerOptions {
+                        keys: &["Escape"],
+                        prevent_default: true,
+                        ..ListenerOptions::default()
+                    },
+                    Callback::new(move |event: ListenerEvent| stack.escape(event)),
+                )
+            });
+            self.state.borrow_mut().escape_listener = listener;
+        }
+    }
+
+    fn remove(&self, id: usize) {
+        let mut order = self.order;
+        if !order.peek().contains(&id) {
+            return;
+        }
+        order.write().retain(|layer| *layer != id);
+
+        if order.peek().is_empty() {
+            self.state.borrow_mut().escape_listener.take();
+        }
+    }
+
+    /// Hands Escape to the topmost layer. Layers underneath never see it, even when the
+    /// topmost layer has no handler.
+    fn escape(&self, event: ListenerEvent) {
+        let Some(top) = self.order.peek().last().copied() else {
+            return;
+        };
+        let handler = self.state.borrow().escape_handlers.get(&top).copied();
+        if let Some(handler) = handler {
+            handler.call(event);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Layer
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A handle to one overlay's place in the layer stack. Returned by [`use_layer`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct Layer {
+    id: usize,
+    order: Signal<Vec<usize>>,
+}
+
+impl Layer {
+    /// Whether the layer is in the stack. Subscribes the caller to changes.
+    pub fn is_open(&self) -> bool {
+        self.order.read().contains(&self.id)
+    }
+
+    /// Whether no open layer sits above this one. Subscribes the caller to changes.
+    pub fn is_topmost(&self) -> bool {
+        self.order.read().last() == Some(&self.id)
+    }
+
+    /// The z-index for this layer's bottom part. Layers opened later get higher values.
+    /// Subscribes the caller to changes.
+    pub fn z_index(&self) -> i32 {
+        let position = self
+            .order
+            .read()
+            .iter()
+            .position(|layer| *layer == self.id)
+            .unwrap_or(0);
+        BASE_Z_INDEX + position as i32 * Z_INDEX_STEP
+    }
+}
+
+/// Registers an overlay in the layer stack while `open` is true.
+///
+/// The layer is pushed on top when it opens and removed when it closes or the component
+/// unmounts.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_components::use_layer;
+///
+/// #[component]
+/// fn Banner(open: bool) -> Element {
+///     let layer = use_layer(open);
+///
+///     rsx! {
+///         if open {
+///             div { style: "position: fixed; z-index: {layer.z_index()};", "Saved" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_layer(open: bool) -> Layer {
+    let (id, stack) = use_hook(|| {
+        let stack = layer_stack();
+        let id = {
+            let mut state = stack.state.borrow_mut();
+            state.next_id += 1;
+            state.next_id
+        };
+        (id, stack)
+    });
+
+    let effect_stack = stack.clone();
+    use_effect(use_reactive((&open,), move |(open,)| {
+        if open {
+            effect_stack.push(id);
+        } else {
+            effect_stack.remove(id);
+        }
+    }));
+
+    let drop_stack = stack.clone();
+    use_drop(move || drop_stack.remove(id));
+
+    Layer {
+        id,
+        order: stack.order,
+    }
+}
+
+/// Calls `handler` when Escape is pressed while `layer` is the topmost open layer.
+///
+/// The key's default action is prevented whenever any layer is open.
+pub fn use_layer_escape(layer: Layer, handler: impl FnMut(ListenerEvent) + 'static) {
+    let callback = use_callback(handler);
+    let stack = use_hook(|| {
+        let stack = layer_stack();
+        stack
+            .state
+            .borrow_mut()
+            .escape_handlers
+            .insert(layer.id, callback);
+        stack
+    });
+
+    use_drop(move || {
+        stack.state.borrow_mut().escape_handlers.remove(&layer.id);
+    });
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Scroll lock
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Hides the body scrollbar, compensating for its width, so the page can't scroll under
+/// an overlay.
+const SCROLL_LOCK_SETUP: &str = r#"
+(function() {
+    if (!document.body) return;
+
+    // Calculate scrollbar width before hiding it
+    const scrollbarWidth = window.innerWidth - document.documentElement.clientWidth;
+
+    // Store original values for restoration
+    window._originalOverflow = document.body.style.overflow;
+    window._originalPaddingRight = document.body.style.paddingRight;
+
+    // Lock scroll and compensate for scrollbar width
+    document.body.style.overflow = 'hidden';
+    if (scrollbarWidth > 0) {
+        document.body.style.paddingRight = scrollbarWidth + 'px';
+    }
+})();
+"#;
+
+const SCROLL_LOCK_TEARDOWN: &str = r#"
+(function() {
+    if (!document.body) return;
+
+    // Restore original values
+    document.body.style.overflow = window._originalOverflow || '';
+    document.body.style.paddingRight = window._originalPaddingRight || '';
+
+    // Clean up stored values
+    delete window._originalOverflow;
+    delete window._originalPaddingRight;
+})();
+"#;
+
+/// One holder of the body scroll lock. The page unlocks when the last one is dropped.
+struct ScrollLock(Rc<RefCell<StackState>>);
+
+impl ScrollLock {
+    fn acquire(stack: &LayerStack) -> Self {
+        let mut state = stack.state.borrow_mut();
+        state.scroll_locks += 1;
+        if state.scroll_locks == 1 {
+            state.scroll_guard = Some(ScriptGuard::new(SCROLL_LOCK_SETUP, SCROLL_LOCK_TEARDOWN));
+        }
+        Self(stack.state.clone())
+    }
+}
+
+impl Drop for ScrollLock {
+    fn drop(&mut self) {
+        let guard = {
+            let mut state = self.0.borrow_mut();
+            state.scroll_locks -= 1;
+            if state.scroll_locks == 0 {
+                state.scroll_guard.take()
+            } else {
+                None
+            }
+        };
+        // Run the teardown script outside the borrow
+        drop(guard);
+    }
+}
+
+/// Locks body scroll while `active` is true. Any number of components can hold the lock;
+/// the page scrolls again once all of them release it.
+pub fn use_scroll_lock(active: bool) {
+    let stack = use_hook(layer_stack);
+    let lock = use_hook(|| Rc::new(RefCell::new(None::<ScrollLock>)));
+
+    let slot = lock.clone();
+    use_effect(use_reactive((&active,), move |(active,)| {
+        let mut slot = slot.borrow_mut();
+        if !active {
+            slot.take();
+        } else if slot.is_none() {
+            *slot = Some(ScrollLock::acquire(&stack));
+        }
+    }));
+
+    use_drop(move || {
+        lock.borrow_mut().take();
+    });
+}
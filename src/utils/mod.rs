@@ -1,3 +1,20 @@
+mod attributes;
+mod collection;
+mod controllable;
+pub mod floating;
+mod fuzzy;
 mod helper;
+mod id;
+mod layer;
+mod roving;
+mod typeahead;
 
+pub(crate) use attributes::*;
+pub(crate) use collection::*;
+pub use controllable::*;
+pub use fuzzy::*;
 pub use helper::*;
+pub use id::*;
+pub use layer::*;
+pub(crate) use roving::*;
+pub use typeahead::*;
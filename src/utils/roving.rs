@@ -0,0 +1,80 @@
+use dioxus::prelude::Key;
+
+/// Finds the item to move focus to when `key` is pressed on item `current` of a group of
+/// `count` items that share one tab stop, such as tabs or radio buttons.
+///
+/// `horizontal` and `vertical` say which arrow keys move: ArrowLeft/ArrowRight and
+/// ArrowUp/ArrowDown. Home and End move to the first and last item. With `wrap`, moving
+/// past either end continues at the other. Returns `None` for keys that don't move.
+pub(crate) fn roving_index(
+    key: &Key,
+    current: usize,
+    count: usize,
+    horizontal: bool,
+    vertical: bool,
+    wrap: bool,
+) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    let forward = match key {
+        Key::ArrowRight if horizontal => true,
+        Key::ArrowDown if vertical => true,
+        Key::ArrowLeft if horizontal => false,
+        Key::ArrowUp if vertical => false,
+        Key::Home => return Some(0),
+        Key::End => return Some(last),
+        _ => return None,
+    };
+    Some(match (forward, wrap) {
+        (true, true) if current >= last => 0,
+        (true, _) => (current + 1).min(last),
+        (false, true) if current == 0 => last,
+        (false, _) => current.saturating_sub(1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrows_move_along_their_axis_only() {
+        assert_eq!(
+            roving_index(&Key::ArrowRight, 0, 3, true, false, true),
+            Some(1)
+        );
+        assert_eq!(
+            roving_index(&Key::ArrowLeft, 1, 3, true, false, true),
+            Some(0)
+        );
+        assert_eq!(roving_index(&Key::ArrowDown, 0, 3, true, false, true), None);
+        assert_eq!(
+            roving_index(&Key::ArrowDown, 0, 3, false, true, true),
+            Some(1)
+        );
+        assert_eq!(
+            roving_index(&Key::ArrowRight, 0, 3, false, true, true),
+            None
+        );
+        assert_eq!(roving_index(&Key::Enter, 0, 3, true, true, true), None);
+    }
+
+    #[test]
+    fn wraps_or_stops_at_the_ends() {
+        assert_eq!(
+            roving_index(&Key::ArrowRight, 2, 3, true, true, true),
+            Some(0)
+        );
+        assert_eq!(roving_index(&Key::ArrowUp, 0, 3, true, true, true), Some(2));
+        assert_eq!(
+            roving_index(&Key::ArrowRight, 2, 3, true, true, false),
+            Some(2)
+        );
+        assert_eq!(
+            roving_index(&Key::ArrowUp, 0, 3, true, true, false),
+            Some(0)
+        );
+        assert_eq!(roving_index(&Key::End, 0, 3, true, true, false), Some(2));
+        assert_eq!(roving_index(&Key::Home, 2, 3, true, true, false), Some(0));
+        assert_eq!(roving_index(&Key::Home, 0, 0, true, true, false), None);
+    }
+}
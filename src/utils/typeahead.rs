@@ -0,0 +1,165 @@
+use crate::platform::{clear_timeout, set_timeout};
+use dioxus::core::{DynamicNode, Task, TemplateNode, VNode};
+use dioxus::prelude::*;
+
+/// How long after the last key press a typeahead search starts over.
+const TYPEAHEAD_RESET_MS: u64 = 1000;
+
+/// Finds the item to move to when the user has typed `search` in a list of `texts`, with
+/// the item at `current` focused.
+///
+/// Matching is a case-insensitive prefix match, starting at the current item and wrapping
+/// around. A single letter skips the current item, and typing the same letter repeatedly
+/// cycles through the items starting with it, as in native `<select>`s and menus.
+///
+/// # Example
+/// ```rust
+/// use dioxus_components::utils::typeahead_match;
+///
+/// let fruits = ["Apple", "Banana", "Blueberry", "Cherry"];
+///
+/// assert_eq!(typeahead_match(&fruits, None, "b"), Some(1));
+/// assert_eq!(typeahead_match(&fruits, Some(1), "bb"), Some(2));
+/// assert_eq!(typeahead_match(&fruits, Some(1), "blu"), Some(2));
+/// ```
+pub fn typeahead_match(texts: &[&str], current: Option<usize>, search: &str) -> Option<usize> {
+    let first = search.chars().next()?;
+    let search = if search.chars().all(|c| c == first) {
+        first.to_lowercase().to_string()
+    } else {
+        search.to_lowercase()
+    };
+    let single_letter = search.chars().count() == 1;
+
+    let len = texts.len();
+    let start = current.unwrap_or(0);
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .filter(|&index| !(single_letter && Some(index) == current))
+        .find(|&index| {
+            texts[index]
+                .trim_start()
+                .to_lowercase()
+                .starts_with(&search)
+        })
+}
+
+/// The text an item shows, for typeahead when it has no explicit text value.
+///
+/// Collects the text nodes of the item's own markup. Text rendered by child components
+/// isn't visible from here, so items wrapping their label in a component need a text
+/// value.
+pub(crate) fn element_text(element: &Element) -> String {
+    fn collect(node: &VNode, text: &mut String) {
+        for root in node.template.roots {
+            collect_template(node, root, text);
+        }
+    }
+
+    fn collect_template(node: &VNode, template: &TemplateNode, text: &mut String) {
+        match template {
+            TemplateNode::Element { children, .. } => {
+                for child in children.iter() {
+                    collect_template(node, child, text);
+                }
+            }
+            TemplateNode::Text { text: value } => text.push_str(value),
+            TemplateNode::Dynamic { id } => match &node.dynamic_nodes[*id] {
+                DynamicNode::Text(value) => text.push_str(&value.value),
+                DynamicNode::Fragment(nodes) => {
+                    for child in nodes {
+                        collect(child, text);
+                    }
+                }
+                DynamicNode::Component(_) | DynamicNode::Placeholder(_) => {}
+            },
+        }
+    }
+
+    let mut text = String::new();
+    if let Ok(node) = element {
+        collect(node, &mut text);
+    }
+    text.trim().to_string()
+}
+
+/// The search typed so far. Returned by [`use_typeahead`].
+#[derive(Clone, Copy)]
+pub struct Typeahead {
+    search: CopyValue<String>,
+    reset: CopyValue<Option<Task>>,
+}
+
+/// Collects typed characters into a search that is cleared after a second without typing.
+pub fn use_typeahead() -> Typeahead {
+    let search = use_hook(|| CopyValue::new(String::new()));
+    let reset = use_hook(|| CopyValue::new(None::<Task>));
+    Typeahead { search, reset }
+}
+
+impl Typeahead {
+    /// Adds `key` to the search and returns the whole search.
+    pub fn push(&self, key: &str) -> String {
+        let mut search = self.search;
+        let mut reset = self.reset;
+
+        search.write().push_str(key);
+        if let Some(task) = reset.take() {
+            clear_timeout(task);
+        }
+        reset.set(Some(set_timeout(
+            move || {
+                search.write().clear();
+                reset.set(None);
+            },
+            TYPEAHEAD_RESET_MS,
+        )));
+
+        search.cloned()
+    }
+
+    /// Whether a search is in progress, in which case Space is part of it rather than
+    /// selecting the focused item.
+    pub fn is_searching(&self) -> bool {
+        !self.search.peek().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILES: [&str; 5] = ["New file", "Open…", "open recent", "Save", "Save as…"];
+
+    #[test]
+    fn matches_a_prefix_ignoring_case() {
+        assert_eq!(typeahead_match(&FILES, None, "o"), Some(1));
+        assert_eq!(typeahead_match(&FILES, None, "OPEN R"), Some(2));
+        assert_eq!(typeahead_match(&FILES, None, "x"), None);
+        assert_eq!(typeahead_match(&[], None, "a"), None);
+    }
+
+    #[test]
+    fn single_letter_moves_past_the_current_item_and_wraps() {
+        assert_eq!(typeahead_match(&FILES, Some(1), "o"), Some(2));
+        assert_eq!(typeahead_match(&FILES, Some(2), "o"), Some(1));
+        assert_eq!(typeahead_match(&FILES, Some(3), "n"), Some(0));
+    }
+
+    #[test]
+    fn repeating_a_letter_cycles_through_its_items() {
+        assert_eq!(typeahead_match(&FILES, Some(3), "ss"), Some(4));
+        assert_eq!(typeahead_match(&FILES, Some(4), "sss"), Some(3));
+    }
+
+    #[test]
+    fn longer_search_keeps_the_current_item_while_it_matches() {
+        assert_eq!(typeahead_match(&FILES, Some(3), "sa"), Some(3));
+        assert_eq!(typeahead_match(&FILES, Some(3), "save "), Some(4));
+    }
+
+    #[test]
+    fn ignores_leading_whitespace() {
+        assert_eq!(typeahead_match(&["  Copy", "Cut"], None, "co"), Some(0));
+    }
+}
@@ -0,0 +1,2745 @@
+//! Headless interaction tests: render into a [`TestDom`], click and type, then assert on
+//! the ARIA and `data-state` attributes the components expose.
+
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_components::platform::{self, ListenerEvent};
+use dioxus_components::testing::{TestDom, TestNode};
+use dioxus_components::*;
+
+/// Long enough for a typeahead search to reset, with slack for timer threads that wake
+/// late while the other tests run.
+const TYPEAHEAD_RESET: Duration = Duration::from_millis(1200);
+
+/* -------------------------------------------------------------------------------------------------
+ * Accordion
+ * -----------------------------------------------------------------------------------------------*/
+
+fn faq(accordion_type: AccordionType) -> Element {
+    rsx! {
+        Accordion {
+            accordion_type,
+            AccordionItem {
+                value: "shipping",
+                AccordionTrigger { "Shipping" }
+                AccordionContent { "Ships in 2 days." }
+            }
+            AccordionItem {
+                value: "returns",
+                AccordionTrigger { "Returns" }
+                AccordionContent { "30 day returns." }
+            }
+            AccordionItem {
+                value: "warranty",
+                disabled: true,
+                AccordionTrigger { "Warranty" }
+                AccordionContent { "One year." }
+            }
+        }
+    }
+}
+
+#[test]
+fn accordion_single_opens_one_item_at_a_time() {
+    let mut dom = TestDom::with_props(faq, AccordionType::Single { collapsible: true });
+    let shipping = dom.get_by_role_named("button", "Shipping");
+    let returns = dom.get_by_role_named("button", "Returns");
+    assert_eq!(dom.attribute(shipping, "aria-expanded"), Some("false"));
+    assert_eq!(dom.attribute(shipping, "data-state"), Some("closed"));
+
+    dom.click(shipping);
+    assert_eq!(dom.attribute(shipping, "aria-expanded"), Some("true"));
+    assert_eq!(dom.attribute(shipping, "data-state"), Some("open"));
+
+    dom.click(returns);
+    assert_eq!(dom.attribute(shipping, "data-state"), Some("closed"));
+    assert_eq!(dom.attribute(returns, "data-state"), Some("open"));
+
+    dom.click(returns);
+    assert_eq!(dom.attribute(returns, "data-state"), Some("closed"));
+}
+
+#[test]
+fn accordion_single_not_collapsible_stays_open() {
+    let mut dom = TestDom::with_props(faq, AccordionType::Single { collapsible: false });
+    let shipping = dom.get_by_role_named("button", "Shipping");
+
+    dom.click(shipping);
+    dom.click(shipping);
+    assert_eq!(dom.attribute(shipping, "aria-expanded"), Some("true"));
+}
+
+#[test]
+fn accordion_multiple_keeps_items_open() {
+    let mut dom = TestDom::with_props(faq, AccordionType::Multiple);
+    let shipping = dom.get_by_role_named("button", "Shipping");
+    let returns = dom.get_by_role_named("button", "Returns");
+
+    dom.click(shipping);
+    dom.click(returns);
+    assert_eq!(dom.attribute(shipping, "data-state"), Some("open"));
+    assert_eq!(dom.attribute(returns, "data-state"), Some("open"));
+}
+
+#[test]
+fn accordion_disabled_item_ignores_clicks_and_keys() {
+    let mut dom = TestDom::with_props(faq, AccordionType::Multiple);
+    let warranty = dom.get_by_role_named("button", "Warranty");
+
+    dom.click(warranty);
+    assert_eq!(dom.attribute(warranty, "data-state"), Some("closed"));
+    assert_eq!(dom.focused(), None);
+}
+
+#[test]
+fn accordion_trigger_toggles_with_keyboard() {
+    let mut dom = TestDom::with_props(faq, AccordionType::Multiple);
+    let shipping = dom.get_by_role_named("button", "Shipping");
+
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(shipping));
+
+    dom.press("Enter");
+    assert_eq!(dom.attribute(shipping, "aria-expanded"), Some("true"));
+
+    dom.press(" ");
+    assert_eq!(dom.attribute(shipping, "aria-expanded"), Some("false"));
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Alert Dialog
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn DeleteProject(succeeds: bool) -> Element {
+    let mut deleted = use_signal(|| false);
+
+    rsx! {
+        AlertDialog {
+            AlertDialogTrigger { "Delete project" }
+            AlertDialogContent {
+                AlertDialogTitle { "Delete this project?" }
+                AlertDialogDescription { "This cannot be undone." }
+                AlertDialogAction {
+                    variant: ButtonVariant::Destructive,
+                    on_action: move |_| -> AlertDialogActionFuture {
+                        Box::pin(async move {
+                            platform::sleep(50).await;
+                            if succeeds {
+                                deleted.set(true);
+                            }
+                            succeeds
+                        })
+                    },
+                    "Delete"
+                }
+                AlertDialogCancel { "Cancel" }
+            }
+        }
+        p { "deleted: {deleted}" }
+    }
+}
+
+#[test]
+fn alert_dialog_focuses_cancel_and_closes_from_it() {
+    let mut dom = TestDom::with_props(DeleteProject, DeleteProjectProps { succeeds: true });
+    dom.click(dom.get_by_text("Delete project"));
+
+    let dialog = dom.get_by_role("alertdialog");
+    assert_eq!(dom.accessible_name(dialog), "Delete this project?");
+    let cancel = dom.get_by_role_named("button", "Cancel");
+    assert_eq!(dom.focused(), Some(cancel));
+
+    dom.click(cancel);
+    assert_eq!(dom.query_by_role("alertdialog"), None);
+    dom.get_by_text("deleted: false");
+}
+
+#[test]
+fn alert_dialog_async_action_stays_open_until_it_resolves() {
+    let mut dom = TestDom::with_props(DeleteProject, DeleteProjectProps { succeeds: true });
+    dom.click(dom.get_by_text("Delete project"));
+
+    let action = dom.get_by_role_named("button", "Delete");
+    dom.click(action);
+    assert_eq!(dom.attribute(action, "aria-busy"), Some("true"));
+    assert!(dom.has_attribute(action, "disabled"));
+    dom.get_by_role("status");
+
+    // Escape is ignored while the action runs
+    dom.press("Escape");
+    assert!(dom.query_by_role("alertdialog").is_some());
+
+    dom.wait(Duration::from_millis(100));
+    assert_eq!(dom.query_by_role("alertdialog"), None);
+    dom.get_by_text("deleted: true");
+}
+
+#[test]
+fn alert_dialog_failed_action_keeps_it_open() {
+    let mut dom = TestDom::with_props(DeleteProject, DeleteProjectProps { succeeds: false });
+    dom.click(dom.get_by_text("Delete project"));
+    dom.click(dom.get_by_role_named("button", "Delete"));
+
+    dom.wait(Duration::from_millis(100));
+    let action = dom.get_by_role_named("button", "Delete");
+    assert_eq!(dom.attribute(action, "aria-busy"), Some("false"));
+    assert_eq!(dom.query_by_role("status"), None);
+    assert!(dom.query_by_role("alertdialog").is_some());
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Checkbox
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn Terms(default_checked: CheckedState, disabled: bool) -> Element {
+    rsx! {
+        CheckboxProvider {
+            default_checked,
+            disabled,
+            name: "terms",
+            CheckboxTrigger { CheckboxIndicator {} }
+            CheckboxLabel { "Accept terms" }
+        }
+    }
+}
+
+#[test]
+fn checkbox_click_toggles_checked_state() {
+    let mut dom = TestDom::with_props(
+        Terms,
+        TermsProps {
+            default_checked: CheckedState::Unchecked,
+            disabled: false,
+        },
+    );
+    let checkbox = dom.get_by_role("checkbox");
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+
+    dom.click(checkbox);
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(checkbox, "data-state"), Some("checked"));
+
+    dom.click(checkbox);
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+    assert_eq!(dom.attribute(checkbox, "data-state"), Some("unchecked"));
+}
+
+#[test]
+fn checkbox_label_click_toggles_checkbox() {
+    let mut dom = TestDom::with_props(
+        Terms,
+        TermsProps {
+            default_checked: CheckedState::Unchecked,
+            disabled: false,
+        },
+    );
+    let checkbox = dom.get_by_label("Accept terms");
+
+    dom.click(dom.get_by_text("Accept terms"));
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("true"));
+}
+
+#[test]
+fn checkbox_indeterminate_becomes_checked() {
+    let mut dom = TestDom::with_props(
+        Terms,
+        TermsProps {
+            default_checked: CheckedState::Indeterminate,
+            disabled: false,
+        },
+    );
+    let checkbox = dom.get_by_role("checkbox");
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("mixed"));
+
+    dom.click(checkbox);
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("true"));
+}
+
+#[test]
+fn checkbox_space_toggles_and_enter_does_not() {
+    let mut dom = TestDom::with_props(
+        Terms,
+        TermsProps {
+            default_checked: CheckedState::Unchecked,
+            disabled: false,
+        },
+    );
+    let checkbox = dom.get_by_role("checkbox");
+
+    dom.focus(checkbox);
+    dom.press("Enter");
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+
+    dom.press(" ");
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("true"));
+}
+
+#[test]
+fn checkbox_disabled_ignores_clicks() {
+    let mut dom = TestDom::with_props(
+        Terms,
+        TermsProps {
+            default_checked: CheckedState::Unchecked,
+            disabled: true,
+        },
+    );
+    let checkbox = dom.get_by_role("checkbox");
+
+    dom.click(checkbox);
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+    assert!(dom.has_attribute(checkbox, "data-disabled"));
+}
+
+#[component]
+fn ControlledTerms(accept_changes: bool) -> Element {
+    let mut checked = use_signal(|| CheckedState::Unchecked);
+    let mut changes = use_signal(Vec::<CheckedState>::new);
+
+    rsx! {
+        CheckboxProvider {
+            checked: checked(),
+            onchange: move |state| {
+                changes.write().push(state);
+                if accept_changes {
+                    checked.set(state);
+                }
+            },
+            CheckboxTrigger { CheckboxIndicator {} }
+        }
+        p { "changes: {changes.read().len()}" }
+    }
+}
+
+#[test]
+fn checkbox_onchange_reports_new_state() {
+    let mut dom = TestDom::with_props(
+        ControlledTerms,
+        ControlledTermsProps {
+            accept_changes: true,
+        },
+    );
+    let checkbox = dom.get_by_role("checkbox");
+
+    dom.click(checkbox);
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("true"));
+    dom.get_by_text("changes: 1");
+
+    dom.click(checkbox);
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+    dom.get_by_text("changes: 2");
+}
+
+#[test]
+fn checkbox_controlled_state_ignores_rejected_changes() {
+    let mut dom = TestDom::with_props(
+        ControlledTerms,
+        ControlledTermsProps {
+            accept_changes: false,
+        },
+    );
+    let checkbox = dom.get_by_role("checkbox");
+
+    dom.click(checkbox);
+    dom.get_by_text("changes: 1");
+    assert_eq!(dom.attribute(checkbox, "aria-checked"), Some("false"));
+    assert_eq!(dom.attribute(checkbox, "data-state"), Some("unchecked"));
+}
+
+#[component]
+fn SignupForm() -> Element {
+    let mut changes = use_signal(Vec::<CheckedState>::new);
+
+    rsx! {
+        form {
+            id: "signup",
+            "data-testid": "signup",
+            CheckboxProvider {
+                default_checked: CheckedState::Checked,
+                name: "newsletter",
+                onchange: move |state| changes.write().push(state),
+                CheckboxTrigger { CheckboxIndicator {} }
+                CheckboxLabel { "Newsletter" }
+            }
+        }
+        // Associated through the `form` attribute rather than nesting
+        CheckboxProvider {
+            form: "signup",
+            name: "terms",
+            CheckboxTrigger { CheckboxIndicator {} }
+            CheckboxLabel { "Accept terms" }
+        }
+        p { "newsletter changes: {changes:?}" }
+    }
+}
+
+#[test]
+fn checkbox_form_reset_restores_default_checked() {
+    let mut dom = TestDom::new(SignupForm);
+    let newsletter = dom.get_by_label("Newsletter");
+    let terms = dom.get_by_label("Accept terms");
+
+    dom.click(newsletter);
+    dom.click(terms);
+    assert_eq!(dom.attribute(newsletter, "aria-checked"), Some("false"));
+    assert_eq!(dom.attribute(terms, "aria-checked"), Some("true"));
+
+    dom.reset(dom.get_by_test_id("signup"));
+    assert_eq!(dom.attribute(newsletter, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(terms, "aria-checked"), Some("false"));
+    dom.get_by_text("newsletter changes: [Unchecked, Checked]");
+}
+
+#[component]
+fn Channels() -> Element {
+    let mut selected = use_signal(|| vec!["email".to_string()]);
+
+    rsx! {
+        form {
+            "data-testid": "channels",
+            CheckboxGroup {
+                name: "channels",
+                value: selected(),
+                onchange: move |values| selected.set(values),
+                CheckboxGroupSelectAll {
+                    CheckboxTrigger { CheckboxIndicator {} }
+                    CheckboxLabel { "All channels" }
+                }
+                for channel in ["email", "sms", "push"] {
+                    CheckboxProvider {
+                        key: "{channel}",
+                        value: channel,
+                        CheckboxTrigger { CheckboxIndicator {} }
+                        CheckboxLabel { "{channel}" }
+                    }
+                }
+            }
+        }
+        p { "selected: {selected:?}" }
+    }
+}
+
+#[test]
+fn checkbox_group_select_all_aggregates_items() {
+    let mut dom = TestDom::new(Channels);
+    let all = dom.get_by_label("All channels");
+    let email = dom.get_by_label("email");
+    let sms = dom.get_by_label("sms");
+    assert_eq!(dom.attribute(email, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(all, "aria-checked"), Some("mixed"));
+
+    dom.click(all);
+    assert_eq!(dom.attribute(all, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(sms, "aria-checked"), Some("true"));
+    dom.get_by_text(r#"selected: ["email", "sms", "push"]"#);
+
+    dom.click(sms);
+    assert_eq!(dom.attribute(all, "aria-checked"), Some("mixed"));
+    assert_eq!(dom.attribute(all, "data-state"), Some("indeterminate"));
+
+    dom.click(all);
+    assert_eq!(dom.attribute(all, "aria-checked"), Some("true"));
+    dom.click(all);
+    assert_eq!(dom.attribute(all, "aria-checked"), Some("false"));
+    assert_eq!(dom.attribute(email, "aria-checked"), Some("false"));
+    dom.get_by_text("selected: []");
+}
+
+#[test]
+fn checkbox_group_submits_checked_values_under_its_name() {
+    let mut dom = TestDom::new(Channels);
+    let form = dom.get_by_test_id("channels");
+    assert_eq!(
+        dom.form_data(form),
+        vec![("channels".to_string(), "email".to_string())]
+    );
+
+    dom.click(dom.get_by_label("push"));
+    assert_eq!(
+        dom.form_data(form),
+        vec![
+            ("channels".to_string(), "email".to_string()),
+            ("channels".to_string(), "push".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn checkbox_group_form_reset_restores_default_value() {
+    let mut dom = TestDom::from_element(rsx! {
+        form {
+            "data-testid": "channels",
+            CheckboxGroup {
+                name: "channels",
+                default_value: vec!["sms".to_string()],
+                CheckboxGroupSelectAll {
+                    CheckboxTrigger { CheckboxIndicator {} }
+                    CheckboxLabel { "All channels" }
+                }
+                CheckboxProvider {
+                    value: "email",
+                    CheckboxTrigger { CheckboxIndicator {} }
+                    CheckboxLabel { "Email" }
+                }
+                CheckboxProvider {
+                    value: "sms",
+                    CheckboxTrigger { CheckboxIndicator {} }
+                    CheckboxLabel { "SMS" }
+                }
+            }
+        }
+    });
+    let form = dom.get_by_test_id("channels");
+    let all = dom.get_by_label("All channels");
+
+    dom.click(all);
+    assert_eq!(dom.form_data(form).len(), 2);
+
+    dom.reset(form);
+    assert_eq!(dom.attribute(all, "aria-checked"), Some("mixed"));
+    assert_eq!(
+        dom.form_data(form),
+        vec![("channels".to_string(), "sms".to_string())]
+    );
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Combobox
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn LanguagePicker() -> Element {
+    let mut changes = use_signal(Vec::<Vec<String>>::new);
+
+    rsx! {
+        Combobox {
+            options: vec![
+                ComboboxOption::new("rs", "Rust"),
+                ComboboxOption::new("rb", "Ruby").disabled(true),
+                ComboboxOption::new("py", "Python"),
+                ComboboxOption::new("ts", "TypeScript"),
+            ],
+            on_value_change: move |value| changes.write().push(value),
+            ComboboxInput { aria_label: "Language" }
+            ComboboxContent {
+                ComboboxEmpty { "No language found." }
+            }
+        }
+        p { "changes: {changes:?}" }
+    }
+}
+
+#[test]
+fn combobox_filters_as_you_type_and_highlights_with_activedescendant() {
+    let mut dom = TestDom::new(LanguagePicker);
+    let input = dom.get_by_role_named("combobox", "Language");
+    assert_eq!(dom.attribute(input, "aria-expanded"), Some("false"));
+    dom.focus(input);
+
+    dom.input(input, "r");
+    assert_eq!(dom.attribute(input, "aria-expanded"), Some("true"));
+    assert_eq!(dom.get_all_by_role("option").len(), 3);
+    // The first enabled match is highlighted, while focus stays in the input
+    let rust = dom.get_by_role_named("option", "Rust");
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(rust, "id")
+    );
+    assert!(dom.has_attribute(rust, "data-highlighted"));
+    assert_eq!(dom.focused(), Some(input));
+
+    dom.input(input, "");
+    dom.press("ArrowDown");
+    let python = dom.get_by_role_named("option", "Python");
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(python, "id")
+    );
+    dom.press("ArrowUp");
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(rust, "id")
+    );
+
+    dom.input(input, "script");
+    dom.press("Enter");
+    assert_eq!(dom.query_by_role("listbox"), None);
+    assert_eq!(dom.attribute(input, "value"), Some("TypeScript"));
+    assert_eq!(dom.attribute(input, "aria-activedescendant"), None);
+    dom.get_by_text(r#"changes: [["ts"]]"#);
+}
+
+#[test]
+fn combobox_shows_the_empty_state_and_escape_restores_or_clears() {
+    let mut dom = TestDom::new(LanguagePicker);
+    let input = dom.get_by_role_named("combobox", "Language");
+    dom.focus(input);
+
+    dom.press("ArrowDown");
+    dom.click(dom.get_by_role_named("option", "Python"));
+    assert_eq!(dom.attribute(input, "value"), Some("Python"));
+
+    dom.input(input, "cobol");
+    assert!(dom.get_all_by_role("option").is_empty());
+    dom.get_by_text("No language found.");
+
+    // Escape closes the list and puts the selected label back
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("listbox"), None);
+    assert_eq!(dom.attribute(input, "value"), Some("Python"));
+
+    // Escape on the closed list clears the selection
+    dom.press("Escape");
+    assert_eq!(dom.attribute(input, "value"), Some(""));
+    dom.get_by_text(r#"changes: [["py"], []]"#);
+}
+
+#[component]
+fn ReviewerPicker() -> Element {
+    let load = move |query: String| -> ComboboxLoadFuture {
+        Box::pin(async move {
+            platform::sleep(50).await;
+            ["Ada", "Alan", "Grace"]
+                .into_iter()
+                .filter(|name| name.to_lowercase().starts_with(&query.to_lowercase()))
+                .map(|name| ComboboxOption::new(name.to_lowercase(), name))
+                .collect()
+        })
+    };
+
+    rsx! {
+        form {
+            "data-testid": "review",
+            Combobox {
+                multiple: true,
+                name: "reviewers",
+                default_value: vec!["grace".to_string()],
+                // Labels the default value until a load lists it
+                options: vec![ComboboxOption::new("grace", "Grace")],
+                load,
+                ComboboxInput { aria_label: "Reviewers" }
+                ComboboxContent {
+                    ComboboxEmpty { "No one matches." }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn combobox_loads_options_with_a_spinner() {
+    let mut dom = TestDom::new(ReviewerPicker);
+    let input = dom.get_by_role_named("combobox", "Reviewers");
+    dom.focus(input);
+
+    dom.input(input, "a");
+    let listbox = dom.get_by_role("listbox");
+    assert_eq!(dom.attribute(listbox, "aria-busy"), Some("true"));
+    dom.get_by_role_named("status", "Loading");
+    // The empty state waits for the load
+    assert_eq!(dom.query_by_text("No one matches."), None);
+
+    dom.wait(Duration::from_millis(100));
+    assert_eq!(dom.attribute(listbox, "aria-busy"), Some("false"));
+    assert_eq!(dom.query_by_role_named("status", "Loading"), None);
+    assert_eq!(dom.get_all_by_role("option").len(), 2);
+
+    dom.input(input, "z");
+    dom.wait(Duration::from_millis(100));
+    dom.get_by_text("No one matches.");
+}
+
+#[test]
+fn combobox_multiple_selections_are_removable_badges() {
+    let mut dom = TestDom::new(ReviewerPicker);
+    let form = dom.get_by_test_id("review");
+    let input = dom.get_by_role_named("combobox", "Reviewers");
+    assert_eq!(
+        dom.form_data(form),
+        vec![("reviewers".to_string(), "grace".to_string())]
+    );
+    dom.focus(input);
+
+    dom.input(input, "ad");
+    dom.wait(Duration::from_millis(100));
+    let listbox = dom.get_by_role("listbox");
+    assert_eq!(dom.attribute(listbox, "aria-multiselectable"), Some("true"));
+    dom.press("Enter");
+    // The list stays open for the next pick, with the query cleared
+    assert_eq!(dom.attribute(input, "value"), Some(""));
+    assert!(dom.query_by_role("listbox").is_some());
+    dom.get_by_role_named("button", "Remove Ada");
+    assert_eq!(
+        dom.form_data(form),
+        vec![
+            ("reviewers".to_string(), "grace".to_string()),
+            ("reviewers".to_string(), "ada".to_string()),
+        ]
+    );
+
+    dom.click(dom.get_by_role_named("button", "Remove Grace"));
+    assert_eq!(dom.query_by_role_named("button", "Remove Grace"), None);
+    assert_eq!(dom.focused(), Some(input));
+
+    // Backspace in the empty input removes the last badge
+    dom.press("Backspace");
+    assert_eq!(dom.query_by_role_named("button", "Remove Ada"), None);
+    assert!(dom.form_data(form).is_empty());
+}
+
+#[component]
+fn DetachedLanguageForm() -> Element {
+    rsx! {
+        form { id: "profile", "data-testid": "profile" }
+        // Associated through the `form` attribute rather than nesting
+        Combobox {
+            form: "profile",
+            name: "language",
+            default_value: vec!["rs".to_string()],
+            options: vec![
+                ComboboxOption::new("rs", "Rust"),
+                ComboboxOption::new("py", "Python"),
+            ],
+            ComboboxInput { aria_label: "Language" }
+            ComboboxContent {}
+        }
+    }
+}
+
+#[test]
+fn combobox_form_reset_follows_the_form_attribute() {
+    let mut dom = TestDom::new(DetachedLanguageForm);
+    let form = dom.get_by_test_id("profile");
+    let input = dom.get_by_role_named("combobox", "Language");
+    dom.focus(input);
+    dom.input(input, "py");
+    dom.press("Enter");
+    assert_eq!(
+        dom.form_data(form),
+        vec![("language".to_string(), "py".to_string())]
+    );
+
+    dom.reset(form);
+    assert_eq!(dom.attribute(input, "value"), Some("Rust"));
+    assert_eq!(
+        dom.form_data(form),
+        vec![("language".to_string(), "rs".to_string())]
+    );
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Command
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn CommandMenu() -> Element {
+    let mut ran = use_signal(Vec::<String>::new);
+
+    rsx! {
+        Command {
+            CommandInput { aria_label: "Search commands" }
+            CommandList {
+                CommandEmpty { "No results found." }
+                CommandGroup {
+                    heading: "Suggestions",
+                    "data-testid": "suggestions",
+                    CommandItem { on_select: move |value| ran.write().push(value), "Calendar" }
+                    CommandItem { on_select: move |value| ran.write().push(value), "Emoji Search" }
+                    CommandItem { disabled: true, "Calculator" }
+                }
+                CommandSeparator {}
+                CommandGroup {
+                    heading: "Settings",
+                    "data-testid": "settings",
+                    CommandItem { "Profile" }
+                    CommandItem {
+                        keywords: vec!["preferences".to_string()],
+                        on_select: move |value| ran.write().push(value),
+                        "Settings"
+                        CommandShortcut { "⌘S" }
+                    }
+                }
+            }
+        }
+        p { "ran: {ran:?}" }
+    }
+}
+
+/// The shown option with `data-value` equal to `value`.
+fn command_option(dom: &TestDom, value: &str) -> TestNode {
+    dom.get_all_by_role("option")
+        .into_iter()
+        .find(|&option| dom.attribute(option, "data-value") == Some(value))
+        .unwrap_or_else(|| panic!("no option {value:?} is shown"))
+}
+
+#[test]
+fn command_ranks_fuzzy_matches_and_navigates_them_in_order() {
+    let mut dom = TestDom::new(CommandMenu);
+    let input = dom.get_by_role_named("combobox", "Search commands");
+    dom.focus(input);
+    assert_eq!(dom.get_all_by_role("option").len(), 5);
+    assert_eq!(dom.get_all_by_role("group").len(), 2);
+
+    // "Settings" starts with the query, "Emoji Search" has it at a word start
+    dom.input(input, "se");
+    assert_eq!(dom.get_all_by_role("option").len(), 2);
+    let settings = command_option(&dom, "Settings");
+    let emoji = command_option(&dom, "Emoji Search");
+    // Settings moves above the hidden Profile, and its group above Suggestions
+    assert_eq!(dom.attribute(settings, "style"), Some("order: 0;"));
+    let settings_group = dom.get_by_test_id("settings");
+    assert_eq!(dom.attribute(settings_group, "style"), Some("order: 0;"));
+    let suggestions_group = dom.get_by_test_id("suggestions");
+    assert_eq!(dom.attribute(suggestions_group, "style"), Some("order: 1;"));
+
+    // The best match is highlighted, and the arrows follow the ranking
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(settings, "id")
+    );
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(emoji, "id")
+    );
+    assert!(dom.has_attribute(emoji, "data-highlighted"));
+    // Without `loop` the last item stays highlighted
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(emoji, "id")
+    );
+    assert_eq!(dom.focused(), Some(input));
+
+    dom.press("Enter");
+    dom.get_by_text(r#"ran: ["Emoji Search"]"#);
+
+    // Keywords match too, and clicking selects
+    dom.input(input, "prefs");
+    assert_eq!(dom.get_all_by_role("option").len(), 1);
+    dom.click(command_option(&dom, "Settings"));
+    dom.get_by_text(r#"ran: ["Emoji Search", "Settings"]"#);
+}
+
+#[test]
+fn command_skips_disabled_items_and_shows_the_empty_state() {
+    let mut dom = TestDom::new(CommandMenu);
+    let input = dom.get_by_role_named("combobox", "Search commands");
+    dom.focus(input);
+    assert_eq!(dom.query_by_text("No results found."), None);
+    assert!(dom.query_by_role("separator").is_some());
+
+    dom.input(input, "ca");
+    assert_eq!(dom.get_all_by_role("option").len(), 2);
+    dom.press("End");
+    // Calculator is disabled, so the last item to highlight is Calendar
+    assert_eq!(
+        dom.attribute(input, "aria-activedescendant"),
+        dom.attribute(command_option(&dom, "Calendar"), "id")
+    );
+    dom.click(command_option(&dom, "Calculator"));
+    dom.get_by_text("ran: []");
+    // Groups sort, so separators are hidden while searching
+    assert_eq!(dom.query_by_role("separator"), None);
+
+    dom.input(input, "xyz");
+    assert!(dom.get_all_by_role("option").is_empty());
+    assert!(dom.get_all_by_role("group").is_empty());
+    assert_eq!(dom.attribute(input, "aria-activedescendant"), None);
+    dom.get_by_text("No results found.");
+    dom.press("Enter");
+    dom.get_by_text("ran: []");
+}
+
+#[component]
+fn PaletteApp() -> Element {
+    let mut ran = use_signal(Vec::<String>::new);
+    let mut open = use_signal(|| false);
+
+    rsx! {
+        input { aria_label: "Notes" }
+        CommandDialog {
+            open: open(),
+            on_open_change: move |next| open.set(next),
+            CommandInput { aria_label: "Search commands" }
+            CommandList {
+                CommandItem {
+                    on_select: move |value| {
+                        ran.write().push(value);
+                        open.set(false);
+                    },
+                    "New file"
+                }
+            }
+        }
+        p { "ran: {ran:?}" }
+    }
+}
+
+#[test]
+fn command_dialog_toggles_with_its_hotkey() {
+    let mut dom = TestDom::new(PaletteApp);
+    dom.focus(dom.get_by_role_named("textbox", "Notes"));
+    dom.press("k");
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    // Ctrl+K, or Cmd+K on macOS, opens the palette with the search focused
+    dom.press("Control+k");
+    let dialog = dom.get_by_role("dialog");
+    assert_eq!(dom.accessible_name(dialog), "Command Palette");
+    let input = dom.get_by_role_named("combobox", "Search commands");
+    assert_eq!(dom.focused(), Some(input));
+
+    dom.press("Meta+k");
+    assert_eq!(dom.query_by_role("dialog"), None);
+    dom.press("Meta+Shift+k");
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    dom.press("Meta+k");
+    let input = dom.get_by_role_named("combobox", "Search commands");
+    dom.input(input, "new");
+    dom.press("Enter");
+    assert_eq!(dom.query_by_role("dialog"), None);
+    dom.get_by_text(r#"ran: ["New file"]"#);
+}
+
+#[test]
+fn command_dialog_rebinds_when_its_hotkey_changes() {
+    #[component]
+    fn RebindablePalette() -> Element {
+        let mut hotkey = use_signal(|| "mod+k");
+
+        rsx! {
+            button { onclick: move |_| hotkey.set("mod+p"), "Rebind" }
+            CommandDialog {
+                hotkey: hotkey(),
+                CommandInput { aria_label: "Search commands" }
+                CommandList {
+                    CommandItem { "New file" }
+                }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(RebindablePalette);
+    dom.click(dom.get_by_role_named("button", "Rebind"));
+
+    dom.press("Control+k");
+    assert_eq!(dom.query_by_role("dialog"), None);
+    dom.press("Control+p");
+    assert!(dom.query_by_role("dialog").is_some());
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenu
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn FileBrowser(disabled: bool, long_press: bool) -> Element {
+    let mut log = use_signal(Vec::<String>::new);
+
+    rsx! {
+        p { "data-testid": "log", "{log:?}" }
+        ContextMenu {
+            on_open_change: move |open: bool| log.write().push(format!("open: {open}")),
+            ContextMenuTrigger {
+                "data-testid": "files",
+                disabled,
+                long_press,
+                button { "notes.md" }
+            }
+            ContextMenuContent {
+                ContextMenuItem {
+                    on_select: move |_| log.write().push("rename".to_string()),
+                    "Rename"
+                }
+                ContextMenuItem { disabled: true, "Paste" }
+                ContextMenuItem { "Delete" }
+            }
+        }
+    }
+}
+
+#[test]
+fn context_menu_opens_on_right_click_and_escape_restores_focus() {
+    let mut dom = TestDom::with_props(
+        FileBrowser,
+        FileBrowserProps {
+            disabled: false,
+            long_press: false,
+        },
+    );
+    let area = dom.get_by_test_id("files");
+    let file = dom.get_by_role_named("button", "notes.md");
+
+    dom.context_menu(file, 120.0, 80.0);
+    assert_eq!(dom.attribute(area, "data-state"), Some("open"));
+    let menu = dom.get_by_role("menu");
+    assert_eq!(dom.focused(), Some(menu));
+    // The area isn't a button, so it doesn't name the menu
+    assert!(!dom.has_attribute(menu, "aria-labelledby"));
+
+    dom.press("ArrowDown");
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Delete"))
+    );
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("menu"), None);
+    assert_eq!(dom.focused(), Some(file));
+}
+
+#[test]
+fn context_menu_item_selects_and_closes() {
+    let mut dom = TestDom::with_props(
+        FileBrowser,
+        FileBrowserProps {
+            disabled: false,
+            long_press: false,
+        },
+    );
+    let area = dom.get_by_test_id("files");
+
+    dom.context_menu(area, 10.0, 10.0);
+    dom.click(dom.get_by_role_named("menuitem", "Rename"));
+    assert_eq!(dom.query_by_role("menu"), None);
+    assert_eq!(
+        dom.text(dom.get_by_test_id("log")),
+        r#"["open: true", "rename", "open: false"]"#
+    );
+}
+
+#[test]
+fn context_menu_disabled_trigger_leaves_right_click_alone() {
+    let mut dom = TestDom::with_props(
+        FileBrowser,
+        FileBrowserProps {
+            disabled: true,
+            long_press: true,
+        },
+    );
+    let area = dom.get_by_test_id("files");
+    assert!(dom.has_attribute(area, "data-disabled"));
+
+    dom.context_menu(area, 10.0, 10.0);
+    dom.long_press(area, 10.0, 10.0, Duration::from_millis(800));
+    assert_eq!(dom.query_by_role("menu"), None);
+}
+
+#[test]
+fn context_menu_opens_on_a_long_touch_press() {
+    let mut dom = TestDom::with_props(
+        FileBrowser,
+        FileBrowserProps {
+            disabled: false,
+            long_press: true,
+        },
+    );
+    let area = dom.get_by_test_id("files");
+
+    // Lifting early is a tap
+    dom.long_press(area, 40.0, 20.0, Duration::from_millis(100));
+    dom.wait(Duration::from_millis(800));
+    assert_eq!(dom.query_by_role("menu"), None);
+
+    dom.long_press(area, 40.0, 20.0, Duration::from_millis(800));
+    assert_eq!(dom.attribute(area, "data-state"), Some("open"));
+    assert!(dom.query_by_role("menu").is_some());
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Dialog
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn EditProfile() -> Element {
+    rsx! {
+        Dialog {
+            DialogTrigger { "Edit profile" }
+            DialogContent {
+                DialogTitle { "Edit profile" }
+                DialogDescription { "Make changes to your profile." }
+                DialogClose { "Cancel" }
+            }
+        }
+    }
+}
+
+#[test]
+fn dialog_trigger_opens_and_close_closes() {
+    let mut dom = TestDom::new(EditProfile);
+    let trigger = dom.get_by_role_named("button", "Edit profile");
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("false"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    dom.click(trigger);
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("true"));
+    assert_eq!(dom.attribute(trigger, "data-state"), Some("open"));
+
+    let dialog = dom.get_by_role("dialog");
+    assert_eq!(dom.attribute(dialog, "aria-modal"), Some("true"));
+    assert_eq!(
+        dom.attribute(trigger, "aria-controls"),
+        dom.attribute(dialog, "id")
+    );
+    assert_eq!(dom.accessible_name(dialog), "Edit profile");
+
+    dom.click(dom.get_by_role_named("button", "Cancel"));
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("false"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert!(!dom.is_connected(dialog));
+}
+
+#[component]
+fn ControlledDialog() -> Element {
+    let mut open = use_signal(|| false);
+
+    rsx! {
+        button { onclick: move |_| open.toggle(), "Toggle from outside" }
+        Dialog {
+            open: open(),
+            on_open_change: move |next| open.set(next),
+            DialogContent {
+                DialogTitle { "Edit profile" }
+                DialogClose { "Cancel" }
+            }
+        }
+    }
+}
+
+#[test]
+fn dialog_follows_a_controlled_open_changed_from_outside() {
+    let mut dom = TestDom::new(ControlledDialog);
+    let toggle = dom.get_by_role_named("button", "Toggle from outside");
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    dom.click(toggle);
+    dom.get_by_role("dialog");
+
+    dom.click(dom.get_by_role_named("button", "Cancel"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    dom.click(toggle);
+    dom.get_by_role("dialog");
+}
+
+#[test]
+fn dialog_controlled_open_stays_open_when_a_close_is_rejected() {
+    #[component]
+    fn UnsavedChangesDialog() -> Element {
+        let mut requests = use_signal(Vec::<bool>::new);
+
+        rsx! {
+            p { "data-testid": "requests", "{requests:?}" }
+            Dialog {
+                open: true,
+                on_open_change: move |next| requests.write().push(next),
+                DialogContent {
+                    DialogTitle { "Unsaved changes" }
+                    DialogClose { "Cancel" }
+                }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(UnsavedChangesDialog);
+    dom.click(dom.get_by_role_named("button", "Cancel"));
+    assert_eq!(dom.text(dom.get_by_test_id("requests")), "[false]");
+    dom.get_by_role_named("dialog", "Unsaved changes");
+
+    dom.press("Escape");
+    assert_eq!(dom.text(dom.get_by_test_id("requests")), "[false, false]");
+    dom.get_by_role_named("dialog", "Unsaved changes");
+}
+
+#[component]
+fn AsyncForm() -> Element {
+    let mut loaded = use_signal(|| false);
+
+    rsx! {
+        Dialog {
+            DialogTrigger { "Edit profile" }
+            DialogContent {
+                DialogTitle { "Edit profile" }
+                button { onclick: move |_| loaded.set(true), "Load" }
+                if loaded() {
+                    input { aria_label: "Name" }
+                }
+                DialogClose { "Cancel" }
+            }
+        }
+    }
+}
+
+#[test]
+fn dialog_traps_focus_including_late_content_and_restores_it() {
+    let mut dom = TestDom::new(AsyncForm);
+    let trigger = dom.get_by_role_named("button", "Edit profile");
+
+    dom.click(trigger);
+    let load = dom.get_by_role_named("button", "Load");
+    assert_eq!(dom.focused(), Some(load));
+
+    dom.press("Tab");
+    let cancel = dom.get_by_role_named("button", "Cancel");
+    assert_eq!(dom.focused(), Some(cancel));
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(load));
+    dom.press("Shift+Tab");
+    assert_eq!(dom.focused(), Some(cancel));
+
+    // Content rendered after opening joins the trap
+    dom.click(load);
+    let name = dom.get_by_label("Name");
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(name));
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(cancel));
+
+    dom.click(cancel);
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+}
+
+#[test]
+fn focus_scope_auto_focus_can_be_prevented() {
+    #[component]
+    fn Scope() -> Element {
+        let mut show = use_signal(|| false);
+        rsx! {
+            button { onclick: move |_| show.set(true), "Open" }
+            if show() {
+                FocusScope {
+                    on_mount_auto_focus: move |event: Event<()>| event.prevent_default(),
+                    on_unmount_auto_focus: move |event: Event<()>| event.prevent_default(),
+                    button { onclick: move |_| show.set(false), "Close" }
+                }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(Scope);
+    let open = dom.get_by_role_named("button", "Open");
+    dom.click(open);
+    assert_eq!(dom.focused(), Some(open));
+
+    // Without the handlers, focus would return to "Open" here
+    dom.click(dom.get_by_role_named("button", "Close"));
+    assert_eq!(dom.query_by_role_named("button", "Close"), None);
+    assert_eq!(dom.focused(), None);
+}
+
+#[component]
+fn UnsavedChanges(block_escape: bool) -> Element {
+    let mut escapes = use_signal(|| 0);
+
+    rsx! {
+        Dialog {
+            default_open: true,
+            DialogContent {
+                on_escape_key_down: move |event: Event<ListenerEvent>| {
+                    escapes += 1;
+                    if block_escape {
+                        event.prevent_default();
+                    }
+                },
+                DialogTitle { "Unsaved changes" }
+            }
+        }
+        p { "escapes: {escapes}" }
+    }
+}
+
+#[test]
+fn dialog_escape_closes() {
+    let mut dom = TestDom::with_props(
+        UnsavedChanges,
+        UnsavedChangesProps {
+            block_escape: false,
+        },
+    );
+    assert!(dom.query_by_role("dialog").is_some());
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("dialog"), None);
+    dom.get_by_text("escapes: 1");
+
+    // The listener is gone once the dialog has closed
+    dom.press("Escape");
+    dom.get_by_text("escapes: 1");
+}
+
+#[test]
+fn dialog_escape_can_be_prevented() {
+    let mut dom = TestDom::with_props(UnsavedChanges, UnsavedChangesProps { block_escape: true });
+
+    dom.press("Escape");
+    dom.get_by_text("escapes: 1");
+    assert!(dom.query_by_role("dialog").is_some());
+}
+
+#[component]
+fn NestedDialogs() -> Element {
+    rsx! {
+        Dialog {
+            default_open: true,
+            DialogContent {
+                DialogTitle { "Settings" }
+                Dialog {
+                    DialogTrigger { "Delete account" }
+                    DialogContent {
+                        DialogTitle { "Are you sure?" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The z-index from an inline `style` attribute.
+fn z_index(style: Option<&str>) -> i32 {
+    let style = style.expect("element has no style");
+    let value = style
+        .split(';')
+        .find_map(|rule| rule.trim().strip_prefix("z-index:"))
+        .expect("style has no z-index");
+    value.trim().parse().unwrap()
+}
+
+#[test]
+fn nested_dialogs_stack_and_escape_closes_only_the_topmost() {
+    let mut dom = TestDom::new(NestedDialogs);
+    dom.click(dom.get_by_text("Delete account"));
+
+    let outer = dom.get_by_role_named("dialog", "Settings");
+    let inner = dom.get_by_role_named("dialog", "Are you sure?");
+    assert!(z_index(dom.attribute(inner, "style")) > z_index(dom.attribute(outer, "style")));
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role_named("dialog", "Are you sure?"), None);
+    assert!(dom.is_connected(outer));
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("dialog"), None);
+}
+
+#[component]
+fn ShareDialog(keep_open: bool) -> Element {
+    rsx! {
+        input { aria_label: "Search" }
+        Dialog {
+            modal: false,
+            DialogTrigger { "Share" }
+            DialogContent {
+                on_interact_outside: move |event: Event<ListenerEvent>| {
+                    if keep_open {
+                        event.prevent_default();
+                    }
+                },
+                DialogTitle { "Share" }
+                input { aria_label: "Email" }
+            }
+        }
+    }
+}
+
+#[test]
+fn non_modal_dialog_closes_on_pointer_down_outside_and_keeps_focus_there() {
+    let mut dom = TestDom::with_props(ShareDialog, ShareDialogProps { keep_open: false });
+    dom.click(dom.get_by_role_named("button", "Share"));
+    let dialog = dom.get_by_role("dialog");
+    assert_eq!(dom.attribute(dialog, "aria-modal"), Some("false"));
+
+    // Pressing inside keeps it open
+    dom.click(dom.get_by_label("Email"));
+    assert!(dom.is_connected(dialog));
+
+    let search = dom.get_by_label("Search");
+    dom.click(search);
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.focused(), Some(search));
+}
+
+#[test]
+fn non_modal_dialog_closes_when_focus_moves_outside() {
+    let mut dom = TestDom::with_props(ShareDialog, ShareDialogProps { keep_open: false });
+    dom.click(dom.get_by_role_named("button", "Share"));
+
+    dom.focus(dom.get_by_label("Search"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+}
+
+#[test]
+fn non_modal_dialog_trigger_still_toggles_it_closed() {
+    let mut dom = TestDom::with_props(ShareDialog, ShareDialogProps { keep_open: false });
+    let trigger = dom.get_by_role_named("button", "Share");
+    dom.click(trigger);
+    assert!(dom.query_by_role("dialog").is_some());
+
+    dom.click(trigger);
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("false"));
+}
+
+#[test]
+fn non_modal_dialog_outside_interaction_can_be_prevented() {
+    let mut dom = TestDom::with_props(ShareDialog, ShareDialogProps { keep_open: true });
+    dom.click(dom.get_by_role_named("button", "Share"));
+
+    dom.click(dom.get_by_label("Search"));
+    assert!(dom.query_by_role("dialog").is_some());
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("dialog"), None);
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DismissableLayer
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn NestedLayers() -> Element {
+    let mut outer = use_signal(|| true);
+    let mut inner = use_signal(|| true);
+
+    rsx! {
+        button { "Elsewhere" }
+        if outer() {
+            DismissableLayer {
+                role: "region",
+                aria_label: "Outer",
+                on_dismiss: move |_| outer.set(false),
+                button { "Outer action" }
+            }
+        }
+        // Rendered apart from the outer layer, the way a portaled popover would be
+        if inner() {
+            DismissableLayer {
+                role: "region",
+                aria_label: "Inner",
+                on_dismiss: move |_| inner.set(false),
+                button { "Inner action" }
+            }
+        }
+    }
+}
+
+#[test]
+fn dismissable_layer_only_dismisses_the_topmost_layer() {
+    let mut dom = TestDom::new(NestedLayers);
+
+    // Inside the topmost layer, although outside the other one
+    dom.click(dom.get_by_role_named("button", "Inner action"));
+    assert!(dom.query_by_role_named("region", "Outer").is_some());
+    assert!(dom.query_by_role_named("region", "Inner").is_some());
+
+    dom.click(dom.get_by_role_named("button", "Elsewhere"));
+    assert_eq!(dom.query_by_role_named("region", "Inner"), None);
+    assert!(dom.query_by_role_named("region", "Outer").is_some());
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role_named("region", "Outer"), None);
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DropdownMenu
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn EditMenu() -> Element {
+    let mut log = use_signal(Vec::<String>::new);
+
+    rsx! {
+        p { "data-testid": "log", "{log:?}" }
+        button { "Elsewhere" }
+        DropdownMenu {
+            DropdownMenuTrigger { "Edit" }
+            DropdownMenuContent {
+                DropdownMenuItem {
+                    on_select: move |_| log.write().push("undo".to_string()),
+                    "Undo"
+                }
+                DropdownMenuItem { disabled: true, "Redo" }
+                DropdownMenuItem { "Cut" }
+                DropdownMenuItem { "Copy" }
+                DropdownMenuSeparator {}
+                DropdownMenuCheckboxItem {
+                    on_select: move |event: Event<()>| event.prevent_default(),
+                    DropdownMenuItemIndicator { "✓" }
+                    "Word wrap"
+                }
+                DropdownMenuLabel { "Zoom" }
+                DropdownMenuRadioGroup {
+                    default_value: "100",
+                    on_value_change: move |value| log.write().push(value),
+                    DropdownMenuRadioItem { value: "100", "Actual size" }
+                    DropdownMenuRadioItem { value: "200", "Double size" }
+                }
+                DropdownMenuSub {
+                    DropdownMenuSubTrigger { "Find" }
+                    DropdownMenuSubContent {
+                        DropdownMenuItem {
+                            on_select: move |_| log.write().push("find next".to_string()),
+                            "Find next"
+                        }
+                        DropdownMenuItem { "Find previous" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn dropdown_menu_opens_on_press_and_arrows_skip_disabled_items() {
+    let mut dom = TestDom::new(EditMenu);
+    let trigger = dom.get_by_role_named("button", "Edit");
+    assert_eq!(dom.attribute(trigger, "aria-haspopup"), Some("menu"));
+
+    dom.click(trigger);
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("true"));
+    let menu = dom.get_by_role_named("menu", "Edit");
+    assert_eq!(dom.focused(), Some(menu));
+
+    dom.press("ArrowDown");
+    let undo = dom.get_by_role_named("menuitem", "Undo");
+    assert_eq!(dom.focused(), Some(undo));
+    assert!(dom.has_attribute(undo, "data-highlighted"));
+
+    let redo = dom.get_by_role_named("menuitem", "Redo");
+    assert_eq!(dom.attribute(redo, "aria-disabled"), Some("true"));
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Cut"))
+    );
+    assert!(!dom.has_attribute(undo, "data-highlighted"));
+
+    dom.press("End");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Find"))
+    );
+    dom.press("Home");
+    assert_eq!(dom.focused(), Some(undo));
+    // Without `loop`, the first item stays put
+    dom.press("ArrowUp");
+    assert_eq!(dom.focused(), Some(undo));
+
+    dom.click(trigger);
+    assert_eq!(dom.query_by_role("menu"), None);
+}
+
+#[test]
+fn dropdown_menu_opens_from_the_keyboard_and_escape_returns_focus() {
+    let mut dom = TestDom::new(EditMenu);
+    let trigger = dom.get_by_role_named("button", "Edit");
+    dom.focus(trigger);
+
+    dom.press("Enter");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Undo"))
+    );
+
+    dom.press("Tab");
+    assert!(dom.query_by_role("menu").is_some());
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("menu"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Undo"))
+    );
+}
+
+#[test]
+fn dropdown_menu_typeahead_focuses_items_by_their_text() {
+    let mut dom = TestDom::new(EditMenu);
+    dom.focus(dom.get_by_role_named("button", "Edit"));
+    dom.press("Enter");
+
+    dom.press("c");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Cut"))
+    );
+    // The same letter again moves on to the next match
+    dom.press("c");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Copy"))
+    );
+
+    // Disabled items are skipped
+    dom.wait(TYPEAHEAD_RESET);
+    dom.press("r");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Copy"))
+    );
+
+    // Letters typed in quick succession search together
+    dom.press("w");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Copy"))
+    );
+    dom.wait(TYPEAHEAD_RESET);
+    dom.press("w");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitemcheckbox", "Word wrap"))
+    );
+}
+
+#[test]
+fn dropdown_menu_selecting_closes_unless_prevented() {
+    let mut dom = TestDom::new(EditMenu);
+    let trigger = dom.get_by_role_named("button", "Edit");
+    let log = dom.get_by_test_id("log");
+
+    dom.focus(trigger);
+    dom.press("Enter");
+    dom.press("Enter");
+    assert_eq!(dom.text(log), r#"["undo"]"#);
+    assert_eq!(dom.query_by_role("menu"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+
+    // The checkbox item prevents the default, so the menu stays open
+    dom.click(trigger);
+    let wrap = dom.get_by_role("menuitemcheckbox");
+    assert_eq!(dom.attribute(wrap, "aria-checked"), Some("false"));
+    dom.click(wrap);
+    assert_eq!(dom.attribute(wrap, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(wrap, "data-state"), Some("checked"));
+    assert_eq!(dom.text(wrap), "✓Word wrap");
+
+    let actual = dom.get_by_role_named("menuitemradio", "Actual size");
+    let double = dom.get_by_role_named("menuitemradio", "Double size");
+    assert_eq!(dom.attribute(actual, "aria-checked"), Some("true"));
+    dom.click(double);
+    assert_eq!(dom.text(log), r#"["undo", "200"]"#);
+    assert_eq!(dom.query_by_role("menu"), None);
+}
+
+#[test]
+fn dropdown_menu_closes_on_pointer_down_outside() {
+    let mut dom = TestDom::new(EditMenu);
+    dom.click(dom.get_by_role_named("button", "Edit"));
+
+    let elsewhere = dom.get_by_role_named("button", "Elsewhere");
+    dom.click(elsewhere);
+    assert_eq!(dom.query_by_role("menu"), None);
+}
+
+#[test]
+fn dropdown_menu_runs_caller_listeners_alongside_its_own() {
+    #[component]
+    fn LoggedMenu() -> Element {
+        let mut log = use_signal(Vec::<String>::new);
+
+        // Listeners reach the parts through attribute spreading, as from a wrapper
+        let content_listeners = vec![onkeydown(move |event: KeyboardEvent| {
+            log.write().push(event.key().to_string())
+        })];
+        let item_listeners = vec![onclick(move |_| log.write().push("clicked".to_string()))];
+
+        rsx! {
+            p { "data-testid": "log", "{log:?}" }
+            DropdownMenu {
+                DropdownMenuTrigger { "File" }
+                DropdownMenuContent {
+                    attributes: content_listeners,
+                    DropdownMenuItem {
+                        id: "save",
+                        on_select: move |_| log.write().push("saved".to_string()),
+                        attributes: item_listeners,
+                        "Save"
+                    }
+                    DropdownMenuItem { "Close" }
+                }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(LoggedMenu);
+    let trigger = dom.get_by_role_named("button", "File");
+    let log = dom.get_by_test_id("log");
+    dom.click(trigger);
+
+    // The content keeps navigating with the caller's `onkeydown`
+    dom.press("ArrowDown");
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Close"))
+    );
+    dom.press("ArrowUp");
+    let save = dom.get_by_role_named("menuitem", "Save");
+    assert_eq!(dom.focused(), Some(save));
+    assert_eq!(dom.attribute(save, "id"), Some("save"));
+    assert_eq!(dom.text(log), r#"["ArrowDown", "ArrowDown", "ArrowUp"]"#);
+
+    // The item still selects and closes with the caller's `onclick`
+    dom.click(save);
+    assert_eq!(
+        dom.text(log),
+        r#"["ArrowDown", "ArrowDown", "ArrowUp", "clicked", "saved"]"#
+    );
+    assert_eq!(dom.query_by_role("menu"), None);
+}
+
+#[test]
+fn dropdown_menu_submenus_open_with_arrow_right_and_close_one_level_at_a_time() {
+    let mut dom = TestDom::new(EditMenu);
+    let trigger = dom.get_by_role_named("button", "Edit");
+    dom.focus(trigger);
+    dom.press("Enter");
+    dom.press("End");
+
+    let find = dom.get_by_role_named("menuitem", "Find");
+    assert_eq!(dom.attribute(find, "aria-haspopup"), Some("menu"));
+    dom.press("ArrowRight");
+    assert_eq!(dom.attribute(find, "aria-expanded"), Some("true"));
+    assert_eq!(dom.get_all_by_role("menu").len(), 2);
+    let find_next = dom.get_by_role_named("menuitem", "Find next");
+    assert_eq!(dom.focused(), Some(find_next));
+
+    // Keys in the submenu stay there
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("menuitem", "Find previous"))
+    );
+
+    dom.press("Escape");
+    assert_eq!(dom.get_all_by_role("menu").len(), 1);
+    assert_eq!(dom.focused(), Some(find));
+
+    dom.press("ArrowRight");
+    dom.press("ArrowLeft");
+    assert_eq!(dom.get_all_by_role("menu").len(), 1);
+    assert_eq!(dom.focused(), Some(find));
+
+    // Selecting in a submenu closes the whole menu
+    dom.press("Enter");
+    dom.press("Enter");
+    assert_eq!(dom.text(dom.get_by_test_id("log")), r#"["find next"]"#);
+    assert_eq!(dom.query_by_role("menu"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+}
+
+#[test]
+fn dropdown_menu_submenu_opens_on_hover_and_closes_when_leaving_for_another_item() {
+    let mut dom = TestDom::new(EditMenu);
+    dom.click(dom.get_by_role_named("button", "Edit"));
+
+    let find = dom.get_by_role_named("menuitem", "Find");
+    dom.hover(find);
+    assert_eq!(dom.focused(), Some(find));
+    dom.wait(Duration::from_millis(150));
+    assert_eq!(dom.get_all_by_role("menu").len(), 2);
+    assert_eq!(dom.focused(), Some(find));
+
+    let copy = dom.get_by_role_named("menuitem", "Copy");
+    dom.hover(copy);
+    assert_eq!(dom.get_all_by_role("menu").len(), 1);
+    assert_eq!(dom.focused(), Some(copy));
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Popover
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn FilterPopover(modal: bool) -> Element {
+    rsx! {
+        input { aria_label: "Search" }
+        Popover {
+            modal,
+            PopoverTrigger { "Filters" }
+            PopoverContent {
+                aria_label: "Filters",
+                input { aria_label: "Min price" }
+                PopoverClose { "Done" }
+                PopoverArrow {}
+            }
+        }
+    }
+}
+
+#[test]
+fn popover_opens_on_click_and_escape_returns_focus_to_the_trigger() {
+    let mut dom = TestDom::with_props(FilterPopover, FilterPopoverProps { modal: false });
+    let trigger = dom.get_by_role_named("button", "Filters");
+    assert_eq!(dom.attribute(trigger, "aria-haspopup"), Some("dialog"));
+
+    // Hovering does nothing, unlike a tooltip
+    dom.hover(trigger);
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    dom.click(trigger);
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("true"));
+    let popover = dom.get_by_role_named("dialog", "Filters");
+    assert_eq!(
+        dom.attribute(trigger, "aria-controls"),
+        dom.attribute(popover, "id")
+    );
+    assert_eq!(dom.attribute(popover, "data-side"), Some("bottom"));
+    assert_eq!(dom.focused(), Some(dom.get_by_label("Min price")));
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+}
+
+#[test]
+fn popover_closes_on_pointer_down_outside_and_keeps_focus_there() {
+    let mut dom = TestDom::with_props(FilterPopover, FilterPopoverProps { modal: false });
+    let trigger = dom.get_by_role_named("button", "Filters");
+    dom.click(trigger);
+
+    // Not trapped, and the trigger still toggles it
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(dom.get_by_role_named("button", "Done")));
+    dom.click(trigger);
+    assert_eq!(dom.query_by_role("dialog"), None);
+
+    dom.click(trigger);
+    let search = dom.get_by_label("Search");
+    dom.click(search);
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.focused(), Some(search));
+}
+
+#[test]
+fn modal_popover_traps_focus_and_closes_on_outside_press() {
+    let mut dom = TestDom::with_props(FilterPopover, FilterPopoverProps { modal: true });
+    let trigger = dom.get_by_role_named("button", "Filters");
+    dom.click(trigger);
+
+    let min_price = dom.get_by_label("Min price");
+    assert_eq!(dom.focused(), Some(min_price));
+    dom.press("Tab");
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(min_price));
+
+    dom.click(dom.get_by_label("Search"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("false"));
+}
+
+#[component]
+fn ControlledPopover() -> Element {
+    let mut open = use_signal(|| false);
+    let mut changes = use_signal(Vec::<bool>::new);
+
+    rsx! {
+        p { "data-testid": "changes", "{changes:?}" }
+        button { onclick: move |_| open.set(true), "Edit inline" }
+        Popover {
+            open: open(),
+            on_open_change: move |value| {
+                changes.write().push(value);
+                open.set(value);
+            },
+            PopoverAnchor { "Title" }
+            PopoverTrigger { "Edit" }
+            PopoverContent {
+                input { aria_label: "Title" }
+                PopoverClose { "Save" }
+            }
+        }
+    }
+}
+
+#[test]
+fn popover_controlled_open_state_reports_changes() {
+    let mut dom = TestDom::new(ControlledPopover);
+    let changes = dom.get_by_test_id("changes");
+
+    dom.click(dom.get_by_role_named("button", "Edit inline"));
+    assert!(dom.query_by_role("dialog").is_some());
+    assert_eq!(dom.text(changes), "[]");
+
+    dom.click(dom.get_by_role_named("button", "Save"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+    assert_eq!(dom.text(changes), "[false]");
+
+    dom.click(dom.get_by_role_named("button", "Edit"));
+    assert!(dom.query_by_role("dialog").is_some());
+    assert_eq!(dom.text(changes), "[false, true]");
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Portal
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn PortalInOverlays() -> Element {
+    let mut shown = use_signal(|| true);
+    rsx! {
+        div { "data-testid": "overlays", id: "overlays" }
+        button { onclick: move |_| shown.toggle(), "Toggle" }
+        div { "data-testid": "inline",
+            if shown() {
+                Portal { container: "#overlays",
+                    p { "Portaled" }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn portal_moves_its_content_into_the_container_and_removes_it_on_unmount() {
+    let mut dom = TestDom::new(PortalInOverlays);
+    let overlays = dom.get_by_test_id("overlays");
+    let inline = dom.get_by_test_id("inline");
+    assert_eq!(dom.text(overlays), "Portaled");
+    assert_eq!(dom.text(inline), "");
+
+    dom.click(dom.get_by_role_named("button", "Toggle"));
+    assert_eq!(dom.query_by_text("Portaled"), None);
+    assert_eq!(dom.text(overlays), "");
+}
+
+#[component]
+fn PortalSwitchingContainers() -> Element {
+    let mut container = use_signal(|| "#first");
+    rsx! {
+        div { "data-testid": "first", id: "first" }
+        div { "data-testid": "second", id: "second" }
+        button { onclick: move |_| container.set("#second"), "Switch" }
+        PortalProvider { container: container(),
+            Portal {
+                p { "Portaled" }
+            }
+        }
+    }
+}
+
+#[test]
+fn portal_keeps_its_content_when_the_container_changes() {
+    let mut dom = TestDom::new(PortalSwitchingContainers);
+    let first = dom.get_by_test_id("first");
+    let second = dom.get_by_test_id("second");
+    assert_eq!(dom.text(first), "Portaled");
+
+    dom.click(dom.get_by_role_named("button", "Switch"));
+    assert_eq!(dom.text(first), "");
+    assert_eq!(dom.text(second), "Portaled");
+    assert!(dom.is_connected(dom.get_by_text("Portaled")));
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Presence
+ * -----------------------------------------------------------------------------------------------*/
+
+const FADE: &str = "animation-name: fade; animation-duration: 100ms;";
+
+#[component]
+fn SavedToast() -> Element {
+    let mut visible = use_signal(|| true);
+
+    rsx! {
+        button { onclick: move |_| visible.toggle(), "Toggle" }
+        Presence {
+            present: visible(),
+            role: "status",
+            style: FADE,
+            "Saved"
+        }
+    }
+}
+
+#[test]
+fn presence_waits_for_the_exit_animation_to_end() {
+    let mut dom = TestDom::new(SavedToast);
+    let toast = dom.get_by_role("status");
+    assert_eq!(dom.attribute(toast, "data-state"), Some("open"));
+
+    dom.click(dom.get_by_role_named("button", "Toggle"));
+    assert_eq!(dom.attribute(toast, "data-state"), Some("closed"));
+    assert!(dom.is_connected(toast));
+
+    dom.animation_end(toast);
+    assert_eq!(dom.query_by_role("status"), None);
+
+    // Coming back mounts it again as open
+    dom.click(dom.get_by_role_named("button", "Toggle"));
+    let toast = dom.get_by_role("status");
+    assert_eq!(dom.attribute(toast, "data-state"), Some("open"));
+}
+
+#[test]
+fn presence_ignores_end_events_bubbling_up_from_its_children() {
+    #[component]
+    fn SpinnerToast() -> Element {
+        let mut visible = use_signal(|| true);
+
+        rsx! {
+            button { onclick: move |_| visible.toggle(), "Toggle" }
+            Presence {
+                present: visible(),
+                role: "status",
+                style: FADE,
+                span { "data-testid": "spinner", style: FADE }
+                "Saving"
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(SpinnerToast);
+    let toast = dom.get_by_role("status");
+    let spinner = dom.get_by_test_id("spinner");
+
+    dom.click(dom.get_by_role_named("button", "Toggle"));
+    dom.animation_end(spinner);
+    dom.transition_end(spinner);
+    assert!(dom.is_connected(toast));
+
+    dom.animation_end(toast);
+    assert_eq!(dom.query_by_role("status"), None);
+}
+
+#[test]
+fn presence_unmounts_after_the_animation_duration_without_an_end_event() {
+    let mut dom = TestDom::new(SavedToast);
+    let toast = dom.get_by_role("status");
+
+    dom.click(dom.get_by_role_named("button", "Toggle"));
+    dom.wait(Duration::from_millis(50));
+    assert!(dom.is_connected(toast));
+
+    dom.wait(Duration::from_millis(150));
+    assert_eq!(dom.query_by_role("status"), None);
+}
+
+#[test]
+fn presence_reopening_during_the_exit_animation_keeps_it_mounted() {
+    let mut dom = TestDom::new(SavedToast);
+    let toggle = dom.get_by_role_named("button", "Toggle");
+    let toast = dom.get_by_role("status");
+
+    dom.click(toggle);
+    dom.click(toggle);
+    dom.wait(Duration::from_millis(200));
+    assert!(dom.is_connected(toast));
+    assert_eq!(dom.attribute(toast, "data-state"), Some("open"));
+}
+
+#[component]
+fn AnimatedDialog() -> Element {
+    rsx! {
+        Dialog {
+            DialogTrigger { "Edit profile" }
+            DialogContent {
+                style: FADE,
+                DialogTitle { "Edit profile" }
+                DialogClose { "Cancel" }
+            }
+        }
+    }
+}
+
+#[test]
+fn dialog_content_plays_its_exit_animation_before_unmounting() {
+    let mut dom = TestDom::new(AnimatedDialog);
+    dom.click(dom.get_by_role_named("button", "Edit profile"));
+    let dialog = dom.get_by_role("dialog");
+
+    dom.click(dom.get_by_role_named("button", "Cancel"));
+    assert!(dom.is_connected(dialog));
+    assert_eq!(dom.attribute(dialog, "data-state"), Some("closed"));
+
+    dom.animation_end(dialog);
+    assert_eq!(dom.query_by_role("dialog"), None);
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RadioGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn PlanForm(orientation: Option<RadioGroupOrientation>) -> Element {
+    let mut changes = use_signal(Vec::<String>::new);
+
+    rsx! {
+        form {
+            id: "plan",
+            "data-testid": "plan",
+            input { aria_label: "Email" }
+            RadioGroup {
+                name: "plan",
+                aria_label: "Plan",
+                required: true,
+                orientation,
+                on_value_change: move |value| changes.write().push(value),
+                for (value, label, disabled) in [
+                    ("free", "Free", false),
+                    ("team", "Team", true),
+                    ("pro", "Pro", false),
+                    ("enterprise", "Enterprise", false),
+                ] {
+                    div {
+                        key: "{value}",
+                        RadioGroupItem {
+                            value,
+                            disabled,
+                            id: "plan-{value}",
+                            RadioGroupIndicator {}
+                        }
+                        label { r#for: "plan-{value}", "{label}" }
+                    }
+                }
+            }
+        }
+        p { "changes: {changes:?}" }
+    }
+}
+
+#[test]
+fn radio_group_submits_one_value_for_the_checked_item() {
+    let mut dom = TestDom::with_props(PlanForm, PlanFormProps { orientation: None });
+    let form = dom.get_by_test_id("plan");
+    let group = dom.get_by_role_named("radiogroup", "Plan");
+    assert_eq!(dom.attribute(group, "aria-required"), Some("true"));
+    assert_eq!(dom.get_all_by_role("radio").len(), 4);
+    assert!(dom.form_data(form).is_empty());
+
+    let free = dom.get_by_role_named("radio", "Free");
+    let pro = dom.get_by_role_named("radio", "Pro");
+    dom.click(dom.get_by_label("Pro"));
+    assert_eq!(dom.attribute(pro, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(pro, "data-state"), Some("checked"));
+    assert_eq!(dom.attribute(free, "aria-checked"), Some("false"));
+    assert_eq!(
+        dom.form_data(form),
+        vec![("plan".to_string(), "pro".to_string())]
+    );
+
+    // Disabled items can't be checked
+    dom.click(dom.get_by_role_named("radio", "Team"));
+    assert_eq!(dom.attribute(pro, "aria-checked"), Some("true"));
+
+    dom.click(free);
+    assert_eq!(
+        dom.form_data(form),
+        vec![("plan".to_string(), "free".to_string())]
+    );
+    dom.get_by_text(r#"changes: ["pro", "free"]"#);
+
+    // Reset restores the (empty) default
+    dom.reset(form);
+    assert_eq!(dom.attribute(free, "aria-checked"), Some("false"));
+    assert!(dom.form_data(form).is_empty());
+}
+
+#[test]
+fn radio_group_arrow_keys_move_and_check_with_one_tab_stop() {
+    let mut dom = TestDom::with_props(PlanForm, PlanFormProps { orientation: None });
+    let free = dom.get_by_role_named("radio", "Free");
+    let pro = dom.get_by_role_named("radio", "Pro");
+    let enterprise = dom.get_by_role_named("radio", "Enterprise");
+
+    // Without a checked item, Tab lands on the first enabled one and leaves the group next
+    dom.focus(dom.get_by_role_named("textbox", "Email"));
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(free));
+    assert_eq!(dom.attribute(free, "aria-checked"), Some("false"));
+
+    // Team is disabled, so it's skipped
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(pro));
+    assert_eq!(dom.attribute(pro, "aria-checked"), Some("true"));
+    assert_eq!(dom.attribute(pro, "tabindex"), Some("0"));
+    assert_eq!(dom.attribute(free, "tabindex"), Some("-1"));
+
+    dom.press("ArrowRight");
+    assert_eq!(dom.focused(), Some(enterprise));
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(free));
+    dom.press("ArrowLeft");
+    assert_eq!(dom.focused(), Some(enterprise));
+    assert_eq!(dom.attribute(enterprise, "aria-checked"), Some("true"));
+
+    // Enter doesn't check, Space does
+    dom.press("Home");
+    assert_eq!(dom.focused(), Some(free));
+    dom.press("Enter");
+    assert_eq!(dom.attribute(free, "aria-checked"), Some("false"));
+    dom.press(" ");
+    assert_eq!(dom.attribute(free, "aria-checked"), Some("true"));
+    dom.get_by_text(r#"changes: ["pro", "enterprise", "free", "enterprise", "free"]"#);
+
+    dom.focus(dom.get_by_role_named("textbox", "Email"));
+    dom.press("Tab");
+    assert_eq!(dom.focused(), Some(free));
+}
+
+#[test]
+fn radio_group_orientation_limits_the_arrow_keys() {
+    let mut dom = TestDom::with_props(
+        PlanForm,
+        PlanFormProps {
+            orientation: Some(RadioGroupOrientation::Horizontal),
+        },
+    );
+    let group = dom.get_by_role_named("radiogroup", "Plan");
+    assert_eq!(dom.attribute(group, "aria-orientation"), Some("horizontal"));
+    let free = dom.get_by_role_named("radio", "Free");
+    dom.focus(free);
+
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(free));
+    assert_eq!(dom.attribute(free, "aria-checked"), Some("false"));
+
+    dom.press("ArrowLeft");
+    let enterprise = dom.get_by_role_named("radio", "Enterprise");
+    assert_eq!(dom.focused(), Some(enterprise));
+    assert_eq!(dom.attribute(enterprise, "aria-checked"), Some("true"));
+}
+
+#[component]
+fn GrowingPlans() -> Element {
+    let mut team = use_signal(|| false);
+
+    rsx! {
+        button { onclick: move |_| team.set(true), "Offer Team" }
+        RadioGroup { aria_label: "Plan",
+            RadioGroupItem { value: "free", aria_label: "Free" }
+            if team() {
+                RadioGroupItem { value: "team", aria_label: "Team" }
+            }
+            RadioGroupItem { value: "pro", aria_label: "Pro" }
+        }
+    }
+}
+
+#[test]
+fn radio_group_arrows_follow_document_order_for_items_added_later() {
+    let mut dom = TestDom::new(GrowingPlans);
+    dom.click(dom.get_by_role_named("button", "Offer Team"));
+
+    dom.focus(dom.get_by_role_named("radio", "Free"));
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(dom.get_by_role_named("radio", "Team")));
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(dom.get_by_role_named("radio", "Pro")));
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Select
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn FruitForm() -> Element {
+    let mut changes = use_signal(Vec::<String>::new);
+
+    rsx! {
+        form {
+            "data-testid": "order",
+            Select {
+                name: "fruit",
+                default_value: "banana",
+                required: true,
+                on_value_change: move |value| changes.write().push(value),
+                SelectTrigger { SelectValue { placeholder: "Pick a fruit" } }
+                SelectContent {
+                    SelectGroup {
+                        SelectLabel { "Fruits" }
+                        SelectItem { value: "apple", SelectItemText { "Apple" } }
+                        SelectItem {
+                            value: "banana",
+                            SelectItemText { "Banana" }
+                            SelectItemIndicator { "✓" }
+                        }
+                        SelectItem { value: "blueberry", disabled: true, SelectItemText { "Blueberry" } }
+                        SelectItem { value: "cherry", SelectItemText { "Cherry" } }
+                    }
+                }
+            }
+        }
+        p { "changes: {changes:?}" }
+    }
+}
+
+#[test]
+fn select_opens_on_the_selected_item_and_arrows_skip_disabled_items() {
+    let mut dom = TestDom::new(FruitForm);
+    let trigger = dom.get_by_role("combobox");
+    assert_eq!(dom.text(trigger), "Banana");
+    assert_eq!(dom.query_by_role("listbox"), None);
+
+    dom.click(trigger);
+    assert_eq!(dom.attribute(trigger, "aria-expanded"), Some("true"));
+    // The check mark is part of its text
+    let banana = dom.get_by_role_named("option", "Banana✓");
+    assert_eq!(dom.attribute(banana, "aria-selected"), Some("true"));
+    assert_eq!(dom.focused(), Some(banana));
+    assert!(dom.has_attribute(banana, "data-highlighted"));
+
+    let blueberry = dom.get_by_role_named("option", "Blueberry");
+    assert_eq!(dom.attribute(blueberry, "aria-disabled"), Some("true"));
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("option", "Cherry"))
+    );
+    // Without wrapping, the last item stays put
+    dom.press("ArrowDown");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("option", "Cherry"))
+    );
+    dom.press("Home");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("option", "Apple"))
+    );
+
+    dom.click(trigger);
+    assert_eq!(dom.query_by_role("listbox"), None);
+    dom.get_by_text("changes: []");
+}
+
+#[test]
+fn select_item_selection_updates_the_value_and_the_form() {
+    let mut dom = TestDom::new(FruitForm);
+    let form = dom.get_by_test_id("order");
+    let trigger = dom.get_by_role("combobox");
+    assert_eq!(
+        dom.form_data(form),
+        vec![("fruit".to_string(), "banana".to_string())]
+    );
+
+    dom.focus(trigger);
+    dom.press("Enter");
+    dom.press("ArrowDown");
+    dom.press("Enter");
+    assert_eq!(dom.query_by_role("listbox"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+    assert_eq!(dom.text(trigger), "Cherry");
+    assert_eq!(
+        dom.form_data(form),
+        vec![("fruit".to_string(), "cherry".to_string())]
+    );
+
+    dom.click(trigger);
+    dom.click(dom.get_by_role_named("option", "Apple"));
+    assert_eq!(dom.text(trigger), "Apple");
+    dom.get_by_text(r#"changes: ["cherry", "apple"]"#);
+}
+
+#[test]
+fn select_typeahead_on_the_closed_trigger_changes_the_value() {
+    let mut dom = TestDom::new(FruitForm);
+    let trigger = dom.get_by_role("combobox");
+    dom.focus(trigger);
+
+    dom.press("c");
+    assert_eq!(dom.query_by_role("listbox"), None);
+    assert_eq!(dom.text(trigger), "Cherry");
+
+    // Disabled items are skipped, so "b" finds Banana rather than Blueberry
+    dom.wait(TYPEAHEAD_RESET);
+    dom.press("b");
+    assert_eq!(dom.text(trigger), "Banana");
+    dom.get_by_text(r#"changes: ["cherry", "banana"]"#);
+}
+
+#[test]
+fn select_escape_closes_without_changing_the_value() {
+    let mut dom = TestDom::new(FruitForm);
+    let trigger = dom.get_by_role("combobox");
+    dom.focus(trigger);
+
+    dom.press("ArrowDown");
+    let listbox = dom.get_by_role("listbox");
+    assert_eq!(
+        dom.attribute(listbox, "aria-labelledby"),
+        dom.attribute(trigger, "id")
+    );
+    dom.press("a");
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("option", "Apple"))
+    );
+
+    dom.press("Escape");
+    assert_eq!(dom.query_by_role("listbox"), None);
+    assert_eq!(dom.focused(), Some(trigger));
+    assert_eq!(dom.text(trigger), "Banana");
+}
+
+#[test]
+fn select_without_a_value_shows_the_placeholder_and_submits_nothing() {
+    let mut dom = TestDom::from_element(rsx! {
+        form {
+            "data-testid": "order",
+            Select {
+                name: "fruit",
+                required: true,
+                SelectTrigger { SelectValue { placeholder: "Pick a fruit" } }
+                SelectContent {
+                    SelectItem { value: "apple", "Apple" }
+                    SelectItem { value: "cherry", "Cherry" }
+                }
+            }
+        }
+    });
+    let trigger = dom.get_by_role("combobox");
+    assert_eq!(dom.text(trigger), "Pick a fruit");
+    assert!(dom.has_attribute(trigger, "data-placeholder"));
+    assert_eq!(dom.attribute(trigger, "aria-required"), Some("true"));
+    assert_eq!(
+        dom.form_data(dom.get_by_test_id("order")),
+        vec![("fruit".to_string(), String::new())]
+    );
+
+    // Items without a `SelectItemText` show their own text
+    dom.click(trigger);
+    assert_eq!(
+        dom.focused(),
+        Some(dom.get_by_role_named("option", "Apple"))
+    );
+    dom.press("Enter");
+    assert_eq!(dom.text(trigger), "Apple");
+    assert!(!dom.has_attribute(trigger, "data-placeholder"));
+}
+
+#[test]
+fn select_form_reset_restores_default_value() {
+    let mut dom = TestDom::new(FruitForm);
+    let form = dom.get_by_test_id("order");
+    let trigger = dom.get_by_role("combobox");
+
+    dom.click(trigger);
+    dom.click(dom.get_by_role_named("option", "Cherry"));
+    assert_eq!(dom.text(trigger), "Cherry");
+
+    dom.reset(form);
+    assert_eq!(dom.text(trigger), "Banana");
+    assert_eq!(
+        dom.form_data(form),
+        vec![("fruit".to_string(), "banana".to_string())]
+    );
+    dom.get_by_text(r#"changes: ["cherry", "banana"]"#);
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Sheet
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn Navigation(side: SheetSide) -> Element {
+    rsx! {
+        Sheet {
+            SheetTrigger { "Menu" }
+            SheetContent {
+                side,
+                size: "18rem",
+                swipe_to_dismiss: true,
+                SheetTitle { "Navigation" }
+                SheetClose { "Close" }
+            }
+        }
+    }
+}
+
+#[test]
+fn sheet_opens_on_its_side_and_closes() {
+    let mut dom = TestDom::with_props(
+        Navigation,
+        NavigationProps {
+            side: SheetSide::Left,
+        },
+    );
+    dom.click(dom.get_by_text("Menu"));
+
+    let sheet = dom.get_by_role_named("dialog", "Navigation");
+    assert_eq!(dom.attribute(sheet, "data-side"), Some("left"));
+    assert_eq!(dom.attribute(sheet, "data-state"), Some("open"));
+    assert!(dom
+        .attribute(sheet, "style")
+        .unwrap()
+        .contains("width: 18rem;"));
+
+    dom.click(dom.get_by_text("Close"));
+    assert_eq!(dom.query_by_role("dialog"), None);
+}
+
+#[test]
+fn sheet_swipe_towards_its_edge_dismisses_it() {
+    let mut dom = TestDom::with_props(
+        Navigation,
+        NavigationProps {
+            side: SheetSide::Right,
+        },
+    );
+    dom.click(dom.get_by_text("Menu"));
+    let sheet = dom.get_by_role("dialog");
+    // Horizontal drags reach the sheet instead of panning the page
+    assert!(dom
+        .attribute(sheet, "style")
+        .unwrap()
+        .contains("touch-action: pan-y;"));
+
+    // Too short, or the wrong way: the sheet springs back
+    dom.swipe(sheet, 30.0, 0.0);
+    dom.swipe(sheet, -200.0, 0.0);
+    assert!(dom.is_connected(sheet));
+    assert!(!dom.has_attribute(sheet, "data-swiping"));
+
+    dom.swipe(sheet, 200.0, 10.0);
+    assert_eq!(dom.query_by_role("dialog"), None);
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Tabs
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn AccountTabs(activation_mode: TabsActivationMode, orientation: TabsOrientation) -> Element {
+    let mut changes = use_signal(Vec::<String>::new);
+
+    rsx! {
+        Tabs {
+            default_value: "account",
+            activation_mode,
+            orientation,
+            on_value_change: move |value| changes.write().push(value),
+            TabsList {
+                aria_label: "Settings",
+                TabsTrigger { value: "account", "Account" }
+                TabsTrigger { value: "password", "Password" }
+                TabsTrigger { value: "billing", disabled: true, "Billing" }
+            }
+            TabsContent { value: "account", "Account settings" }
+            TabsContent { value: "password", input { aria_label: "New password" } }
+            TabsContent { value: "billing", force_mount: true, "Billing settings" }
+        }
+        p { "changes: {changes:?}" }
+    }
+}
+
+#[test]
+fn tabs_wire_triggers_to_panels_with_one_tab_stop() {
+    let dom = TestDom::with_props(
+        AccountTabs,
+        AccountTabsProps {
+            activation_mode: TabsActivationMode::Automatic,
+            orientation: TabsOrientation::Horizontal,
+        },
+    );
+    let list = dom.get_by_role_named("tablist", "Settings");
+    assert_eq!(dom.attribute(list, "aria-orientation"), Some("horizontal"));
+
+    let account = dom.get_by_role_named("tab", "Account");
+    let password = dom.get_by_role_named("tab", "Password");
+    assert_eq!(dom.attribute(account, "aria-selected"), Some("true"));
+    assert_eq!(dom.attribute(account, "tabindex"), Some("0"));
+    assert_eq!(dom.attribute(password, "aria-selected"), Some("false"));
+    assert_eq!(dom.attribute(password, "tabindex"), Some("-1"));
+
+    // Only the selected panel is mounted, unless forced
+    let panel = dom.get_by_role("tabpanel");
+    assert_eq!(
+        dom.attribute(account, "aria-controls"),
+        dom.attribute(panel, "id")
+    );
+    assert_eq!(
+        dom.attribute(panel, "aria-labelledby"),
+        dom.attribute(account, "id")
+    );
+    assert_eq!(dom.accessible_name(panel), "Account");
+    assert_eq!(dom.query_by_role_named("textbox", "New password"), None);
+    let billing = dom.get_by_text("Billing settings");
+    assert!(dom.has_attribute(billing, "hidden"));
+    assert_eq!(dom.attribute(billing, "data-state"), Some("inactive"));
+}
+
+#[test]
+fn tabs_arrow_keys_select_as_focus_moves_and_skip_disabled_triggers() {
+    let mut dom = TestDom::with_props(
+        AccountTabs,
+        AccountTabsProps {
+            activation_mode: TabsActivationMode::Automatic,
+            orientation: TabsOrientation::Horizontal,
+        },
+    );
+    let account = dom.get_by_role_named("tab", "Account");
+    let password = dom.get_by_role_named("tab", "Password");
+    dom.focus(account);
+
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(account));
+
+    dom.press("ArrowRight");
+    assert_eq!(dom.focused(), Some(password));
+    assert_eq!(dom.attribute(password, "aria-selected"), Some("true"));
+    assert_eq!(dom.attribute(password, "tabindex"), Some("0"));
+    assert_eq!(dom.attribute(account, "tabindex"), Some("-1"));
+    dom.get_by_role_named("textbox", "New password");
+    assert_eq!(dom.query_by_text("Account settings"), None);
+
+    // Billing is disabled, so focus wraps around to the first trigger
+    dom.press("ArrowRight");
+    assert_eq!(dom.focused(), Some(account));
+    dom.press("End");
+    assert_eq!(dom.focused(), Some(password));
+    dom.press("Home");
+    assert_eq!(dom.focused(), Some(account));
+    dom.get_by_text(r#"changes: ["password", "account", "password", "account"]"#);
+
+    dom.click(dom.get_by_role_named("tab", "Billing"));
+    dom.get_by_text("Account settings");
+}
+
+#[test]
+fn tabs_manual_activation_waits_for_enter_along_a_vertical_list() {
+    let mut dom = TestDom::with_props(
+        AccountTabs,
+        AccountTabsProps {
+            activation_mode: TabsActivationMode::Manual,
+            orientation: TabsOrientation::Vertical,
+        },
+    );
+    let account = dom.get_by_role_named("tab", "Account");
+    let password = dom.get_by_role_named("tab", "Password");
+    dom.focus(account);
+
+    dom.press("ArrowRight");
+    assert_eq!(dom.focused(), Some(account));
+    dom.press("ArrowDown");
+    assert_eq!(dom.focused(), Some(password));
+    assert_eq!(dom.attribute(password, "aria-selected"), Some("false"));
+    dom.get_by_text("Account settings");
+
+    dom.press("Enter");
+    assert_eq!(dom.attribute(password, "aria-selected"), Some("true"));
+    dom.get_by_role_named("textbox", "New password");
+    dom.get_by_text(r#"changes: ["password"]"#);
+
+    dom.press("ArrowUp");
+    assert_eq!(dom.focused(), Some(account));
+    dom.press(" ");
+    assert_eq!(dom.attribute(account, "aria-selected"), Some("true"));
+}
+
+#[component]
+fn LookalikeTabs() -> Element {
+    rsx! {
+        Tabs { default_value: "a b",
+            TabsList {
+                TabsTrigger { value: "a b", "Spaced" }
+                TabsTrigger { value: "a-b", "Hyphenated" }
+            }
+            TabsContent { value: "a b", force_mount: true, "Spaced panel" }
+            TabsContent { value: "a-b", force_mount: true, "Hyphenated panel" }
+        }
+    }
+}
+
+#[test]
+fn tabs_with_similar_values_get_distinct_ids() {
+    let dom = TestDom::new(LookalikeTabs);
+    let spaced = dom.get_by_role_named("tab", "Spaced");
+    let hyphenated = dom.get_by_role_named("tab", "Hyphenated");
+    assert_ne!(dom.attribute(spaced, "id"), dom.attribute(hyphenated, "id"));
+
+    for (trigger, panel) in [(spaced, "Spaced panel"), (hyphenated, "Hyphenated panel")] {
+        let panel = dom.get_by_text(panel);
+        assert_eq!(
+            dom.attribute(trigger, "aria-controls"),
+            dom.attribute(panel, "id")
+        );
+        assert_eq!(
+            dom.attribute(panel, "aria-labelledby"),
+            dom.attribute(trigger, "id")
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Tooltip
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn SaveTooltip(delay_duration: u64) -> Element {
+    rsx! {
+        TooltipProvider {
+            delay_duration,
+            Tooltip {
+                TooltipTrigger { "Save" }
+                TooltipContent { "Save changes" }
+            }
+        }
+    }
+}
+
+#[test]
+fn tooltip_opens_on_hover_after_delay_and_closes_after_leaving() {
+    let mut dom = TestDom::with_props(SaveTooltip, SaveTooltipProps { delay_duration: 20 });
+    let trigger = dom.get_by_text("Save");
+    assert_eq!(dom.attribute(trigger, "data-state"), Some("closed"));
+
+    dom.hover(trigger);
+    assert_eq!(dom.query_by_role("tooltip"), None);
+
+    dom.wait(Duration::from_millis(100));
+    assert_eq!(dom.attribute(trigger, "data-state"), Some("delayed-open"));
+    let tooltip = dom.get_by_role("tooltip");
+    assert_eq!(dom.text(tooltip), "Save changes");
+    assert_eq!(
+        dom.attribute(trigger, "aria-describedby"),
+        dom.attribute(tooltip, "id")
+    );
+
+    // Hoverable content keeps the tooltip open for a grace period after leaving the trigger.
+    dom.unhover(trigger);
+    assert!(dom.query_by_role("tooltip").is_some());
+
+    dom.wait(Duration::from_millis(400));
+    assert_eq!(dom.attribute(trigger, "data-state"), Some("closed"));
+    assert_eq!(dom.query_by_role("tooltip"), None);
+}
+
+#[test]
+fn tooltip_unhover_before_delay_cancels_opening() {
+    let mut dom = TestDom::with_props(SaveTooltip, SaveTooltipProps { delay_duration: 50 });
+    let trigger = dom.get_by_text("Save");
+
+    dom.hover(trigger);
+    dom.unhover(trigger);
+    dom.wait(Duration::from_millis(100));
+    assert_eq!(dom.attribute(trigger, "data-state"), Some("closed"));
+}
+
+#[test]
+fn tooltip_escape_and_click_close() {
+    let mut dom = TestDom::with_props(SaveTooltip, SaveTooltipProps { delay_duration: 0 });
+    let trigger = dom.get_by_text("Save");
+
+    dom.hover(trigger);
+    dom.wait(Duration::from_millis(20));
+    assert!(dom.query_by_role("tooltip").is_some());
+
+    dom.key_down(trigger, "Escape");
+    assert_eq!(dom.attribute(trigger, "data-state"), Some("closed"));
+
+    dom.hover(trigger);
+    dom.wait(Duration::from_millis(20));
+    dom.click(trigger);
+    assert_eq!(dom.query_by_role("tooltip"), None);
+}
@@ -0,0 +1,438 @@
+//! Server-side rendering snapshots for every exported component.
+//!
+//! Each test renders a component with `dioxus-ssr` and compares the HTML against the
+//! snapshots in `tests/snapshots`, so class-string and ARIA regressions show up as a diff.
+//! Run with `INSTA_UPDATE=always` (or `cargo insta review`) to accept intended changes.
+
+use dioxus::prelude::*;
+use dioxus_components::*;
+
+/// Renders `element` to HTML without the injected `<style>` blocks, one tag per line.
+fn render(element: Element) -> String {
+    let html = dioxus_ssr::render_element(element);
+
+    let mut stripped = String::with_capacity(html.len());
+    let mut rest = html.as_str();
+    while let Some(start) = rest.find("<style>") {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start..].find("</style>") {
+            Some(end) => &rest[start + end + "</style>".len()..],
+            None => "",
+        };
+    }
+    stripped.push_str(rest);
+
+    stripped.replace("><", ">\n<")
+}
+
+#[test]
+fn accordion() {
+    insta::assert_snapshot!(render(rsx! {
+        Accordion {
+            accordion_type: AccordionType::Single { collapsible: true },
+            default_value: "item-1",
+            AccordionItem {
+                value: "item-1",
+                AccordionTrigger { "Is it accessible?" }
+                AccordionContent { "Yes." }
+            }
+            AccordionItem {
+                value: "item-2",
+                disabled: true,
+                AccordionTrigger { "Is it styled?" }
+                AccordionContent { "Yes." }
+            }
+        }
+    }));
+}
+
+#[test]
+fn avatar() {
+    insta::assert_snapshot!(render(rsx! {
+        Avatar {
+            AvatarImage { src: "https://github.com/shadcn.png", alt: "User" }
+            AvatarFallback { "CN" }
+        }
+    }));
+}
+
+#[test]
+fn badge_variants() {
+    for (name, variant) in [
+        ("default", BadgeVariant::Default),
+        ("secondary", BadgeVariant::Secondary),
+        ("destructive", BadgeVariant::Destructive),
+        ("outline", BadgeVariant::Outline),
+    ] {
+        insta::assert_snapshot!(
+            format!("badge_{name}"),
+            render(rsx! { Badge { variant, "Badge" } })
+        );
+    }
+}
+
+#[test]
+fn badge_as_link() {
+    insta::assert_snapshot!(render(rsx! {
+        Badge { as_: "a", href: "/inbox", "3 new" }
+    }));
+}
+
+#[test]
+fn button_variants() {
+    for (name, variant) in [
+        ("default", ButtonVariant::Default),
+        ("destructive", ButtonVariant::Destructive),
+        ("outline", ButtonVariant::Outline),
+        ("secondary", ButtonVariant::Secondary),
+        ("ghost", ButtonVariant::Ghost),
+        ("link", ButtonVariant::Link),
+    ] {
+        insta::assert_snapshot!(
+            format!("button_variant_{name}"),
+            render(rsx! { Button { variant, "Button" } })
+        );
+    }
+}
+
+#[test]
+fn button_sizes() {
+    for (name, size) in [
+        ("default", ButtonSize::Default),
+        ("sm", ButtonSize::Sm),
+        ("lg", ButtonSize::Lg),
+        ("icon", ButtonSize::Icon),
+        ("icon_sm", ButtonSize::IconSm),
+        ("icon_lg", ButtonSize::IconLg),
+    ] {
+        insta::assert_snapshot!(
+            format!("button_size_{name}"),
+            render(rsx! { Button { size, "Button" } })
+        );
+    }
+}
+
+#[test]
+fn button_as_link_and_disabled() {
+    insta::assert_snapshot!(
+        "button_as_link",
+        render(rsx! { Button { as_: "a", href: "/docs", "Docs" } })
+    );
+    insta::assert_snapshot!(
+        "button_disabled",
+        render(rsx! { Button { disabled: true, aria_invalid: true, "Submit" } })
+    );
+}
+
+#[test]
+fn card() {
+    insta::assert_snapshot!(render(rsx! {
+        Card {
+            class: "w-[350px]",
+            CardHeader {
+                CardTitle { "Title" }
+                CardDescription { "Description" }
+                CardAction { "Action" }
+            }
+            CardContent { "Content" }
+            CardFooter { "Footer" }
+        }
+    }));
+}
+
+#[test]
+fn checkbox_states() {
+    for (name, state) in [
+        ("checked", CheckedState::Checked),
+        ("unchecked", CheckedState::Unchecked),
+        ("indeterminate", CheckedState::Indeterminate),
+    ] {
+        insta::assert_snapshot!(
+            format!("checkbox_{name}"),
+            render(rsx! {
+                Checkbox {
+                    default_checked: state,
+                    name: "terms",
+                    id: "terms",
+                    CheckboxIndicator {}
+                }
+            })
+        );
+    }
+}
+
+#[test]
+fn checkbox_disabled_required_with_label() {
+    insta::assert_snapshot!(render(rsx! {
+        CheckboxProvider {
+            disabled: true,
+            required: true,
+            CheckboxTrigger { CheckboxIndicator {} }
+            CheckboxLabel { "Accept terms" }
+        }
+    }));
+}
+
+#[test]
+fn checkbox_group() {
+    insta::assert_snapshot!(render(rsx! {
+        CheckboxGroup {
+            name: "channels",
+            default_value: vec!["email".to_string()],
+            CheckboxProvider {
+                value: "email",
+                id: "email",
+                CheckboxTrigger { CheckboxIndicator {} }
+                CheckboxLabel { "Email" }
+            }
+            CheckboxProvider {
+                value: "sms",
+                id: "sms",
+                CheckboxTrigger { CheckboxIndicator {} }
+                CheckboxLabel { "SMS" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn combobox_multiple() {
+    insta::assert_snapshot!(render(rsx! {
+        Combobox {
+            multiple: true,
+            name: "tags",
+            options: vec![ComboboxOption::new("bug", "Bug"), ComboboxOption::new("docs", "Docs")],
+            default_value: vec!["bug".to_string()],
+            ComboboxInput { placeholder: "Add tags…" }
+            ComboboxContent {}
+        }
+    }));
+}
+
+#[test]
+fn command() {
+    insta::assert_snapshot!(render(rsx! {
+        Command {
+            CommandInput { placeholder: "Type a command or search…" }
+            CommandList {
+                CommandEmpty { "No results found." }
+                CommandGroup {
+                    heading: "Suggestions",
+                    CommandItem { "Calendar" }
+                    CommandItem { disabled: true, "Calculator" }
+                }
+                CommandSeparator {}
+                CommandItem {
+                    "Settings"
+                    CommandShortcut { "⌘S" }
+                }
+            }
+        }
+    }));
+}
+
+#[test]
+fn dialog_closed() {
+    insta::assert_snapshot!(render(rsx! {
+        Dialog {
+            DialogTrigger { "Open" }
+            DialogContent {
+                DialogTitle { "Title" }
+                DialogDescription { "Description" }
+                DialogClose { "Close" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn dialog_open() {
+    insta::assert_snapshot!(render(rsx! {
+        Dialog {
+            default_open: true,
+            DialogTrigger { "Open" }
+            DialogContent {
+                DialogTitle { "Title" }
+                DialogDescription { "Description" }
+                DialogClose { "Close" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn dropdown_menu_open() {
+    insta::assert_snapshot!(render(rsx! {
+        DropdownMenu {
+            default_open: true,
+            DropdownMenuTrigger { "Edit" }
+            DropdownMenuContent {
+                DropdownMenuLabel { "History" }
+                DropdownMenuItem {
+                    "Undo"
+                    DropdownMenuShortcut { "⌘Z" }
+                }
+                DropdownMenuItem { disabled: true, "Redo" }
+                DropdownMenuSeparator {}
+                DropdownMenuCheckboxItem {
+                    default_checked: CheckedState::Checked,
+                    DropdownMenuItemIndicator { "✓" }
+                    "Word wrap"
+                }
+                DropdownMenuSub {
+                    DropdownMenuSubTrigger { "Find" }
+                    DropdownMenuSubContent {
+                        DropdownMenuItem { "Find next" }
+                    }
+                }
+            }
+        }
+    }));
+}
+
+#[test]
+fn empty() {
+    insta::assert_snapshot!(render(rsx! {
+        Empty {
+            EmptyHeader {
+                EmptyMedia { variant: EmptyMediaVariant::Icon, "?" }
+                EmptyTitle { "No results" }
+                EmptyDescription { "Try another search" }
+            }
+            EmptyContent { "Clear filters" }
+        }
+    }));
+}
+
+#[test]
+fn focus_scope() {
+    insta::assert_snapshot!(render(rsx! {
+        FocusScope {
+            trapped: true,
+            id: "panel",
+            class: "panel",
+            button { "Done" }
+        }
+    }));
+}
+
+#[test]
+fn popover_open() {
+    insta::assert_snapshot!(render(rsx! {
+        Popover {
+            default_open: true,
+            PopoverTrigger { "Filters" }
+            PopoverContent {
+                input { aria_label: "Min price" }
+                PopoverClose { "Done" }
+                PopoverArrow {}
+            }
+        }
+    }));
+}
+
+#[test]
+fn portal() {
+    insta::assert_snapshot!(render(rsx! {
+        PortalProvider {
+            container: "#overlays",
+            Portal {
+                class: "overlay",
+                p { "Portaled" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn radio_group() {
+    insta::assert_snapshot!(render(rsx! {
+        RadioGroup {
+            id: "density",
+            default_value: "comfortable",
+            name: "density",
+            orientation: RadioGroupOrientation::Vertical,
+            RadioGroupItem { value: "default", id: "density-default", RadioGroupIndicator {} }
+            RadioGroupItem { value: "comfortable", id: "density-comfortable", RadioGroupIndicator {} }
+            RadioGroupItem { value: "compact", id: "density-compact", disabled: true, RadioGroupIndicator {} }
+        }
+    }));
+}
+
+#[test]
+fn select_closed() {
+    insta::assert_snapshot!(render(rsx! {
+        Select {
+            name: "fruit",
+            default_value: "banana",
+            required: true,
+            SelectTrigger { SelectValue { placeholder: "Pick a fruit" } }
+            SelectContent {
+                SelectItem { value: "apple", SelectItemText { "Apple" } }
+                SelectItem { value: "banana", SelectItemText { "Banana" } }
+                SelectItem { value: "cherry", disabled: true, SelectItemText { "Cherry" } }
+            }
+        }
+    }));
+}
+
+#[test]
+fn spinner_sizes() {
+    for (name, size) in [
+        ("small", SpinnerSize::Small),
+        ("medium", SpinnerSize::Medium),
+        ("large", SpinnerSize::Large),
+        ("xlarge", SpinnerSize::XLarge),
+    ] {
+        insta::assert_snapshot!(format!("spinner_{name}"), render(rsx! { Spinner { size } }));
+    }
+}
+
+#[test]
+fn tabs() {
+    insta::assert_snapshot!(render(rsx! {
+        Tabs {
+            default_value: "account",
+            TabsList {
+                TabsTrigger { value: "account", "Account" }
+                TabsTrigger { value: "password", "Password" }
+                TabsTrigger { value: "billing", disabled: true, "Billing" }
+            }
+            TabsContent { value: "account", "Account settings" }
+            TabsContent { value: "password", "Password settings" }
+            TabsContent { value: "billing", force_mount: true, "Billing settings" }
+        }
+    }));
+}
+
+#[test]
+fn tooltip() {
+    insta::assert_snapshot!(
+        "tooltip_closed",
+        render(rsx! {
+            TooltipProvider {
+                Tooltip {
+                    TooltipTrigger { "Hover me" }
+                    TooltipContent { "Tip" }
+                }
+            }
+        })
+    );
+    insta::assert_snapshot!(
+        "tooltip_open",
+        render(rsx! {
+            TooltipProvider {
+                Tooltip {
+                    default_open: true,
+                    TooltipTrigger { "Hover me" }
+                    TooltipContent {
+                        side: TooltipSide::Bottom,
+                        align: TooltipAlign::Start,
+                        "Tip"
+                        TooltipArrow {}
+                    }
+                }
+            }
+        })
+    );
+}